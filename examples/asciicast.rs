@@ -0,0 +1,27 @@
+//! Demonstrates the asciicast recorder. It records a few frames of a progress bar and prints the
+//! recording, which can be saved to a `.cast` file and replayed with `asciinema play`.
+
+use core::time::Duration;
+
+use tuit::draw::asciicast::{AsciicastRecorder, SteadyClock};
+use tuit::draw::DummyTarget;
+use tuit::prelude::*;
+use tuit::style::Style;
+use tuit::terminal::ConstantSize;
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<20, 3> = ConstantSize::new();
+    let mut cast = String::new();
+    let clock = SteadyClock::new(Duration::from_millis(200));
+    let mut recorder = AsciicastRecorder::new(DummyTarget, &mut cast, clock).title(Some("Loading"));
+
+    for done in 0..=10 {
+        let bar = format!("[{}{}]", "#".repeat(done), " ".repeat(10 - done));
+
+        Text::new(&bar).styled(Style::new().bold()).drawn(&mut terminal).ok();
+        recorder.render(&terminal).expect("Writing to a String can't fail");
+    }
+
+    print!("{cast}");
+}
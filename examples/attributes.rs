@@ -0,0 +1,35 @@
+//! Demonstrates the text attributes: dimming, blinking, concealment, overlines and underline variants.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Colour, Style, Underline};
+use tuit::terminal::{ConstantSize, Rectangle};
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<20, 10> = ConstantSize::new();
+
+    let rows = [
+        ("Dimmed", Style::new().dimmed()),
+        ("Blinking", Style::new().blinking()),
+        ("Hidden", Style::new().hidden()),
+        ("Overlined", Style::new().overlined()),
+        ("Single underline", Style::new().underline_variant(Underline::Single)),
+        ("Double underline", Style::new().underline_variant(Underline::Double)),
+        ("Curly underline", Style::new().underline_variant(Underline::Curly)),
+        ("Dotted underline", Style::new().underline_variant(Underline::Dotted)),
+        ("Dashed underline", Style::new().underline_variant(Underline::Dashed)),
+        (
+            "Red underline",
+            Style::new().underline_variant(Underline::Curly).underline_colour_of(Colour::Rgb24(255, 0, 0)),
+        ),
+    ];
+
+    for (y, (label, style)) in rows.into_iter().enumerate() {
+        let mut row = terminal.view_mut(Rectangle::of_size((20, 1)).at((0, y))).expect("Row fits");
+
+        Text::new(label).styled(style).drawn(&mut row).ok();
+    }
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible.");
+}
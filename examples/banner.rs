@@ -0,0 +1,26 @@
+//! Demonstrates the [`Banner`] widget with both builtin fonts.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Ansi4, Style};
+use tuit::terminal::ConstantSize;
+use tuit::widgets::builtins::banner::{Alignment, Font};
+use tuit::widgets::builtins::Banner;
+
+fn main() {
+    let mut terminal: ConstantSize<70, 18> = ConstantSize::new();
+
+    let title = Banner::new("Tuit!", Font::LARGE)
+        .styled(Style::new().fg_ansi4(Ansi4::Cyan))
+        .vertical(Alignment::Start);
+
+    let counter = Banner::new("12:45\nv0.3.1", Font::SMALL)
+        .styled(Style::new().fg_ansi4(Ansi4::Yellow))
+        .horizontal(Alignment::End)
+        .vertical(Alignment::End);
+
+    title.drawn(&mut terminal).expect("There is enough space");
+    counter.drawn(&mut terminal).expect("There is enough space");
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+}
@@ -0,0 +1,28 @@
+//! Prints what Tuit detected about the terminal, then renders some coloured text at that colour depth.
+//!
+//! Try running it with `NO_COLOR=1`, `TERM=xterm`, or piped into a file.
+
+use tuit::prelude::*;
+use tuit::std::capabilities::Capabilities;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Colour, Style};
+use tuit::terminal::ConstantSize;
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let capabilities = Capabilities::from_env();
+
+    println!("{capabilities:#?}");
+
+    let mut terminal: ConstantSize<40, 3> = ConstantSize::new();
+    let text = Text::new("This text is orange, if it can be!").styled(Style::new().fg(Colour::Rgb24(255, 135, 0)));
+
+    text.drawn(&mut terminal).expect("There is enough space");
+
+    StdoutRenderer::default()
+        .capabilities(&capabilities)
+        .render(&terminal)
+        .expect("Stdout is writable");
+
+    println!();
+}
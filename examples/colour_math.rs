@@ -0,0 +1,41 @@
+//! Demonstrates colour maths: a hue wheel, lightness ramps, blending, and contrast ratios.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Colour, Hsl, Style};
+use tuit::terminal::ConstantSize;
+
+const WIDTH: usize = 48;
+
+fn main() {
+    let mut terminal: ConstantSize<WIDTH, 5> = ConstantSize::new();
+    let base = Colour::Rgb24(40, 120, 220);
+    let overlay = Colour::Rgb24(255, 200, 0);
+
+    for x in 0..WIDTH {
+        let factor = x as f32 / (WIDTH - 1) as f32;
+
+        let rows = [
+            Colour::from_hsl(Hsl::new(factor * 360.0, 0.8, 0.5)),
+            base.darken(0.4).lerp(base.lighten(0.4), factor),
+            base.desaturate(factor),
+            overlay.blend(base, factor),
+        ];
+
+        for (y, colour) in rows.into_iter().enumerate() {
+            terminal.cell_mut(x, y).expect("Cell exists").style = Style::new().bg(colour);
+        }
+    }
+
+    let ratio = base.contrast_ratio(Colour::Luma8(255)).expect("Both colours are known");
+    let label = format!("White on blue has a contrast ratio of {ratio:.2}");
+
+    for (x, character) in label.chars().enumerate().take(WIDTH) {
+        let cell = terminal.cell_mut(x, 4).expect("Cell exists");
+
+        cell.character = character;
+        cell.style = Style::new().fg(Colour::Luma8(255)).bg(base);
+    }
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+}
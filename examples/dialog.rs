@@ -0,0 +1,29 @@
+//! Demonstrates the [`Dialog`] widget drawn over existing content.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Ansi4, Colour};
+use tuit::terminal::{keys, ConstantSize, KeyState, UpdateInfo, UpdateResult};
+use tuit::widgets::builtins::{Dialog, Sweeper, Text};
+
+fn main() {
+    let mut terminal: ConstantSize<57, 14> = ConstantSize::new();
+
+    Sweeper::of_colour(Colour::Ansi16(Ansi4::BrightCyan)).drawn(&mut terminal).ok();
+    Text::new("Some very important work is going on behind this dialog...").drawn(&mut terminal).ok();
+
+    let mut dialog = Dialog::new("Continue?", "Unsaved changes will be lost.", &[" Yes ", " No "]);
+    dialog.frame_style = dialog.frame_style.bg(Colour::Ansi16(Ansi4::Yellow));
+
+    dialog.drawn(&mut terminal).expect("There is enough space");
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+    println!();
+
+    let result = dialog
+        .update(UpdateInfo::KeyboardInput(keys::ENTER, KeyState::KeyDown), &terminal)
+        .expect("Infallible");
+
+    if let UpdateResult::Selected(button) = result {
+        println!("You chose button #{button}");
+    }
+}
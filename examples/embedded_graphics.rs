@@ -0,0 +1,27 @@
+//! Demonstrates the `embedded-graphics` renderer. There's no display here, so it draws onto
+//! `embedded-graphics`' mock display and prints its pixels: `#` for lit pixels, `.` for dark ones.
+
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::mono_font::ascii::FONT_5X8;
+use embedded_graphics::pixelcolor::BinaryColor;
+use tuit::draw::embedded::DrawTargetRenderer;
+use tuit::prelude::*;
+use tuit::style::Style;
+use tuit::terminal::{ConstantSize, Rectangle};
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<12, 2> = ConstantSize::new();
+
+    Text::new("Tuit on an").drawn(&mut terminal).ok();
+
+    let mut row = terminal.view_mut(Rectangle::of_size((12, 1)).at((0, 1))).expect("Row fits");
+    Text::new("OLED").styled(Style::new().underlined()).drawn(&mut row).ok();
+
+    let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+    display.set_allow_overdraw(true);
+
+    DrawTargetRenderer::new(&mut display, &FONT_5X8).render(&terminal).expect("The display can be drawn to");
+
+    println!("{display:?}");
+}
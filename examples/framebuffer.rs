@@ -0,0 +1,42 @@
+//! Demonstrates the framebuffer renderer. There's no display here, so the pixels are printed as
+//! text: `#` for bright pixels, `+` for dim ones, and spaces for dark ones.
+
+use tuit::draw::framebuffer::{Framebuffer, FramebufferRenderer, PixelFormat};
+use tuit::prelude::*;
+use tuit::style::Style;
+use tuit::terminal::{Cell, ConstantSize, Rectangle};
+use tuit::widgets::builtins::Text;
+
+const WIDTH: usize = 10 * 8;
+const HEIGHT: usize = 2 * 8;
+
+fn main() {
+    let mut terminal: ConstantSize<10, 2> = ConstantSize::new();
+    let mut pixels = [0; WIDTH * HEIGHT];
+    let mut previous = [Cell::default(); 10 * 2];
+
+    let framebuffer = Framebuffer::new(&mut pixels, WIDTH, HEIGHT, PixelFormat::Gray8);
+    let mut renderer = FramebufferRenderer::new(framebuffer).track_changes(&mut previous);
+
+    Text::new("Tuit on").drawn(&mut terminal).ok();
+    renderer.render(&terminal).expect("The framebuffer is big enough");
+    println!("First frame, changed {:?}", renderer.dirty());
+
+    let mut row = terminal.view_mut(Rectangle::of_size((10, 1)).at((0, 1))).expect("Row fits");
+    Text::new("an LCD").styled(Style::new().underlined()).drawn(&mut row).ok();
+    renderer.render(&terminal).expect("The framebuffer is big enough");
+    println!("Second frame, changed {:?}", renderer.dirty());
+
+    for row in renderer.framebuffer.pixels.chunks(WIDTH) {
+        let row: String = row
+            .iter()
+            .map(|&luma| match luma {
+                0..64 => ' ',
+                64..192 => '+',
+                _ => '#',
+            })
+            .collect();
+
+        println!("{}", row.trim_end());
+    }
+}
@@ -0,0 +1,42 @@
+//! Demonstrates drawing a true-pixel image next to some text, using the Sixel or Kitty graphics protocol.
+//!
+//! Pass `kitty` as an argument to use the Kitty graphics protocol instead of Sixel.
+
+use tuit::draw::graphics::{GraphicsProtocol, GraphicsRenderer, ImagePlacement, RgbImage};
+use tuit::prelude::*;
+use tuit::terminal::{ConstantSize, Rectangle};
+use tuit::widgets::builtins::Text;
+
+const WIDTH: usize = 48;
+const HEIGHT: usize = 48;
+
+fn main() {
+    let protocol = if std::env::args().any(|arg| arg == "kitty") {
+        GraphicsProtocol::Kitty
+    } else {
+        GraphicsProtocol::Sixel
+    };
+
+    let mut pixels = [0_u8; WIDTH * HEIGHT * 3];
+
+    for (idx, pixel) in pixels.chunks_exact_mut(3).enumerate() {
+        let (x, y) = (idx % WIDTH, idx / WIDTH);
+
+        pixel.copy_from_slice(&[(x * 255 / WIDTH) as u8, (y * 255 / HEIGHT) as u8, 200]);
+    }
+
+    let placements = [ImagePlacement::new(RgbImage::new(&pixels, WIDTH, HEIGHT), Rectangle::of_size((8, 4)).at((1, 1))).id(1)];
+
+    let mut terminal: ConstantSize<40, 6> = ConstantSize::new();
+    let text = Text::new("          This gradient is drawn with real pixels!");
+
+    text.drawn(&mut terminal).expect("There is enough space");
+
+    let mut output = String::new();
+
+    GraphicsRenderer::new(&mut output, protocol, &placements)
+        .render(&terminal)
+        .expect("The image fits inside the terminal");
+
+    println!("{output}");
+}
@@ -0,0 +1,38 @@
+//! Demonstrates the [`HexView`] widget, with a highlighted header and a cursor scrolled down.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Ansi4, Style};
+use tuit::terminal::{keys, ConstantSize, KeyState, UpdateInfo};
+use tuit::widgets::builtins::hex_view::Highlight;
+use tuit::widgets::builtins::HexView;
+
+fn main() {
+    let mut firmware = [0_u8; 200];
+
+    for (idx, byte) in firmware.iter_mut().enumerate() {
+        *byte = (idx * 7) as u8;
+    }
+
+    firmware[..8].copy_from_slice(b"\x7fELF\x02\x01\x01\x00");
+
+    let highlights = [
+        Highlight::new(0, 4, Style::new().fg_ansi4(Ansi4::Yellow).bold()),
+        Highlight::new(4, 8, Style::new().fg_ansi4(Ansi4::Cyan)),
+    ];
+
+    let mut terminal: ConstantSize<60, 6> = ConstantSize::new();
+    let mut hex_view = HexView::new(&firmware).address(0x0800_0000).highlights(&highlights);
+
+    for key in [keys::PAGE_DOWN, keys::RIGHT_ARROW, keys::RIGHT_ARROW] {
+        hex_view.update(UpdateInfo::KeyboardInput(key, KeyState::KeyDown), &terminal).expect("Fits inside the terminal");
+    }
+
+    hex_view.drawn(&mut terminal).expect("There is enough space");
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+    println!();
+
+    hex_view.update(UpdateInfo::KeyboardInput(keys::HOME, KeyState::KeyDown), &terminal).expect("Fits inside the terminal");
+    hex_view.drawn(&mut terminal).expect("There is enough space");
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+}
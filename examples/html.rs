@@ -0,0 +1,32 @@
+//! Demonstrates the HTML renderer. Save the output to a `.html` file and open it in a browser.
+
+use tuit::draw::{HtmlRenderer, HtmlStyling};
+use tuit::prelude::*;
+use tuit::style::{Ansi4, Style};
+use tuit::terminal::{ConstantSize, Hyperlink, Rectangle};
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<30, 4> = ConstantSize::new();
+    let lines = [
+        Text::new("Bold and green").styled(Style::new().bold().fg_ansi4(Ansi4::Green)),
+        Text::new("Curly <underline>").styled("curly-underline italic".parse().expect("Valid style")),
+        Text::new("Tuit on crates.io").linked(Hyperlink::new("https://crates.io/crates/tuit")),
+        Text::new("Inverted").styled(Style::new().inverted()),
+    ];
+
+    for (y, line) in lines.into_iter().enumerate() {
+        let mut row = terminal.view_mut(Rectangle::of_size((30, 1)).at((0, y))).expect("Row fits");
+
+        line.drawn(&mut row).ok();
+    }
+
+    let mut output = String::new();
+
+    HtmlRenderer::new(&mut output)
+        .styling(HtmlStyling::Classes)
+        .render(&terminal)
+        .expect("Writing to a `String` can't fail.");
+
+    println!("{output}");
+}
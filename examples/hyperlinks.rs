@@ -0,0 +1,23 @@
+//! Demonstrates OSC 8 hyperlinks. Hold Ctrl (or Cmd) and click on the text in supporting terminals.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::terminal::{ConstantSize, Hyperlink, Rectangle};
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<30, 3> = ConstantSize::new();
+    let links = [
+        ("Tuit on crates.io", Hyperlink::new("https://crates.io/crates/tuit")),
+        ("Tuit's documentation", Hyperlink::new("https://docs.rs/tuit")),
+        ("/etc/hosts", Hyperlink::new("file:///etc/hosts")),
+    ];
+
+    for (y, (label, link)) in links.into_iter().enumerate() {
+        let mut row = terminal.view_mut(Rectangle::of_size((30, 1)).at((0, y))).expect("Row fits");
+
+        Text::new(label).linked(link).drawn(&mut row).ok();
+    }
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible.");
+}
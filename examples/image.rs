@@ -0,0 +1,30 @@
+//! Demonstrates the [`Image`] widget with a generated gradient, in each of the [`Palette`]s.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::terminal::ConstantSize;
+use tuit::widgets::builtins::image::Palette;
+use tuit::widgets::builtins::Image;
+
+const WIDTH: usize = 64;
+const HEIGHT: usize = 16;
+
+fn main() {
+    let mut pixels = [0_u8; WIDTH * HEIGHT * 3];
+
+    for (idx, pixel) in pixels.chunks_exact_mut(3).enumerate() {
+        let (x, y) = (idx % WIDTH, idx / WIDTH);
+
+        pixel.copy_from_slice(&[(x * 255 / (WIDTH - 1)) as u8, (y * 255 / (HEIGHT - 1)) as u8, 160]);
+    }
+
+    let image = Image::new(&pixels, WIDTH, HEIGHT);
+
+    for (palette, dither) in [(Palette::Rgb24, false), (Palette::Ansi256, false), (Palette::Ansi16, false), (Palette::Ansi16, true)] {
+        let mut terminal: ConstantSize<32, 4> = ConstantSize::new();
+
+        image.palette(palette).dithered(dither).drawn(&mut terminal).expect("The pixel buffer is big enough");
+        terminal.display(StdoutRenderer::default()).expect("Infallible");
+        println!();
+    }
+}
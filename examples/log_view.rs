@@ -0,0 +1,44 @@
+//! Demonstrates capturing `log` records with a [`LogCapture`], and showing them in a [`LogView`].
+
+use log::LevelFilter;
+use tuit::prelude::*;
+use tuit::std::log_view::{LogCapture, LogView};
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::terminal::{keys, ConstantSize, KeyState, UpdateInfo};
+
+static CAPTURE: LogCapture = LogCapture::new(100);
+
+fn main() {
+    CAPTURE.install().expect("No other logger has been installed");
+
+    let mut terminal: ConstantSize<50, 6> = ConstantSize::new();
+    let mut log_view = LogView::new(&CAPTURE).level(LevelFilter::Debug);
+
+    for request in 0..20 {
+        log::debug!(target: "server::http", "Handling request #{request}");
+
+        if request % 7 == 3 {
+            log::warn!(target: "server::db", "Query for request #{request} was slow");
+        }
+    }
+
+    log::trace!("This is filtered out by the view");
+    log::error!(target: "server::db", "Lost the connection to the database");
+
+    log_view.drawn(&mut terminal).expect("Infallible");
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+    println!();
+
+    // Scroll up a page; new records don't move the view until we go back to the tail.
+    log_view.update(UpdateInfo::KeyboardInput(keys::PAGE_UP, KeyState::KeyDown), &terminal).expect("Infallible");
+    log::info!(target: "server", "Reconnected");
+
+    log_view.drawn(&mut terminal).expect("Infallible");
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+    println!();
+
+    log_view.update(UpdateInfo::KeyboardInput(keys::END, KeyState::KeyDown), &terminal).expect("Infallible");
+
+    log_view.drawn(&mut terminal).expect("Infallible");
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+}
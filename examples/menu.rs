@@ -0,0 +1,47 @@
+//! Demonstrates the [`MenuBar`] widget, with a submenu opened over some text.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::terminal::{keys, ConstantSize, KeyState, MouseButton, UpdateInfo};
+use tuit::widgets::builtins::menu::{Menu, MenuBar, MenuEntry, MenuItem};
+use tuit::widgets::builtins::Text;
+
+const RECENT: &[MenuEntry] = &[
+    MenuEntry::Item(MenuItem::new("notes.txt", 10)),
+    MenuEntry::Item(MenuItem::new("todo.md", 11)),
+];
+
+const FILE: &[MenuEntry] = &[
+    MenuEntry::Item(MenuItem::new("New", 1).accelerator('n')),
+    MenuEntry::Item(MenuItem::new("Open...", 2).accelerator('o')),
+    MenuEntry::Submenu(Menu::new("Open recent", RECENT)),
+    MenuEntry::Item(MenuItem::new("Save", 3).accelerator('s').disabled()),
+    MenuEntry::Separator,
+    MenuEntry::Item(MenuItem::new("Quit", 4).accelerator('q')),
+];
+
+const EDIT: &[MenuEntry] = &[
+    MenuEntry::Item(MenuItem::new("Undo", 20)),
+    MenuEntry::Item(MenuItem::new("Redo", 21)),
+];
+
+const MENUS: &[Menu] = &[Menu::new("File", FILE), Menu::new("Edit", EDIT), Menu::new("Help", &[]).disabled()];
+
+fn main() {
+    let mut terminal: ConstantSize<50, 12> = ConstantSize::new();
+
+    let text = Text::new("The menus are drawn over whatever was already on the screen, like this text.");
+    let mut menu_bar = MenuBar::new(MENUS);
+
+    // Click on "File", then move down to "Open recent" and open it.
+    menu_bar.update(UpdateInfo::CellClicked(1, 0, MouseButton::Primary), &terminal).expect("Infallible");
+
+    for key in [keys::DOWN_ARROW, keys::DOWN_ARROW, keys::RIGHT_ARROW] {
+        menu_bar.update(UpdateInfo::KeyboardInput(key, KeyState::KeyDown), &terminal).expect("Infallible");
+    }
+
+    text.drawn(&mut terminal).ok();
+    menu_bar.drawn(&mut terminal).expect("There is enough space");
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+}
@@ -0,0 +1,22 @@
+//! Demonstrates the plain-text renderer, which writes a terminal without any escape codes.
+
+use tuit::draw::PlainTextRenderer;
+use tuit::prelude::*;
+use tuit::terminal::ConstantSize;
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+
+    Text::new("Hello, 世 界 !").centered().drawn(&mut terminal).ok();
+
+    let mut output = String::new();
+
+    PlainTextRenderer::new(&mut output)
+        .trim_trailing_whitespace(true)
+        .skip_blank_rows(true)
+        .render(&terminal)
+        .expect("Writing to a `String` can't fail.");
+
+    print!("{output}");
+}
@@ -0,0 +1,57 @@
+//! Demonstrates the [`PtyTerminal`] widget. It runs a shell script inside a pseudoterminal, sends it
+//! some keys, and draws its screen in the middle of a larger terminal, below a title.
+
+use std::process::Command;
+use std::thread::sleep;
+use std::time::Duration;
+
+use tuit::prelude::*;
+use tuit::std::pty::PtyTerminal;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Ansi4, Colour};
+use tuit::terminal::{keys, ConstantSize, KeyState, Rectangle, UpdateInfo, UpdateResult};
+use tuit::widgets::builtins::{Sweeper, Text};
+
+const SCRIPT: &str = r#"
+printf '\033[1mWhat is your name?\033[0m '
+read name
+printf 'Hello, \033[32m%s\033[0m! This terminal is \033[4m%s\033[0m.\n' "$name" "$(stty size)"
+"#;
+
+fn main() {
+    let mut terminal: ConstantSize<50, 8> = ConstantSize::new();
+    let area = Rectangle::new((2, 2), (48, 7));
+
+    let mut command = Command::new("sh");
+    command.args(["-c", SCRIPT]);
+
+    // The widget starts out smaller than its area, and is resized the first time it is updated.
+    let mut pty_terminal = PtyTerminal::spawn(&command, (20, 2)).expect("The shell could be started");
+
+    let typing = "Ferris".chars().map(|character| UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown));
+    let mut input = typing.chain([UpdateInfo::KeyboardInput(keys::ENTER, KeyState::KeyDown)]);
+
+    loop {
+        // Only start typing once the shell has asked for the name, like a person would.
+        let asked = pty_terminal.screen().cells().any(|cell| cell.character == '?');
+        let update_info = if asked { input.next().unwrap_or(UpdateInfo::NoInfo) } else { UpdateInfo::NoInfo };
+
+        let view = terminal.view(area).expect("The area is inside the terminal");
+        let result = pty_terminal.update(update_info, view).expect("The shell is still there");
+
+        if result == UpdateResult::LifecycleEnd {
+            break;
+        }
+
+        sleep(Duration::from_millis(20));
+    }
+
+    Sweeper::of_colour(Colour::Ansi16(Ansi4::Blue)).drawn(&mut terminal).expect("Infallible");
+    Text::new(" sh ").drawn(&mut terminal).expect("Infallible");
+
+    let view = terminal.view_mut(area).expect("The area is inside the terminal");
+    pty_terminal.drawn(view).expect("Infallible");
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+    println!("\x1b[0m");
+}
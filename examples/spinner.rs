@@ -0,0 +1,35 @@
+//! Demonstrates the [`Spinner`] widget and the [`Animated`] trait.
+
+use std::thread::sleep;
+use std::time::Instant;
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Ansi4, Colour};
+use tuit::terminal::{ConstantSize, UpdateInfo};
+use tuit::widgets::Animated;
+use tuit::widgets::builtins::{Spinner, Sweeper};
+
+fn main() {
+    let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+    let sweeper = Sweeper::of_colour(Colour::Ansi16(Ansi4::Blue));
+    let mut spinner = Spinner::braille().centered();
+
+    let mut last_update = Instant::now();
+
+    for _ in 0..30 {
+        spinner.update(UpdateInfo::TimeDelta(last_update.elapsed()), &terminal).expect("Infallible");
+        last_update = Instant::now();
+
+        sweeper.drawn(&mut terminal).ok();
+        spinner.drawn(&mut terminal).expect("There is enough space");
+
+        terminal.display(StdoutRenderer::default()).expect("Infallible");
+        println!();
+
+        // Only wake up when the spinner actually needs to move.
+        if let Some(next_tick) = spinner.next_tick() {
+            sleep(next_tick);
+        }
+    }
+}
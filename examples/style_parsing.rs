@@ -0,0 +1,37 @@
+//! Demonstrates parsing styles from text, like the ones kept in configuration files.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::Style;
+use tuit::terminal::{ConstantSize, Rectangle};
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let sources = [
+        "bold italic #ff8800 on ansi:4",
+        "fg=bright-cyan bg=luma:40 underline",
+        "curly-underline underline-colour=red orange",
+        "dim fg=purpel",
+        "weight=70000",
+        "bold on",
+    ];
+
+    let mut terminal: ConstantSize<40, 6> = ConstantSize::new();
+
+    for (y, source) in sources.into_iter().enumerate() {
+        match source.parse::<Style>() {
+            Ok(style) => {
+                println!("{source:<45} => {style}");
+
+                let mut row = terminal.view_mut(Rectangle::of_size((40, 1)).at((0, y))).expect("Row fits");
+                Text::new(source).styled(style).drawn(&mut row).ok();
+            }
+            Err(error) => {
+                println!("{source:<45} => {error}");
+                println!("{:<width$}{:^<length$}", "", "", width = error.position, length = error.length.max(1));
+            }
+        }
+    }
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible.");
+}
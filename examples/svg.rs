@@ -0,0 +1,34 @@
+//! Demonstrates the SVG renderer. Save the output to a `.svg` file and open it in a browser.
+
+use tuit::draw::SvgRenderer;
+use tuit::prelude::*;
+use tuit::style::{Ansi4, Style, Underline};
+use tuit::terminal::{ConstantSize, Rectangle};
+use tuit::widgets::builtins::Text;
+
+fn main() {
+    let mut terminal: ConstantSize<32, 4> = ConstantSize::new();
+    let lines = [
+        Text::new("$ cargo run --example svg").styled(Style::new().fg_ansi4(Ansi4::Green)),
+        Text::new("Backgrounds").styled(Style::new().bg_ansi4(Ansi4::Blue).bold()),
+        Text::new("Curly underlines").styled(Style::new().underline_variant(Underline::Curly)),
+        Text::new("Strikethrough").styled(Style::new().strikethrough()),
+    ];
+
+    for (y, line) in lines.into_iter().enumerate() {
+        let mut row = terminal.view_mut(Rectangle::of_size((32, 1)).at((0, y))).expect("Row fits");
+
+        line.drawn(&mut row).ok();
+    }
+
+    let mut output = String::new();
+
+    SvgRenderer::new(&mut output)
+        .chrome(true)
+        .title("tuit")
+        .padding(12)
+        .render(&terminal)
+        .expect("Writing to a `String` can't fail.");
+
+    print!("{output}");
+}
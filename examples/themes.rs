@@ -0,0 +1,44 @@
+//! Demonstrates drawing the same widgets with different [`Theme`]s, including a nested override.
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::{Ansi4, Style, Theme};
+use tuit::terminal::ConstantSize;
+use tuit::widgets::builtins::{Buttons, Dialog, Text};
+
+fn main() {
+    let ocean = Theme {
+        border: Style::new().fg_ansi4(Ansi4::Cyan),
+        title: Style::new().fg_ansi4(Ansi4::BrightCyan).bold(),
+        selected: Style::new().fg_ansi4(Ansi4::Black).bg_ansi4(Ansi4::Cyan),
+        overlay: Style::new().fg_ansi4(Ansi4::Blue),
+        ..Theme::DEFAULT
+    };
+
+    for (name, theme) in [("Default", Theme::DEFAULT), ("Monochrome", Theme::MONOCHROME), ("Ocean", ocean)] {
+        let mut terminal: ConstantSize<44, 9> = ConstantSize::new();
+
+        Text::new(name).themed(theme).drawn(&mut terminal).expect("There is enough space");
+
+        Dialog::new("Save?", "Your changes will be lost otherwise.", &[" Save ", " Discard "])
+            .themed(theme)
+            .drawn(&mut terminal)
+            .expect("There is enough space");
+
+        terminal.display(StdoutRenderer::default()).expect("Infallible");
+        println!();
+    }
+
+    // Themes can be nested: the innermost one wins.
+    let mut terminal: ConstantSize<44, 1> = ConstantSize::new();
+    let buttons = Buttons::new(&[" Ocean ", " Default "]).select(0).themed(ocean);
+
+    Text::new("Nested: ")
+        .next_to(buttons)
+        .themed(Theme::DEFAULT)
+        .drawn(&mut terminal)
+        .expect("There is enough space");
+
+    terminal.display(StdoutRenderer::default()).expect("Infallible");
+    println!();
+}
@@ -0,0 +1,40 @@
+//! Demonstrates the [`Toasts`] widget, with a few toasts expiring over time.
+
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use tuit::prelude::*;
+use tuit::std::stdout_render::StdoutRenderer;
+use tuit::style::Style;
+use tuit::terminal::{ConstantSize, UpdateInfo};
+use tuit::widgets::builtins::toasts::{Severity, Toast};
+use tuit::widgets::builtins::{Sweeper, Toasts};
+use tuit::widgets::Animated;
+
+fn main() {
+    let mut terminal: ConstantSize<40, 12> = ConstantSize::new();
+    let sweeper = Sweeper::new(Style::new());
+    let mut toasts: Toasts<4> = Toasts::new();
+
+    toasts.push(Toast::new("Connected to the server", Severity::Info).lasting(Duration::from_secs(1)));
+    toasts.push(Toast::new("Saved 3 files", Severity::Success).lasting(Duration::from_secs(2)));
+    toasts.push(Toast::new("The disk is almost full, consider deleting some files", Severity::Warning));
+    toasts.push(Toast::new("Could not reach the backup server", Severity::Error).sticky());
+
+    let mut last_update = Instant::now();
+
+    // Redraw every time a toast expires, until only the sticky one is left.
+    loop {
+        toasts.update(UpdateInfo::TimeDelta(last_update.elapsed()), &terminal).expect("Infallible");
+        last_update = Instant::now();
+
+        sweeper.drawn(&mut terminal).ok();
+        toasts.drawn(&mut terminal).expect("Infallible");
+
+        terminal.display(StdoutRenderer::default()).expect("Infallible");
+        println!();
+
+        let Some(next_tick) = toasts.next_tick() else { break };
+        sleep(next_tick);
+    }
+}
@@ -0,0 +1,36 @@
+//! Demonstrates the VT parser. It replays the output of a made-up program, which draws a list,
+//! redraws a progress bar in place, and shows a dialog on the alternate screen, and then prints
+//! the terminal that the output leaves behind.
+
+use tuit::draw::{AnsiRenderer, PlainTextRenderer};
+use tuit::prelude::*;
+use tuit::terminal::vt::VtParser;
+use tuit::terminal::{Cell, ConstantSize};
+
+const OUTPUT: &[&[u8]] = &[
+    b"\x1b[1mFiles:\x1b[0m\r\n",
+    b"  \x1b[34msrc\x1b[0m\r\n  \x1b[32mbuild.sh\x1b[0m\r\n",
+    b"Copying [          ]",
+    b"\r\x1b[8C[#####     ]",
+    b"\x1b[?1049h\x1b[2;3H\x1b[7m Overwrite? \x1b[0m\x1b[?1049l",
+    b"\r\x1b[8C[##########] \x1b[32mdone\x1b[0m\r\n",
+];
+
+fn main() {
+    let mut terminal: ConstantSize<30, 6> = ConstantSize::new();
+    let mut main_screen = [Cell::default(); 30 * 6];
+    let mut parser = VtParser::new().alternate_screen(&mut main_screen);
+
+    for chunk in OUTPUT {
+        parser.parse(&mut terminal, chunk);
+    }
+
+    let mut text = String::new();
+    PlainTextRenderer::new(&mut text).trim_trailing_whitespace(true).render(&terminal).expect("Writing to a `String` can't fail.");
+    println!("{text}");
+
+    // The styles were parsed too, so the terminal can be drawn in colour again.
+    let mut ansi = String::new();
+    AnsiRenderer::new(&mut ansi).render(&terminal).expect("Writing to a `String` can't fail.");
+    println!("{ansi}\x1b[0m");
+}
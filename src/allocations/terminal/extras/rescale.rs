@@ -2,11 +2,18 @@
 //!
 //! It is bundled in `extra` because it pulls in the `ndarray` dependency.
 
+#[cfg(feature = "serde")]
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
 use ndarray::{Array, Array2, Axis, Dim, Shape};
 
 use crate::prelude::{Metadata, TerminalMut};
 use crate::style::Style;
 use crate::terminal::{Cell, Rescalable, TerminalConst};
+#[cfg(feature = "serde")]
+use crate::terminal::serde_impls::{deserialize_terminal, serialize_terminal};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
 /// A dynamically-allocating, fully rescalable terminal.
@@ -92,4 +99,47 @@ impl Rescalable for Rescale {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// The terminal is stored as its default style and its cells, as a list of rows. Deserializing
+/// restores its dimensions, except that a terminal without any rows has no width to measure, so
+/// it comes back 0 by 0.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::allocations::terminal::extras::Rescale;
+///
+/// let terminal = Rescale::new((3, 2));
+///
+/// let snapshot = serde_json::to_string(&terminal).expect("Serializes");
+/// let restored: Rescale = serde_json::from_str(&snapshot).expect("Deserializes");
+///
+/// assert_eq!(restored, terminal);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rescale {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = (0..self.height()).map(|y| self.cells.row(y));
+
+        serialize_terminal(serializer, self.default_style, rows)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rescale {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let (default_style, rows) = deserialize_terminal(deserializer, PhantomData::<Vec<Vec<Cell>>>)?;
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(D::Error::custom("every row of a terminal must have the same length"));
+        }
+
+        let height = rows.len();
+        let cells = Array2::from_shape_vec((height, width), rows.into_iter().flatten().collect()).map_err(D::Error::custom)?;
+
+        Ok(Self { cells, default_style })
+    }
+}
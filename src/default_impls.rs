@@ -2,7 +2,7 @@ use core::cmp::Ordering;
 use core::ops::{BitOr, Deref, DerefMut};
 use crate::draw::Renderer;
 use crate::prelude::{Metadata, Terminal, TerminalConst, TerminalMut};
-use crate::style::{Ansi4, Style};
+use crate::style::{Ansi4, Style, Theme};
 use crate::terminal::{Cell, UpdateInfo, UpdateResult};
 use crate::terminal::Rectangle;
 use crate::widgets::Widget;
@@ -45,6 +45,10 @@ impl<T: Deref<Target: Metadata>> Metadata for T {
     fn default_style(&self) -> Style {
         self.deref().default_style()
     }
+
+    fn theme(&self) -> Theme {
+        self.deref().theme()
+    }
 }
 impl<T: Deref<Target: TerminalConst>> TerminalConst for T {
     fn cells(&self) -> impl Iterator<Item = &Cell> {
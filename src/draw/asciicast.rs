@@ -0,0 +1,323 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt::Write;
+use core::time::Duration;
+
+use anyhow::anyhow;
+
+use crate::draw::{AnsiRenderer, Renderer};
+use crate::style::ColourDepth;
+use crate::terminal::TerminalConst;
+
+/// A source of timestamps for an [`AsciicastRecorder`].
+///
+/// Only the differences between timestamps matter, so a clock can start counting from anywhere.
+/// Closures that return a [`Duration`] are clocks, and so is [`std::time::Instant`] (which returns
+/// the time that has passed since it) when the `std` feature is enabled.
+///
+/// ```
+/// use core::time::Duration;
+/// use tuit::draw::asciicast::Clock;
+///
+/// let mut ticks = 0;
+/// let mut clock = || {
+///     ticks += 1;
+///     Duration::from_millis(ticks * 10)
+/// };
+///
+/// assert_eq!(clock.now(), Duration::from_millis(10));
+/// assert_eq!(clock.now(), Duration::from_millis(20));
+/// ```
+pub trait Clock {
+    /// The current time.
+    fn now(&mut self) -> Duration;
+}
+
+impl<F: FnMut() -> Duration> Clock for F {
+    fn now(&mut self) -> Duration {
+        self()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for std::time::Instant {
+    fn now(&mut self) -> Duration {
+        self.elapsed()
+    }
+}
+
+/// A [`Clock`] that moves forward by the same step every time it is read, so that recordings
+/// play back at a steady frame rate and come out the same every time.
+///
+/// ```
+/// use core::time::Duration;
+/// use tuit::draw::asciicast::{Clock, SteadyClock};
+///
+/// let mut clock = SteadyClock::new(Duration::from_millis(500));
+///
+/// assert_eq!(clock.now(), Duration::ZERO);
+/// assert_eq!(clock.now(), Duration::from_millis(500));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct SteadyClock {
+    /// How far the clock moves forward every time it is read.
+    pub step: Duration,
+    elapsed: Duration,
+}
+
+impl SteadyClock {
+    /// Create a new [`SteadyClock`] that starts at zero.
+    #[must_use]
+    pub const fn new(step: Duration) -> Self {
+        Self { step, elapsed: Duration::ZERO }
+    }
+}
+
+impl Clock for SteadyClock {
+    fn now(&mut self) -> Duration {
+        let now = self.elapsed;
+
+        self.elapsed = now.saturating_add(self.step);
+
+        now
+    }
+}
+
+/// A [`Renderer`] that passes every frame on to another renderer, and also records it.
+///
+/// The recording is an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file, which
+/// can be replayed by asciinema and its web player.
+///
+/// The header is written when the first frame is rendered, with the terminal's size. Each frame
+/// becomes an output event, timed by the recorder's [`Clock`] from the first frame. The frames are
+/// written like an [`AnsiRenderer`] would write them, after moving the cursor to the top-left
+/// corner. If the terminal changes size, a resize event is written and the screen is cleared
+/// before the next frame.
+///
+/// To only record, without displaying anything, pass on the frames to a
+/// [`DummyTarget`](crate::draw::DummyTarget).
+///
+/// ```
+/// use core::time::Duration;
+/// use tuit::draw::asciicast::{AsciicastRecorder, SteadyClock};
+/// use tuit::draw::DummyTarget;
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut terminal: ConstantSize<2, 1> = ConstantSize::new();
+/// let mut cast = String::new();
+/// let clock = SteadyClock::new(Duration::from_millis(250));
+/// let mut recorder = AsciicastRecorder::new(DummyTarget, &mut cast, clock).title(Some("Demo"));
+///
+/// terminal.cell_mut(0, 0).expect("Cell exists").character = 'h';
+/// recorder.render(&terminal).expect("Infallible");
+/// terminal.cell_mut(1, 0).expect("Cell exists").character = 'i';
+/// recorder.render(&terminal).expect("Infallible");
+///
+/// assert_eq!(cast, concat!(
+///     r#"{"version": 2, "width": 2, "height": 1, "title": "Demo"}"#, "\n",
+///     r#"[0.000000, "o", "\u001b[Hh \u001b[0m"]"#, "\n",
+///     r#"[0.250000, "o", "\u001b[Hhi\u001b[0m"]"#, "\n",
+/// ));
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct AsciicastRecorder<'a, R, T, C> {
+    /// The renderer that every frame is passed on to.
+    pub renderer: R,
+    /// The writer that the recording is written into.
+    pub writer: T,
+    /// The clock that times the frames.
+    pub clock: C,
+    /// The colours that the recorded frames use.
+    pub colour_depth: ColourDepth,
+    /// The title of the recording, which is written into its header.
+    pub title: Option<&'a str>,
+    start: Option<Duration>,
+    size: Option<(usize, usize)>,
+}
+
+impl<'a, R: Renderer, T: Write, C: Clock> AsciicastRecorder<'a, R, T, C> {
+    /// Create a new [`AsciicastRecorder`] that records true colour frames, without a title.
+    pub const fn new(renderer: R, writer: T, clock: C) -> Self {
+        Self {
+            renderer,
+            writer,
+            clock,
+            colour_depth: ColourDepth::TrueColour,
+            title: None,
+            start: None,
+            size: None,
+        }
+    }
+
+    /// Set the colours that the recorded frames use.
+    #[must_use]
+    pub const fn colour_depth(mut self, colour_depth: ColourDepth) -> Self {
+        self.colour_depth = colour_depth;
+
+        self
+    }
+
+    /// Set the title of the recording. It has no effect once the first frame has been recorded.
+    #[must_use]
+    pub const fn title(mut self, title: Option<&'a str>) -> Self {
+        self.title = title;
+
+        self
+    }
+
+    /// Write the header, which starts every recording.
+    fn write_header(&mut self, (width, height): (usize, usize)) -> core::fmt::Result {
+        write!(self.writer, r#"{{"version": 2, "width": {width}, "height": {height}"#)?;
+
+        if let Some(title) = self.title {
+            self.writer.write_str(r#", "title": ""#)?;
+            JsonString(&mut self.writer).write_str(title)?;
+            self.writer.write_char('"')?;
+        }
+
+        self.writer.write_str("}\n")
+    }
+}
+
+impl<R: Renderer, T: Write, C: Clock> Renderer for AsciicastRecorder<'_, R, T, C> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        self.renderer.render(&terminal)?;
+
+        let now = self.clock.now();
+        let start = *self.start.get_or_insert(now);
+        let time = Seconds(now.saturating_sub(start));
+        let size = terminal.dimensions();
+        let resized = self.size.is_some_and(|previous| previous != size);
+
+        match self.size {
+            None => self.write_header(size).map_err(|e| anyhow!(e))?,
+            Some(_) if resized => writeln!(self.writer, r#"[{time}, "r", "{}x{}"]"#, size.0, size.1).map_err(|e| anyhow!(e))?,
+            Some(_) => {}
+        }
+
+        self.size = Some(size);
+
+        write!(self.writer, r#"[{time}, "o", ""#).map_err(|e| anyhow!(e))?;
+
+        let mut frame = Frame { writer: JsonString(&mut self.writer), first_row: true };
+
+        frame.write_str(if resized { "\x1b[H\x1b[2J" } else { "\x1b[H" }).map_err(|e| anyhow!(e))?;
+        AnsiRenderer::new(&mut frame).colour_depth(self.colour_depth).render(&terminal)?;
+        frame.write_str("\x1b[0m").map_err(|e| anyhow!(e))?;
+
+        writeln!(self.writer, r#""]"#).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+}
+
+/// A time in seconds, with microsecond precision, like asciicast's timestamps.
+struct Seconds(Duration);
+
+impl core::fmt::Display for Seconds {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{:06}", self.0.as_secs(), self.0.subsec_micros())
+    }
+}
+
+/// A writer that escapes everything written into it for the inside of a JSON string.
+struct JsonString<W>(W);
+
+impl<W: Write> Write for JsonString<W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for character in s.chars() {
+            match character {
+                '"' => self.0.write_str(r#"\""#)?,
+                '\\' => self.0.write_str(r"\\")?,
+                '\n' => self.0.write_str(r"\n")?,
+                '\r' => self.0.write_str(r"\r")?,
+                '\t' => self.0.write_str(r"\t")?,
+                control if control.is_control() => write!(self.0, r"\u{:04x}", u32::from(control))?,
+                character => self.0.write_char(character)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A writer that turns the rows written by an [`AnsiRenderer`] into output for a terminal: the
+/// newline before the first row is left out, so that the frame fits the recording's height, and the
+/// other newlines also return the cursor to the start of the row.
+struct Frame<W> {
+    writer: W,
+    first_row: bool,
+}
+
+impl<W: Write> Write for Frame<W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for (idx, row) in s.split('\n').enumerate() {
+            if idx > 0 {
+                if self.first_row {
+                    self.first_row = false;
+                } else {
+                    self.writer.write_str("\r\n")?;
+                }
+            }
+
+            self.writer.write_str(row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use core::time::Duration;
+    use std::prelude::rust_2021::*;
+
+    use super::{AsciicastRecorder, SteadyClock};
+    use crate::draw::{DummyTarget, Renderer};
+    use crate::style::{ColourDepth, Style};
+    use crate::terminal::{ConstantSize, TerminalMut};
+
+    #[test]
+    fn rows_and_escaping() {
+        let mut terminal: ConstantSize<2, 2> = ConstantSize::new();
+        terminal.cell_mut(0, 0).expect("Cell exists").character = '"';
+        terminal.cell_mut(1, 0).expect("Cell exists").character = '\\';
+        terminal.cell_mut(0, 1).expect("Cell exists").style = Style::new().bold();
+
+        let mut cast = String::new();
+        let mut recorder = AsciicastRecorder::new(DummyTarget, &mut cast, SteadyClock::new(Duration::ZERO)).title(Some("\"Quoted\"\n"));
+
+        recorder.render(&terminal).expect("Infallible");
+
+        assert_eq!(cast, concat!(
+            r#"{"version": 2, "width": 2, "height": 2, "title": "\"Quoted\"\n"}"#, "\n",
+            r#"[0.000000, "o", "\u001b[H\"\\\u001b[0m\r\n\u001b[1m\u001b[1m  \u001b[0m"]"#, "\n",
+        ));
+    }
+
+    #[test]
+    fn resizes() {
+        let small: ConstantSize<1, 1> = ConstantSize::new();
+        let large: ConstantSize<2, 1> = ConstantSize::new();
+
+        let mut cast = String::new();
+        let clock = SteadyClock::new(Duration::from_micros(1_500_001));
+        let mut recorder = AsciicastRecorder::new(DummyTarget, &mut cast, clock).colour_depth(ColourDepth::NoColour);
+
+        recorder.render(&small).expect("Infallible");
+        recorder.render(&large).expect("Infallible");
+        recorder.render(&large).expect("Infallible");
+
+        assert_eq!(cast, concat!(
+            r#"{"version": 2, "width": 1, "height": 1}"#, "\n",
+            r#"[0.000000, "o", "\u001b[H \u001b[0m"]"#, "\n",
+            r#"[1.500001, "r", "2x1"]"#, "\n",
+            r#"[1.500001, "o", "\u001b[H\u001b[2J  \u001b[0m"]"#, "\n",
+            r#"[3.000002, "o", "\u001b[H  \u001b[0m"]"#, "\n",
+        ));
+    }
+}
@@ -0,0 +1,259 @@
+use embedded_graphics::draw_target::{DrawTarget, DrawTargetExt};
+use embedded_graphics::geometry::{Point, Size};
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::primitives::Rectangle as Area;
+use embedded_graphics::text::{Baseline, Text};
+use embedded_graphics::Drawable;
+
+use crate::draw::markup::{Colours, Look};
+use crate::draw::Renderer;
+use crate::style::Palette;
+use crate::terminal::TerminalConst;
+use crate::Error;
+
+/// A [`Renderer`] that draws a terminal onto any `embedded-graphics` [`DrawTarget`], like the
+/// driver of an SPI display.
+///
+/// The characters are drawn with a [`MonoFont`], and every cell is as big as one of its characters
+/// (including its character spacing). Colours are looked up in a [`Palette`] and then converted
+/// from [`Rgb888`] into the target's colour type, so any target whose colours can be converted
+/// from [`Rgb888`] works, including grayscale and binary displays.
+///
+/// Bold and italic text are drawn with [`DrawTargetRenderer::bold_font`] and
+/// [`DrawTargetRenderer::italic_font`]. Without a bold font, bold text is drawn twice, one pixel
+/// apart. Lines (all underlines are drawn as single underlines) go across the whole cell, dim text
+/// is blended into its background, and hidden text isn't drawn. Blinking text is drawn without
+/// blinking.
+///
+/// To draw the terminal somewhere other than the top-left corner, use a translated target from
+/// [`DrawTargetExt::translated`].
+///
+/// ```
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::mono_font::ascii::FONT_4X6;
+/// use embedded_graphics::pixelcolor::BinaryColor;
+/// use tuit::draw::embedded::DrawTargetRenderer;
+/// use tuit::prelude::*;
+/// use tuit::style::Style;
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut terminal: ConstantSize<2, 1> = ConstantSize::new();
+/// let cell = terminal.cell_mut(0, 0).expect("Cell exists");
+/// cell.character = '|';
+/// cell.style = Style::new().underlined();
+///
+/// let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+/// display.set_allow_overdraw(true);
+///
+/// DrawTargetRenderer::new(&mut display, &FONT_4X6).render(&terminal).expect("The display can be drawn to");
+///
+/// display.assert_pattern(&[
+///     ".#......",
+///     ".#......",
+///     ".#......",
+///     ".#......",
+///     ".#......",
+///     "####....",
+/// ]);
+/// ```
+pub struct DrawTargetRenderer<'a, D> {
+    /// The target that the terminal is drawn onto.
+    pub target: &'a mut D,
+    /// The font that the characters are drawn with.
+    pub font: &'a MonoFont<'a>,
+    /// The font that bold characters are drawn with. It should be the same size as
+    /// [`DrawTargetRenderer::font`].
+    pub bold_font: Option<&'a MonoFont<'a>>,
+    /// The font that italic characters are drawn with. It should be the same size as
+    /// [`DrawTargetRenderer::font`].
+    pub italic_font: Option<&'a MonoFont<'a>>,
+    /// The colours used for the ANSI colours and the default colours.
+    pub palette: Palette,
+}
+
+impl<'a, D> DrawTargetRenderer<'a, D>
+where
+    D: DrawTarget,
+    D::Color: From<Rgb888>,
+{
+    /// Create a new [`DrawTargetRenderer`] that uses [`Palette::XTERM`] and draws every style with `font`.
+    pub const fn new(target: &'a mut D, font: &'a MonoFont<'a>) -> Self {
+        Self {
+            target,
+            font,
+            bold_font: None,
+            italic_font: None,
+            palette: Palette::XTERM,
+        }
+    }
+
+    /// Set the font that bold characters are drawn with.
+    #[must_use]
+    pub const fn bold_font(mut self, bold_font: Option<&'a MonoFont<'a>>) -> Self {
+        self.bold_font = bold_font;
+
+        self
+    }
+
+    /// Set the font that italic characters are drawn with.
+    #[must_use]
+    pub const fn italic_font(mut self, italic_font: Option<&'a MonoFont<'a>>) -> Self {
+        self.italic_font = italic_font;
+
+        self
+    }
+
+    /// Set the colours used for the ANSI colours and the default colours.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self
+    }
+
+    /// The width and height of every cell, in pixels.
+    const fn cell_size(&self) -> Size {
+        let size = self.font.character_size;
+
+        Size::new(size.width + self.font.character_spacing, size.height)
+    }
+
+    /// Draw a single character with the specified look, with the top-left of its cell at `origin`.
+    fn draw_cell(&mut self, character: char, look: &Look, origin: Point) -> Result<(), D::Error> {
+        let palette = &self.palette;
+        let cell = Area::new(origin, self.cell_size());
+
+        let Colours { foreground, background, underline } = look.colours(palette);
+        let [bg, fg, underline_colour] = [background, foreground, underline].map(|(red, green, blue)| D::Color::from(Rgb888::new(red, green, blue)));
+
+        self.target.fill_solid(&cell, bg)?;
+
+        if look.hidden {
+            return Ok(());
+        }
+
+        if !(character.is_whitespace() || character.is_control()) {
+            let bold = look.font_weight > 400;
+            let font = match (bold, self.bold_font, self.italic_font) {
+                (true, Some(bold_font), _) => bold_font,
+                (_, _, Some(italic_font)) if look.italic => italic_font,
+                _ => self.font,
+            };
+
+            let mut buffer = [0; 4];
+            let text = Text::with_baseline(character.encode_utf8(&mut buffer), origin, MonoTextStyle::new(font, fg), Baseline::Top);
+
+            text.draw(self.target)?;
+
+            if bold && self.bold_font.is_none() {
+                text.draw(&mut self.target.translated(Point::new(1, 0)))?;
+            }
+        }
+
+        // The lines go across the whole cell, including the character spacing, and some fonts put
+        // their underline below the cell, so it is moved up into it.
+        let mut line = |offset: u32, height: u32, colour: D::Color| {
+            let height = height.max(1);
+            let offset = offset.min(cell.size.height.saturating_sub(height));
+            let area = Area::new(origin + Point::new(0, offset as i32), Size::new(cell.size.width, height));
+
+            self.target.fill_solid(&area, colour)
+        };
+
+        if look.underline {
+            line(self.font.underline.offset, self.font.underline.height, underline_colour)?;
+        }
+
+        if look.strikethrough {
+            line(self.font.strikethrough.offset, self.font.strikethrough.height, fg)?;
+        }
+
+        if look.overline {
+            line(0, 1, fg)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<D> Renderer for DrawTargetRenderer<'_, D>
+where
+    D: DrawTarget,
+    D::Color: From<Rgb888>,
+{
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        let (width, height) = terminal.dimensions();
+        let default_style = terminal.default_style();
+        let cell_size = self.cell_size();
+
+        for (idx, cell) in terminal.cells().take(width * height).enumerate() {
+            let look = Look::new(cell.style.inherits(default_style), &self.palette);
+            let origin = Point::new(
+                ((idx % width) as u32 * cell_size.width) as i32,
+                ((idx / width) as u32 * cell_size.height) as i32,
+            );
+
+            self.draw_cell(cell.character, &look, origin).map_err(|_| Error::RenderError)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::ascii::{FONT_4X6, FONT_5X8};
+    use embedded_graphics::pixelcolor::{BinaryColor, Rgb565, RgbColor};
+
+    use super::DrawTargetRenderer;
+    use crate::prelude::*;
+    use crate::style::{Ansi4, Style};
+    use crate::terminal::ConstantSize;
+
+    #[test]
+    fn colours_and_inversion() {
+        let mut terminal: ConstantSize<2, 1> = ConstantSize::new();
+        terminal.cell_mut(0, 0).expect("Cell exists").style = Style::new().bg_ansi4(Ansi4::BrightRed);
+        terminal.cell_mut(1, 0).expect("Cell exists").style = Style::new().inverted();
+
+        let mut display: MockDisplay<Rgb565> = MockDisplay::new();
+
+        DrawTargetRenderer::new(&mut display, &FONT_4X6).render(&terminal).expect("The display can be drawn to");
+
+        // xterm's bright red is pure red, and an inverted blank cell is the default foreground.
+        assert_eq!(display.get_pixel((0, 0).into()), Some(Rgb565::RED));
+        assert_eq!(display.get_pixel((4, 5).into()), Some(Rgb565::new(28, 57, 28)));
+        assert_eq!(display.affected_area().size, (8, 6).into());
+    }
+
+    #[test]
+    fn emulated_attributes() {
+        let mut terminal: ConstantSize<3, 1> = ConstantSize::new();
+        let cells = [('|', Style::new().bold()), ('|', Style::new().overlined().hidden()), ('-', Style::new().strikethrough())];
+
+        for (x, (character, style)) in cells.into_iter().enumerate() {
+            let cell = terminal.cell_mut(x, 0).expect("Cell exists");
+
+            cell.character = character;
+            cell.style = style;
+        }
+
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        DrawTargetRenderer::new(&mut display, &FONT_5X8).render(&terminal).expect("The display can be drawn to");
+
+        display.assert_pattern(&[
+            "...............",
+            "..##...........",
+            "..##...........",
+            "..##...........",
+            "..##......#####",
+            "..##...........",
+            "..##...........",
+            "...............",
+        ]);
+    }
+}
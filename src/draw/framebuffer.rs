@@ -0,0 +1,515 @@
+use crate::draw::markup::{Colours, Look};
+use crate::draw::Renderer;
+use crate::font::Font;
+use crate::style::{Palette, Underline};
+use crate::terminal::{Cell, Rectangle, TerminalConst};
+use crate::Error;
+
+/// How the colour of each pixel is stored in a [`Framebuffer`].
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+pub enum PixelFormat {
+    /// Three bytes: red, green and blue.
+    #[default]
+    Rgb888,
+    /// Three bytes: blue, green and red.
+    Bgr888,
+    /// Four bytes: red, green, blue and an unused byte.
+    Rgbx8888,
+    /// Four bytes: blue, green, red and an unused byte. This is how `XRGB8888` is laid out on
+    /// little-endian machines.
+    Bgrx8888,
+    /// Two bytes holding 5 bits of red, 6 bits of green and 5 bits of blue, least significant byte first.
+    Rgb565,
+    /// Two bytes holding 5 bits of red, 6 bits of green and 5 bits of blue, most significant byte
+    /// first. Many SPI displays expect this.
+    Rgb565Be,
+    /// One byte of brightness.
+    Gray8,
+}
+
+impl PixelFormat {
+    /// The number of bytes that each pixel takes up.
+    #[must_use]
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Gray8 => 1,
+            Self::Rgb565 | Self::Rgb565Be => 2,
+            Self::Rgb888 | Self::Bgr888 => 3,
+            Self::Rgbx8888 | Self::Bgrx8888 => 4,
+        }
+    }
+
+    /// Encode a colour in this format. Only the first [`PixelFormat::bytes_per_pixel`] bytes are used.
+    ///
+    /// ```
+    /// use tuit::draw::framebuffer::PixelFormat;
+    ///
+    /// assert_eq!(PixelFormat::Bgr888.encode((255, 128, 0)), [0, 128, 255, 0]);
+    /// assert_eq!(PixelFormat::Rgb565Be.encode((255, 0, 0)), [0xf8, 0x00, 0, 0]);
+    /// ```
+    #[must_use]
+    pub const fn encode(self, (red, green, blue): (u8, u8, u8)) -> [u8; 4] {
+        let rgb565 = ((red as u16 >> 3) << 11) | ((green as u16 >> 2) << 5) | (blue as u16 >> 3);
+
+        match self {
+            Self::Rgb888 | Self::Rgbx8888 => [red, green, blue, 0],
+            Self::Bgr888 | Self::Bgrx8888 => [blue, green, red, 0],
+            Self::Rgb565 => {
+                let [low, high] = rgb565.to_le_bytes();
+
+                [low, high, 0, 0]
+            }
+            Self::Rgb565Be => {
+                let [high, low] = rgb565.to_be_bytes();
+
+                [high, low, 0, 0]
+            }
+            Self::Gray8 => {
+                let luma = (red as u32 * 299 + green as u32 * 587 + blue as u32 * 114) / 1000;
+
+                [luma as u8, 0, 0, 0]
+            }
+        }
+    }
+}
+
+/// A caller-supplied buffer of pixels, like the memory of an LCD controller.
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Framebuffer<'a> {
+    /// The pixels, row by row.
+    pub pixels: &'a mut [u8],
+    /// The width of the framebuffer, in pixels.
+    pub width: usize,
+    /// The height of the framebuffer, in pixels.
+    pub height: usize,
+    /// The number of bytes from the start of one row to the start of the next.
+    pub stride: usize,
+    /// How each pixel is stored.
+    pub format: PixelFormat,
+}
+
+impl<'a> Framebuffer<'a> {
+    /// Create a new [`Framebuffer`] whose rows are right after each other.
+    #[must_use]
+    pub const fn new(pixels: &'a mut [u8], width: usize, height: usize, format: PixelFormat) -> Self {
+        Self {
+            pixels,
+            width,
+            height,
+            stride: width * format.bytes_per_pixel(),
+            format,
+        }
+    }
+
+    /// Set the number of bytes from the start of one row to the start of the next, for
+    /// framebuffers that pad their rows.
+    #[must_use]
+    pub const fn stride(mut self, stride: usize) -> Self {
+        self.stride = stride;
+
+        self
+    }
+
+    /// Set the colour of a pixel. Pixels outside the framebuffer are ignored.
+    pub fn set_pixel(&mut self, x: usize, y: usize, colour: (u8, u8, u8)) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let size = self.format.bytes_per_pixel();
+        let idx = y * self.stride + x * size;
+
+        if let Some(pixel) = self.pixels.get_mut(idx..idx + size) {
+            pixel.copy_from_slice(&self.format.encode(colour)[..size]);
+        }
+    }
+
+    /// Fill a [`Rectangle`] of pixels with a colour. The parts outside the framebuffer are ignored.
+    pub fn fill(&mut self, rect: Rectangle, colour: (u8, u8, u8)) {
+        for y in rect.top()..rect.bottom().min(self.height) {
+            for x in rect.left()..rect.right().min(self.width) {
+                self.set_pixel(x, y, colour);
+            }
+        }
+    }
+
+    /// Check that the buffer is big enough for the framebuffer's dimensions and stride.
+    const fn validate(&self) -> crate::Result<()> {
+        let row = self.width * self.format.bytes_per_pixel();
+
+        if self.height == 0 || row == 0 {
+            return Ok(());
+        }
+
+        let expected = (self.height - 1) * self.stride + row;
+
+        if self.stride >= row && self.pixels.len() >= expected {
+            Ok(())
+        } else {
+            Err(Error::oobi(expected - 1))
+        }
+    }
+}
+
+/// A [`Renderer`] that rasterizes a terminal into a [`Framebuffer`], for devices that have a
+/// display but no terminal.
+///
+/// Every cell is a [`Font::width`] by [`Font::height`] block of pixels, drawn with a bitmap
+/// [`Font`] ([`Font::BASIC`] by default). The ANSI colours are looked up in a [`Palette`].
+/// Cells that don't fit inside the framebuffer are cut off.
+///
+/// Bold text is drawn twice, one pixel apart, and italic text is slanted by shifting the top half
+/// of each glyph. Lines (underlines, strikethroughs and overlines) are drawn across the whole
+/// cell, dim text is blended into its background, and hidden text isn't drawn. Blinking text is
+/// drawn without blinking.
+///
+/// ```
+/// use tuit::draw::framebuffer::{Framebuffer, FramebufferRenderer, PixelFormat};
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut terminal: ConstantSize<2, 1> = ConstantSize::new();
+/// terminal.cell_mut(0, 0).expect("Cell exists").character = '|';
+///
+/// // Two 8x8 cells.
+/// let mut pixels = [0; 16 * 8 * 3];
+/// let mut renderer = FramebufferRenderer::new(Framebuffer::new(&mut pixels, 16, 8, PixelFormat::Rgb888));
+///
+/// renderer.render(&terminal).expect("The framebuffer is big enough");
+///
+/// // The bar of the '|' is in the 4th and 5th column of the first cell.
+/// let row = &renderer.framebuffer.pixels[..16 * 3];
+/// assert_eq!(row[2 * 3..3 * 3], [0, 0, 0]);
+/// assert_eq!(row[3 * 3..4 * 3], [229, 229, 229]);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct FramebufferRenderer<'a> {
+    /// The framebuffer that the terminal is drawn into.
+    pub framebuffer: Framebuffer<'a>,
+    /// The font that the characters are drawn with.
+    pub font: Font<'a>,
+    /// The colours used for the ANSI colours and the default colours.
+    pub palette: Palette,
+    /// The cells that were drawn last time, when changes are being tracked.
+    previous: Option<&'a mut [Cell]>,
+    /// The size of the terminal that was drawn last time, or `None` if everything has to be redrawn.
+    previous_size: Option<(usize, usize)>,
+    /// The pixels that were changed by the last render.
+    dirty: Option<Rectangle>,
+}
+
+impl<'a> FramebufferRenderer<'a> {
+    /// Create a new [`FramebufferRenderer`] that uses [`Font::BASIC`] and [`Palette::XTERM`], and
+    /// redraws every cell each time.
+    #[must_use]
+    pub const fn new(framebuffer: Framebuffer<'a>) -> Self {
+        Self {
+            framebuffer,
+            font: Font::BASIC,
+            palette: Palette::XTERM,
+            previous: None,
+            previous_size: None,
+            dirty: None,
+        }
+    }
+
+    /// Set the font that the characters are drawn with.
+    #[must_use]
+    pub const fn font(mut self, font: Font<'a>) -> Self {
+        self.font = font;
+
+        self
+    }
+
+    /// Set the colours used for the ANSI colours and the default colours.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self
+    }
+
+    /// Only redraw the cells that changed since the last render, using `previous` to remember the
+    /// cells that were drawn. It needs room for every cell of the terminal.
+    ///
+    /// After each render, [`FramebufferRenderer::dirty`] says which pixels changed, so that only
+    /// those need to be sent to the display.
+    ///
+    /// ```
+    /// use tuit::draw::framebuffer::{Framebuffer, FramebufferRenderer, PixelFormat};
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::{Cell, ConstantSize, Rectangle};
+    ///
+    /// let mut terminal: ConstantSize<4, 2> = ConstantSize::new();
+    /// let mut pixels = [0; 32 * 16 * 2];
+    /// let mut previous = [Cell::default(); 4 * 2];
+    ///
+    /// let mut renderer = FramebufferRenderer::new(Framebuffer::new(&mut pixels, 32, 16, PixelFormat::Rgb565))
+    ///     .track_changes(&mut previous);
+    ///
+    /// // The first render draws everything...
+    /// renderer.render(&terminal).expect("The framebuffer is big enough");
+    /// assert_eq!(renderer.dirty(), Some(Rectangle::of_size((32, 16))));
+    ///
+    /// // ...then only the cells that changed are drawn.
+    /// terminal.cell_mut(2, 1).expect("Cell exists").character = 'x';
+    /// renderer.render(&terminal).expect("The framebuffer is big enough");
+    /// assert_eq!(renderer.dirty(), Some(Rectangle::of_size((8, 8)).at((16, 8))));
+    ///
+    /// renderer.render(&terminal).expect("The framebuffer is big enough");
+    /// assert_eq!(renderer.dirty(), None);
+    /// ```
+    #[must_use]
+    pub const fn track_changes(mut self, previous: &'a mut [Cell]) -> Self {
+        self.previous = Some(previous);
+        self.previous_size = None;
+
+        self
+    }
+
+    /// The pixels that were changed by the last render, or `None` if nothing changed.
+    #[must_use]
+    pub const fn dirty(&self) -> Option<Rectangle> {
+        self.dirty
+    }
+
+    /// Redraw every cell during the next render. Use this after changing the framebuffer, the
+    /// font or the palette.
+    pub const fn invalidate(&mut self) {
+        self.previous_size = None;
+    }
+
+    /// Draw a single cell, whose style already inherits the terminal's default style.
+    fn draw_cell(&mut self, cell: &Cell, (left, top): (usize, usize)) {
+        let palette = &self.palette;
+        let look = Look::new(cell.style, palette);
+        let (width, height) = (self.font.width, self.font.height);
+
+        let Colours { foreground: fg, background: bg, underline: underline_colour } = look.colours(palette);
+        let glyph = if look.hidden { None } else { self.font.glyph(cell.character).copied() };
+
+        for y in 0..height {
+            // Slant italics by shifting the top half of the glyph to the right.
+            let shift = usize::from(look.italic && y < height / 2);
+
+            for x in 0..width {
+                let set = |x: Option<usize>| match (glyph, x) {
+                    (Some(glyph), Some(x)) => glyph.pixel(x, y, width),
+                    _ => false,
+                };
+                let glyph_x = x.checked_sub(shift);
+                let bold = look.font_weight > 400;
+
+                let colour = if look.hidden {
+                    bg
+                } else if look.underline && is_underline(look.underline_variant, x, y, height) {
+                    underline_colour
+                } else if (look.strikethrough && y == height / 2)
+                    || (look.overline && y == 0)
+                    || set(glyph_x)
+                    || (bold && set(glyph_x.and_then(|x| x.checked_sub(1))))
+                {
+                    fg
+                } else {
+                    bg
+                };
+
+                self.framebuffer.set_pixel(left + x, top + y, colour);
+            }
+        }
+    }
+}
+
+impl Renderer for FramebufferRenderer<'_> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        self.framebuffer.validate()?;
+
+        let (width, height) = terminal.dimensions();
+        let default_style = terminal.default_style();
+        let (cell_width, cell_height) = (self.font.width, self.font.height);
+
+        if let Some(previous) = &self.previous {
+            if previous.len() < width * height {
+                return Err(Error::oobi(width * height - 1));
+            }
+        }
+
+        let redraw_all = self.previous.is_none() || self.previous_size != Some((width, height));
+        // The leftmost, topmost, rightmost and bottommost cells that were drawn.
+        let mut changed: Option<(usize, usize, usize, usize)> = None;
+
+        for (idx, cell) in terminal.cells().take(width * height).enumerate() {
+            let cell = Cell {
+                style: cell.style.inherits(default_style),
+                ..*cell
+            };
+
+            if let Some(previous) = self.previous.as_deref_mut() {
+                if !redraw_all && previous[idx] == cell {
+                    continue;
+                }
+
+                previous[idx] = cell;
+            }
+
+            let (x, y) = (idx % width, idx / width);
+
+            self.draw_cell(&cell, (x * cell_width, y * cell_height));
+
+            changed = Some(match changed {
+                None => (x, y, x, y),
+                Some((left, top, right, bottom)) => (left.min(x), top.min(y), right.max(x), bottom.max(y)),
+            });
+        }
+
+        self.previous_size = Some((width, height));
+        self.dirty = changed
+            .map(|(left, top, right, bottom)| {
+                // Cells outside of the framebuffer aren't drawn, so the rectangle is clipped to it.
+                let (width, height) = (self.framebuffer.width, self.framebuffer.height);
+                let (left, right) = ((left * cell_width).min(width), ((right + 1) * cell_width).min(width));
+                let (top, bottom) = ((top * cell_height).min(height), ((bottom + 1) * cell_height).min(height));
+
+                Rectangle::new((left, top), (right, bottom))
+            })
+            .filter(|rect| rect.area() > 0);
+
+        Ok(())
+    }
+}
+
+/// Whether the pixel at the specified position of a cell is part of an underline.
+const fn is_underline(variant: Underline, x: usize, y: usize, height: usize) -> bool {
+    let bottom = height.saturating_sub(1);
+
+    match variant {
+        Underline::Single => y == bottom,
+        Underline::Double => y == bottom || y + 2 == bottom,
+        Underline::Dotted => y == bottom && x.is_multiple_of(2),
+        Underline::Dashed => y == bottom && x % 4 != 3,
+        Underline::Curly => y + (x / 2) % 2 == bottom,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::{Framebuffer, FramebufferRenderer, PixelFormat};
+    use crate::font::{Font, Glyph};
+    use crate::prelude::*;
+    use crate::style::{Ansi4, Style, Underline};
+    use crate::terminal::{Cell, ConstantSize, Rectangle};
+
+    /// A 3x3 font with a single glyph, a dot in the middle.
+    const DOT: Font = Font::new(3, 3, &[Glyph::new('.', &[0b000, 0b010, 0b000])]);
+
+    const WHITE: (u8, u8, u8) = (229, 229, 229);
+    const BLACK: (u8, u8, u8) = (0, 0, 0);
+
+    /// Draws a single cell with the [`DOT`] font, and returns its pixels as `#` (foreground),
+    /// `.` (background) and `?` (anything else) for each row.
+    fn draw(cell: Cell) -> Vec<String> {
+        let mut terminal: ConstantSize<1, 1> = ConstantSize::new();
+        *terminal.cell_mut(0, 0).expect("Cell exists") = cell;
+
+        let mut pixels = [0; 3 * 3 * 3];
+        let mut renderer = FramebufferRenderer::new(Framebuffer::new(&mut pixels, 3, 3, PixelFormat::Rgb888)).font(DOT);
+
+        renderer.render(&terminal).expect("The framebuffer is big enough");
+
+        pixels
+            .chunks(9)
+            .map(|row| {
+                row.chunks(3)
+                    .map(|pixel| match (pixel[0], pixel[1], pixel[2]) {
+                        WHITE => '#',
+                        BLACK => '.',
+                        _ => '?',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn emulated_attributes() {
+        let dot = |style: Style| draw(Cell { style, ..Cell::new('.') });
+
+        assert_eq!(dot(Style::new()), ["...", ".#.", "..."]);
+        assert_eq!(dot(Style::new().bold()), ["...", ".##", "..."]);
+        assert_eq!(dot(Style::new().inverted()), ["###", "#.#", "###"]);
+        assert_eq!(dot(Style::new().underlined()), ["...", ".#.", "###"]);
+        assert_eq!(dot(Style::new().underline_variant(Underline::Dotted)), ["...", ".#.", "#.#"]);
+        assert_eq!(dot(Style::new().strikethrough().overlined()), ["###", "###", "..."]);
+        assert_eq!(dot(Style::new().hidden().underlined()), ["...", "...", "..."]);
+        assert_eq!(dot(Style::new().dimmed()), ["...", ".?.", "..."]);
+    }
+
+    #[test]
+    fn fallback_and_palette() {
+        // Characters that aren't in the font (and have no fallback) are blank.
+        assert_eq!(draw(Cell::new('x')), ["...", "...", "..."]);
+
+        let mut terminal: ConstantSize<1, 1> = ConstantSize::new();
+        terminal.cell_mut(0, 0).expect("Cell exists").style = Style::new().bg_ansi4(Ansi4::Red);
+
+        let mut pixels = [0; 8 * 8];
+        FramebufferRenderer::new(Framebuffer::new(&mut pixels, 8, 8, PixelFormat::Gray8))
+            .render(&terminal)
+            .expect("The framebuffer is big enough");
+
+        // xterm's red is (205, 0, 0).
+        assert!(pixels.iter().all(|&luma| luma == 61));
+    }
+
+    #[test]
+    fn stride_and_clipping() {
+        let mut terminal: ConstantSize<2, 2> = ConstantSize::new();
+        terminal.default_style = Style::new().bg_rgb24(1, 2, 3);
+
+        // Only one and a half cells fit, and every row has two bytes of padding.
+        let mut pixels = [9; 12 * 8 * 3 + 11 * 2];
+        let framebuffer = Framebuffer::new(&mut pixels, 12, 8, PixelFormat::Rgb888).stride(12 * 3 + 2);
+        let mut renderer = FramebufferRenderer::new(framebuffer);
+
+        renderer.render(&terminal).expect("The framebuffer is big enough");
+
+        assert_eq!(renderer.dirty(), Some(Rectangle::of_size((12, 8))));
+        assert_eq!(pixels[..3], [1, 2, 3]);
+        assert_eq!(pixels[12 * 3..12 * 3 + 2], [9, 9]);
+        assert_eq!(pixels[12 * 3 + 2..12 * 3 + 5], [1, 2, 3]);
+    }
+
+    #[test]
+    fn changes_outside_of_the_framebuffer() {
+        let mut terminal: ConstantSize<4, 1> = ConstantSize::new();
+        let mut pixels = [0; 16 * 8 * 3];
+        let mut previous = [Cell::default(); 4];
+        let mut renderer = FramebufferRenderer::new(Framebuffer::new(&mut pixels, 16, 8, PixelFormat::Rgb888)).track_changes(&mut previous);
+
+        renderer.render(&terminal).expect("The framebuffer is big enough");
+        assert_eq!(renderer.dirty(), Some(Rectangle::of_size((16, 8))));
+
+        // Only the last cell changes, and it doesn't fit into the framebuffer.
+        terminal.cell_mut(3, 0).expect("Cell exists").character = 'x';
+
+        renderer.render(&terminal).expect("The framebuffer is big enough");
+        assert_eq!(renderer.dirty(), None);
+    }
+
+    #[test]
+    fn small_buffers() {
+        let terminal: ConstantSize<1, 1> = ConstantSize::new();
+        let mut pixels = [0; 10];
+        let mut previous = [];
+
+        assert!(FramebufferRenderer::new(Framebuffer::new(&mut pixels, 2, 2, PixelFormat::Rgb888)).render(&terminal).is_err());
+        assert!(FramebufferRenderer::new(Framebuffer::new(&mut pixels, 1, 1, PixelFormat::Rgb888))
+            .track_changes(&mut previous)
+            .render(&terminal)
+            .is_err());
+    }
+}
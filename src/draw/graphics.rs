@@ -0,0 +1,519 @@
+use core::fmt::Write;
+
+use anyhow::anyhow;
+
+use crate::draw::{AnsiRenderer, Renderer};
+use crate::style::ColourDepth;
+use crate::terminal::{Rectangle, TerminalConst};
+use crate::Error;
+
+/// A buffer of RGB pixels, row by row, three bytes (red, green, and blue) each.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct RgbImage<'a> {
+    /// The pixels of the image.
+    pub pixels: &'a [u8],
+    /// The width of the image, in pixels.
+    pub width: usize,
+    /// The height of the image, in pixels.
+    pub height: usize,
+}
+
+impl<'a> RgbImage<'a> {
+    /// Create a new [`RgbImage`]. The buffer should hold `width * height * 3` bytes.
+    #[must_use]
+    pub const fn new(pixels: &'a [u8], width: usize, height: usize) -> Self {
+        Self { pixels, width, height }
+    }
+
+    /// Get the pixel at the specified coordinates.
+    #[must_use]
+    pub fn pixel(&self, x: usize, y: usize) -> Option<(u8, u8, u8)> {
+        let idx = (y * self.width + x) * 3;
+
+        match self.pixels.get(idx..idx + 3)? {
+            &[red, green, blue] => Some((red, green, blue)),
+            _ => None,
+        }
+    }
+
+    /// Check that the buffer holds exactly as many pixels as the image's dimensions say it does.
+    const fn validate(&self) -> crate::Result<()> {
+        let expected = self.width * self.height * 3;
+
+        if self.pixels.len() == expected {
+            Ok(())
+        } else {
+            Err(Error::oobi(expected.saturating_sub(1)))
+        }
+    }
+}
+
+/// The protocol used by a [`GraphicsRenderer`] to send images to the terminal.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+#[allow(clippy::module_name_repetitions)]
+pub enum GraphicsProtocol {
+    /// The DEC Sixel protocol, supported by xterm (with `-ti vt340`), foot, mlterm, `WezTerm`, and others.
+    ///
+    /// Images are resized to the pixel size of their [`Rectangle`], and quantized to 216 colours.
+    #[default]
+    Sixel,
+    /// The Kitty graphics protocol, supported by kitty, `WezTerm`, Ghostty, and others.
+    ///
+    /// Images are sent at full quality, and scaled by the terminal.
+    Kitty,
+}
+
+/// An image, attached to a [`Rectangle`] of cells.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct ImagePlacement<'a> {
+    /// The image to draw.
+    pub image: RgbImage<'a>,
+    /// The cells that the image is stretched over.
+    pub rect: Rectangle,
+    /// The Kitty image ID, so that redrawing an image replaces the old one. Zero means no ID.
+    pub id: u32,
+}
+
+impl<'a> ImagePlacement<'a> {
+    /// Attach an image to a [`Rectangle`] of cells.
+    #[must_use]
+    pub const fn new(image: RgbImage<'a>, rect: Rectangle) -> Self {
+        Self { image, rect, id: 0 }
+    }
+
+    /// Set the Kitty image ID.
+    #[must_use]
+    pub const fn id(mut self, id: u32) -> Self {
+        self.id = id;
+
+        self
+    }
+}
+
+/// A [`Renderer`] that outputs the terminal's text like an [`AnsiRenderer`], followed by images
+/// drawn over their [`ImagePlacement::rect`] using a [`GraphicsProtocol`].
+///
+/// The images are positioned relative to the end of the text output, and the cursor is put back
+/// where the text left it after each image.
+///
+/// ```
+/// use tuit::draw::graphics::{GraphicsProtocol, GraphicsRenderer, ImagePlacement, RgbImage};
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Rectangle};
+///
+/// let logo = RgbImage::new(&[255, 0, 0, 0, 0, 255], 2, 1);
+/// let placements = [ImagePlacement::new(logo, Rectangle::of_size((2, 1)).at((1, 1)))];
+///
+/// let terminal: ConstantSize<4, 2> = ConstantSize::new();
+/// let mut output = String::new();
+///
+/// GraphicsRenderer::new(&mut output, GraphicsProtocol::Kitty, &placements)
+///     .render(&terminal)
+///     .expect("The image fits inside the terminal");
+///
+/// assert!(output.ends_with("\x1b7\r\x1b[1C\x1b_Ga=T,f=24,s=2,v=1,c=2,r=1,C=1,q=2;/wAAAAD/\x1b\\\x1b8"));
+/// ```
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct GraphicsRenderer<'a, T> {
+    /// The writer that everything is written into.
+    pub writer: T,
+    /// The protocol that images are sent with.
+    pub protocol: GraphicsProtocol,
+    /// The images to draw over the text.
+    pub placements: &'a [ImagePlacement<'a>],
+    /// The size of a single cell in pixels, used to resize images for [`GraphicsProtocol::Sixel`].
+    pub cell_size: (usize, usize),
+    /// The colours that the text is drawn with. See [`AnsiRenderer::colour_depth`].
+    pub colour_depth: ColourDepth,
+}
+
+impl<'a, T: Write> GraphicsRenderer<'a, T> {
+    /// The default [`GraphicsRenderer::cell_size`], which is common for terminals with a 10pt font.
+    pub const DEFAULT_CELL_SIZE: (usize, usize) = (10, 20);
+
+    /// Create a new [`GraphicsRenderer`].
+    #[must_use]
+    pub const fn new(writer: T, protocol: GraphicsProtocol, placements: &'a [ImagePlacement<'a>]) -> Self {
+        Self {
+            writer,
+            protocol,
+            placements,
+            cell_size: Self::DEFAULT_CELL_SIZE,
+            colour_depth: ColourDepth::TrueColour,
+        }
+    }
+
+    /// Set the size of a single cell, in pixels.
+    #[must_use]
+    pub const fn cell_size(mut self, cell_size: (usize, usize)) -> Self {
+        self.cell_size = cell_size;
+
+        self
+    }
+
+    /// Set the colours that the text is drawn with.
+    #[must_use]
+    pub const fn colour_depth(mut self, colour_depth: ColourDepth) -> Self {
+        self.colour_depth = colour_depth;
+
+        self
+    }
+}
+
+impl<T: Write> Renderer for GraphicsRenderer<'_, T> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        AnsiRenderer::new(&mut self.writer).colour_depth(self.colour_depth).render(&terminal)?;
+
+        let bounds = terminal.bounding_box();
+
+        for placement in self.placements {
+            let rect = placement.rect;
+
+            if rect.area() == 0 || !bounds.contains_rect(rect) {
+                return Err(Error::oob_with(rect.right_bottom()));
+            }
+
+            // The text output leaves the cursor on the last row, so move up and across from there.
+            let rows_up = bounds.bottom() - 1 - rect.top();
+
+            write!(self.writer, "\x1b7").map_err(|e| anyhow!(e))?;
+
+            if rows_up > 0 {
+                write!(self.writer, "\x1b[{rows_up}A").map_err(|e| anyhow!(e))?;
+            }
+
+            write!(self.writer, "\r").map_err(|e| anyhow!(e))?;
+
+            if rect.left() > 0 {
+                write!(self.writer, "\x1b[{}C", rect.left()).map_err(|e| anyhow!(e))?;
+            }
+
+            match self.protocol {
+                GraphicsProtocol::Sixel => {
+                    let (cell_width, cell_height) = self.cell_size;
+                    let size = (rect.width() * cell_width, rect.height() * cell_height);
+
+                    encode_sixel(&mut self.writer, &placement.image, size)?;
+                }
+                GraphicsProtocol::Kitty => {
+                    encode_kitty(&mut self.writer, &placement.image, placement.id, rect.dimensions())?;
+                }
+            }
+
+            write!(self.writer, "\x1b8").map_err(|e| anyhow!(e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The number of colour registers used by [`encode_sixel`]: a 6x6x6 colour cube.
+const SIXEL_COLOURS: usize = 216;
+
+/// Quantize a pixel into one of the [`SIXEL_COLOURS`] registers.
+const fn sixel_register((red, green, blue): (u8, u8, u8)) -> usize {
+    const fn level(channel: u8) -> usize {
+        (channel as usize * 5 + 127) / 255
+    }
+
+    level(red) * 36 + level(green) * 6 + level(blue)
+}
+
+/// Write a run of identical sixels, using the repeat introducer (`!`) for long runs.
+fn write_sixel_run(out: &mut impl Write, sixel: char, count: usize) -> core::fmt::Result {
+    if count > 3 {
+        write!(out, "!{count}{sixel}")
+    } else {
+        (0..count).try_for_each(|_| out.write_char(sixel))
+    }
+}
+
+/// Encode an image as a Sixel sequence, resized (using nearest-neighbour scaling) to the specified
+/// size in pixels.
+///
+/// Colours are quantized into a 6x6x6 colour cube, and only the colours used by the image are
+/// defined. Pixels are drawn opaque; nothing is left transparent.
+///
+/// ```
+/// use tuit::draw::graphics::{encode_sixel, RgbImage};
+///
+/// // A red pixel next to a blue pixel.
+/// let image = RgbImage::new(&[255, 0, 0, 0, 0, 255], 2, 1);
+/// let mut output = String::new();
+///
+/// encode_sixel(&mut output, &image, (2, 1)).expect("The image is valid");
+///
+/// assert_eq!(output, "\x1bP0;1;0q\"1;1;2;1#5;2;0;0;100#180;2;100;0;0#5?@$#180@\x1b\\");
+/// ```
+///
+/// # Errors
+///
+/// Fails if the image's buffer doesn't match its dimensions, or if the writer fails.
+pub fn encode_sixel(out: &mut impl Write, image: &RgbImage, (width, height): (usize, usize)) -> crate::Result<()> {
+    image.validate()?;
+
+    let sample = |x: usize, y: usize| -> crate::Result<usize> {
+        let source_x = ((2 * x + 1) * image.width) / (2 * width);
+        let source_y = ((2 * y + 1) * image.height) / (2 * height);
+
+        let pixel = image.pixel(source_x, source_y).ok_or_else(|| Error::oob_with((source_x, source_y)))?;
+
+        Ok(sixel_register(pixel))
+    };
+
+    write!(out, "\x1bP0;1;0q\"1;1;{width};{height}").map_err(|e| anyhow!(e))?;
+
+    if image.width == 0 || image.height == 0 || width == 0 || height == 0 {
+        return write!(out, "\x1b\\").map_err(|e| anyhow!(e).into());
+    }
+
+    let mut used = [false; SIXEL_COLOURS];
+
+    for y in 0..height {
+        for x in 0..width {
+            used[sample(x, y)?] = true;
+        }
+    }
+
+    for (register, _) in used.iter().enumerate().filter(|(_, &used)| used) {
+        let percent = |level: usize| level * 20;
+        let (red, green, blue) = (register / 36, register / 6 % 6, register % 6);
+
+        write!(out, "#{register};2;{};{};{}", percent(red), percent(green), percent(blue)).map_err(|e| anyhow!(e))?;
+    }
+
+    let bands = height.div_ceil(6);
+
+    for band in 0..bands {
+        let rows = band * 6..height.min(band * 6 + 6);
+
+        let mut band_used = [false; SIXEL_COLOURS];
+
+        for y in rows.clone() {
+            for x in 0..width {
+                band_used[sample(x, y)?] = true;
+            }
+        }
+
+        let mut first = true;
+
+        for (register, _) in band_used.iter().enumerate().filter(|(_, &used)| used) {
+            // Go back to the start of the band to draw the next colour over it.
+            if !first {
+                out.write_char('$').map_err(|e| anyhow!(e))?;
+            }
+
+            first = false;
+
+            write!(out, "#{register}").map_err(|e| anyhow!(e))?;
+
+            let (mut run, mut count) = ('?', 0);
+
+            for x in 0..width {
+                let mut bits = 0;
+
+                for (bit, y) in rows.clone().enumerate() {
+                    if sample(x, y)? == register {
+                        bits |= 1 << bit;
+                    }
+                }
+
+                let sixel = char::from(b'?' + bits);
+
+                if sixel != run && count > 0 {
+                    write_sixel_run(out, run, count).map_err(|e| anyhow!(e))?;
+                    count = 0;
+                }
+
+                run = sixel;
+                count += 1;
+            }
+
+            // Empty sixels at the end of a line don't draw anything, so they can be left out.
+            if run != '?' {
+                write_sixel_run(out, run, count).map_err(|e| anyhow!(e))?;
+            }
+        }
+
+        if band + 1 < bands {
+            out.write_char('-').map_err(|e| anyhow!(e))?;
+        }
+    }
+
+    write!(out, "\x1b\\").map_err(|e| anyhow!(e))?;
+
+    Ok(())
+}
+
+/// The largest amount of base64 data that the Kitty graphics protocol allows in a single escape code.
+const KITTY_CHUNK: usize = 4096;
+
+/// Write bytes as base64. Padding is only added if the number of bytes isn't a multiple of three.
+fn write_base64(out: &mut impl Write, bytes: &[u8]) -> core::fmt::Result {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    for chunk in bytes.chunks(3) {
+        let triple = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |triple, (idx, &byte)| triple | u32::from(byte) << (16 - idx * 8));
+
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                let sextet = (triple >> (18 - idx * 6)) & 0x3F;
+
+                out.write_char(char::from(ALPHABET[sextet as usize]))?;
+            } else {
+                out.write_char('=')?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Encode an image as a Kitty graphics protocol sequence, that transmits the image as 24-bit RGB
+/// data and displays it stretched over the specified number of columns and rows.
+///
+/// The data is split over multiple escape codes if it is too big for one. The cursor isn't moved,
+/// and the terminal is asked not to respond. If `id` is zero, the image isn't given an ID.
+///
+/// ```
+/// use tuit::draw::graphics::{encode_kitty, RgbImage};
+///
+/// let image = RgbImage::new(&[255, 0, 0], 1, 1);
+/// let mut output = String::new();
+///
+/// encode_kitty(&mut output, &image, 7, (2, 1)).expect("The image is valid");
+///
+/// assert_eq!(output, "\x1b_Ga=T,f=24,s=1,v=1,c=2,r=1,i=7,C=1,q=2;/wAA\x1b\\");
+/// ```
+///
+/// # Errors
+///
+/// Fails if the image's buffer doesn't match its dimensions, or if the writer fails.
+pub fn encode_kitty(out: &mut impl Write, image: &RgbImage, id: u32, (columns, rows): (usize, usize)) -> crate::Result<()> {
+    image.validate()?;
+
+    // Every 3 bytes turn into 4 base64 characters, so this keeps padding out of every chunk but the last.
+    let mut chunks = image.pixels.chunks(KITTY_CHUNK / 4 * 3).peekable();
+    let mut first = true;
+
+    loop {
+        let chunk = chunks.next().unwrap_or_default();
+        let more = chunks.peek().is_some();
+
+        write!(out, "\x1b_G").map_err(|e| anyhow!(e))?;
+
+        if first {
+            write!(out, "a=T,f=24,s={},v={},c={columns},r={rows},", image.width, image.height).map_err(|e| anyhow!(e))?;
+
+            if id != 0 {
+                write!(out, "i={id},").map_err(|e| anyhow!(e))?;
+            }
+
+            write!(out, "C=1,q=2").map_err(|e| anyhow!(e))?;
+
+            if more {
+                write!(out, ",m=1").map_err(|e| anyhow!(e))?;
+            }
+        } else {
+            write!(out, "m={}", u8::from(more)).map_err(|e| anyhow!(e))?;
+        }
+
+        out.write_char(';').map_err(|e| anyhow!(e))?;
+        write_base64(out, chunk).map_err(|e| anyhow!(e))?;
+        write!(out, "\x1b\\").map_err(|e| anyhow!(e))?;
+
+        first = false;
+
+        if !more {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::{encode_kitty, encode_sixel, RgbImage};
+
+    const RED: [u8; 3] = [255, 0, 0];
+    const BLUE: [u8; 3] = [0, 0, 255];
+
+    fn image_of(pixels: &[[u8; 3]]) -> Vec<u8> {
+        pixels.iter().flatten().copied().collect()
+    }
+
+    #[test]
+    fn sixel_two_colours() {
+        let pixels = image_of(&[RED, BLUE, RED, RED]);
+        let mut output = String::new();
+
+        encode_sixel(&mut output, &RgbImage::new(&pixels, 2, 2), (2, 2)).expect("Valid image");
+
+        assert_eq!(output, "\x1bP0;1;0q\"1;1;2;2#5;2;0;0;100#180;2;100;0;0#5?@$#180BA\x1b\\");
+    }
+
+    #[test]
+    fn sixel_repeats_and_bands() {
+        let pixels = image_of(&[RED; 5 * 7]);
+        let mut output = String::new();
+
+        encode_sixel(&mut output, &RgbImage::new(&pixels, 5, 7), (5, 7)).expect("Valid image");
+
+        assert_eq!(output, "\x1bP0;1;0q\"1;1;5;7#180;2;100;0;0#180!5~-#180!5@\x1b\\");
+    }
+
+    #[test]
+    fn sixel_scales_to_size() {
+        let pixels = image_of(&[RED]);
+        let mut output = String::new();
+
+        encode_sixel(&mut output, &RgbImage::new(&pixels, 1, 1), (4, 2)).expect("Valid image");
+
+        assert_eq!(output, "\x1bP0;1;0q\"1;1;4;2#180;2;100;0;0#180!4B\x1b\\");
+    }
+
+    #[test]
+    fn sixel_rejects_short_buffers() {
+        let mut output = String::new();
+
+        assert!(encode_sixel(&mut output, &RgbImage::new(&RED, 2, 1), (2, 1)).is_err());
+    }
+
+    #[test]
+    fn kitty_chunks() {
+        // 1100 pixels is 3300 bytes, which is more than the 3072 bytes that fit in one chunk.
+        let pixels = image_of(&[BLUE; 1100]);
+        let mut output = String::new();
+
+        encode_kitty(&mut output, &RgbImage::new(&pixels, 1100, 1), 0, (10, 1)).expect("Valid image");
+
+        let first_chunk = "AAD/".repeat(1024);
+        let last_chunk = "AAD/".repeat(76);
+
+        assert_eq!(
+            output,
+            format!("\x1b_Ga=T,f=24,s=1100,v=1,c=10,r=1,C=1,q=2,m=1;{first_chunk}\x1b\\\x1b_Gm=0;{last_chunk}\x1b\\")
+        );
+    }
+
+    #[test]
+    fn kitty_padding() {
+        let mut output = String::new();
+
+        // A 1x1 "image" with only two channels isn't valid...
+        assert!(encode_kitty(&mut output, &RgbImage::new(&[1, 2], 1, 1), 0, (1, 1)).is_err());
+
+        // ...but the base64 encoder still needs to pad odd lengths.
+        output.clear();
+        super::write_base64(&mut output, &[1, 2]).expect("Infallible");
+
+        assert_eq!(output, "AQI=");
+    }
+}
@@ -0,0 +1,450 @@
+use core::fmt::{Display, Write};
+
+use anyhow::anyhow;
+
+use crate::draw::markup::{Escaped, Hex, Look, Paint};
+use crate::draw::Renderer;
+use crate::style::{Palette, Style, Underline};
+use crate::terminal::{Hyperlink, TerminalConst};
+
+/// How the [`HtmlRenderer`] writes the styles of cells.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+pub enum HtmlStyling {
+    /// Every `<span>` has a `style` attribute with all of its CSS.
+    #[default]
+    Inline,
+    /// Spans use `tuit-` CSS classes, which are defined by a `<style>` element that is written
+    /// before the `<pre>`. Colours that aren't part of the [`Palette`] are still written inline.
+    Classes,
+}
+
+/// A [`Renderer`] that writes a terminal as a self-contained HTML fragment.
+///
+/// The fragment is a `<pre>` element with a `<span>` for every run of cells that share a style,
+/// and an `<a>` for every run of cells that share a [`Hyperlink`].
+///
+/// Every attribute of [`Style`] is displayed using CSS, and the ANSI colours are looked up in
+/// the renderer's [`Palette`]. Properties that a cell's style leaves unset come from the
+/// terminal's default style, and then from the palette's default colours. Dim text is blended
+/// halfway into its background, rather than made transparent, so that its background stays the same.
+///
+/// ```
+/// use tuit::draw::HtmlRenderer;
+/// use tuit::prelude::*;
+/// use tuit::style::Style;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::Text;
+///
+/// let mut terminal: ConstantSize<8, 2> = ConstantSize::new();
+/// Text::new("<Hi>").styled(Style::new().bold()).drawn(&mut terminal).expect("There is enough space");
+///
+/// let mut html = String::new();
+/// HtmlRenderer::new(&mut html).render(&terminal).expect("Infallible");
+///
+/// assert_eq!(
+///     html,
+///     "<pre style=\"color:#e5e5e5;background-color:#000000\">\
+///      <span style=\"font-weight:700\">&lt;Hi&gt;</span>    \n        </pre>"
+/// );
+///
+/// // Dim text keeps its background.
+/// let mut terminal: ConstantSize<3, 1> = ConstantSize::new();
+/// Text::new("Dim").styled(Style::new().dimmed().bg_rgb24(0, 0, 255)).drawn(&mut terminal).expect("There is enough space");
+///
+/// html.clear();
+/// HtmlRenderer::new(&mut html).render(&terminal).expect("Infallible");
+///
+/// assert!(html.contains("<span style=\"color:#7272f2;background-color:#0000ff\">Dim</span>"));
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct HtmlRenderer<T> {
+    /// The writer that the HTML is written into.
+    pub writer: T,
+    /// The colours used for the ANSI colours and the default colours.
+    pub palette: Palette,
+    /// Whether to use inline styles or CSS classes.
+    pub styling: HtmlStyling,
+}
+
+impl<T: Write> HtmlRenderer<T> {
+    /// Create a new [`HtmlRenderer`] that uses inline styles and [`Palette::XTERM`].
+    pub const fn new(writer: T) -> Self {
+        Self {
+            writer,
+            palette: Palette::XTERM,
+            styling: HtmlStyling::Inline,
+        }
+    }
+
+    /// Set the colours used for the ANSI colours and the default colours.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self
+    }
+
+    /// Set whether to use inline styles or CSS classes.
+    ///
+    /// ```
+    /// use tuit::draw::{HtmlRenderer, HtmlStyling};
+    /// use tuit::prelude::*;
+    /// use tuit::style::{Ansi4, Style};
+    /// use tuit::terminal::ConstantSize;
+    /// use tuit::widgets::builtins::Text;
+    ///
+    /// let mut terminal: ConstantSize<5, 1> = ConstantSize::new();
+    /// Text::new("Error").styled(Style::new().fg_ansi4(Ansi4::Red).italic()).drawn(&mut terminal).expect("There is enough space");
+    ///
+    /// let mut html = String::new();
+    /// HtmlRenderer::new(&mut html).styling(HtmlStyling::Classes).render(&terminal).expect("Infallible");
+    ///
+    /// assert!(html.contains(".tuit-fg-1{color:#cd0000}"));
+    /// assert!(html.ends_with("<pre class=\"tuit\"><span class=\"tuit-italic tuit-fg-1\">Error</span></pre>"));
+    /// ```
+    #[must_use]
+    pub const fn styling(mut self, styling: HtmlStyling) -> Self {
+        self.styling = styling;
+
+        self
+    }
+
+    /// Write the `<style>` element that defines the classes, including only the palette colours that are used.
+    fn write_stylesheet(&mut self, used: &Used) -> core::fmt::Result {
+        let writer = &mut self.writer;
+
+        writer.write_str("<style>\n")?;
+        writeln!(writer, ".tuit{{color:{};background-color:{}}}", Hex(self.palette.foreground), Hex(self.palette.background))?;
+        writer.write_str(".tuit-bold{font-weight:700}\n")?;
+        writer.write_str(".tuit-italic{font-style:italic}\n")?;
+
+        for decoration in 1..8 {
+            let decoration = Decoration(decoration);
+
+            writeln!(writer, ".tuit-d-{}{{text-decoration-line:{}}}", decoration.class(), decoration.css())?;
+        }
+
+        for variant in [Underline::Single, Underline::Double, Underline::Curly, Underline::Dotted, Underline::Dashed] {
+            writeln!(writer, ".tuit-ul-{}{{text-decoration-style:{}}}", variant_name(variant), variant_css(variant))?;
+        }
+
+        writer.write_str(".tuit-blink{animation:tuit-blink 1s step-end infinite}\n")?;
+        writer.write_str(".tuit-hidden{color:transparent!important}\n")?;
+        writer.write_str("@keyframes tuit-blink{50%{opacity:0}}\n")?;
+
+        for (index, &colour) in self.palette.ansi.iter().enumerate() {
+            if used.fg[index] {
+                writeln!(writer, ".tuit-fg-{index}{{color:{}}}", Hex(colour))?;
+            }
+
+            if used.bg[index] {
+                writeln!(writer, ".tuit-bg-{index}{{background-color:{}}}", Hex(colour))?;
+            }
+        }
+
+        write!(writer, "</style>")
+    }
+
+    /// Write the opening tag of a span with the specified look.
+    fn open_span(&mut self, look: &Look) -> core::fmt::Result {
+        let classes = self.styling == HtmlStyling::Classes;
+        let decoration = Decoration::of(look);
+
+        write!(self.writer, "<span")?;
+
+        if classes {
+            let mut list = List::new(&mut self.writer, " class=\"", " ");
+
+            if look.font_weight == 700 {
+                list.item("tuit-bold")?;
+            }
+
+            if look.italic {
+                list.item("tuit-italic")?;
+            }
+
+            if decoration.0 != 0 {
+                list.item(format_args!("tuit-d-{}", decoration.class()))?;
+                list.item(format_args!("tuit-ul-{}", variant_name(look.underline_variant)))?;
+            }
+
+            for (enabled, class) in [(look.blink, "tuit-blink"), (look.hidden, "tuit-hidden")] {
+                if enabled {
+                    list.item(class)?;
+                }
+            }
+
+            if let (Paint::Palette(index), false) = (look.fg, look.dim) {
+                list.item(format_args!("tuit-fg-{index}"))?;
+            }
+
+            if let Paint::Palette(index) = look.bg {
+                list.item(format_args!("tuit-bg-{index}"))?;
+            }
+
+            list.end()?;
+        }
+
+        let palette = self.palette;
+        let mut list = List::new(&mut self.writer, " style=\"", ";");
+
+        if look.font_weight != 400 && !(classes && look.font_weight == 700) {
+            list.item(format_args!("font-weight:{}", look.font_weight))?;
+        }
+
+        if !classes {
+            if look.italic {
+                list.item("font-style:italic")?;
+            }
+
+            if decoration.0 != 0 {
+                list.item(format_args!("text-decoration-line:{}", decoration.css()))?;
+                list.item(format_args!("text-decoration-style:{}", variant_css(look.underline_variant)))?;
+            }
+        }
+
+        if let Some(colour) = look.underline_colour.rgb(&palette) {
+            list.item(format_args!("text-decoration-color:{}", Hex(colour)))?;
+        }
+
+        if look.blink && !classes {
+            list.item("animation:tuit-blink 1s step-end infinite")?;
+        }
+
+        // Dim text is always written with an inline colour, blended into its background.
+        if look.dim {
+            list.item(format_args!("color:{}", Hex(look.colours(&palette).foreground)))?;
+        }
+
+        let fg = if look.dim { Paint::Default } else { look.fg };
+
+        for (paint, property) in [(fg, "color"), (look.bg, "background-color")] {
+            let inline = match paint {
+                Paint::Palette(_) => !classes,
+                Paint::Rgb(_) => true,
+                Paint::Default => false,
+            };
+
+            if let (true, Some(colour)) = (inline, paint.rgb(&palette)) {
+                list.item(format_args!("{property}:{}", Hex(colour)))?;
+            }
+        }
+
+        if look.hidden && !classes {
+            list.item("color:transparent")?;
+        }
+
+        list.end()?;
+
+        write!(self.writer, ">")
+    }
+
+    /// Close whatever `open` opened.
+    fn close(&mut self, open: Option<(Look, Option<Hyperlink>)>) -> core::fmt::Result {
+        let Some((look, hyperlink)) = open else {
+            return Ok(());
+        };
+
+        if look != Look::PLAIN {
+            write!(self.writer, "</span>")?;
+        }
+
+        if hyperlink.is_some() {
+            write!(self.writer, "</a>")?;
+        }
+
+        Ok(())
+    }
+
+    fn render_html(&mut self, terminal: &impl TerminalConst) -> core::fmt::Result {
+        let (width, height) = terminal.dimensions();
+        let default_style = terminal.default_style();
+        let look_of = |style: Style, palette: &Palette| Look::new(style.inherits(default_style), palette);
+
+        // Find out which parts of the stylesheet are needed first.
+        let mut used = Used {
+            fg: [false; 256],
+            bg: [false; 256],
+            blink: false,
+        };
+
+        for cell in terminal.cells().take(width * height) {
+            let look = look_of(cell.style, &self.palette);
+
+            if let (Paint::Palette(index), false) = (look.fg, look.dim) {
+                used.fg[index as usize] = true;
+            }
+
+            if let Paint::Palette(index) = look.bg {
+                used.bg[index as usize] = true;
+            }
+
+            used.blink |= look.blink;
+        }
+
+        match self.styling {
+            HtmlStyling::Classes => {
+                self.write_stylesheet(&used)?;
+                write!(self.writer, "<pre class=\"tuit\">")?;
+            }
+            HtmlStyling::Inline => {
+                if used.blink {
+                    write!(self.writer, "<style>@keyframes tuit-blink{{50%{{opacity:0}}}}</style>")?;
+                }
+
+                write!(self.writer, "<pre style=\"color:{};background-color:{}\">", Hex(self.palette.foreground), Hex(self.palette.background))?;
+            }
+        }
+
+        let mut cells = terminal.cells();
+        let mut open = None;
+
+        for y in 0..height {
+            if y != 0 {
+                // Links end with their row, but spans carry on over the newline when they can.
+                if matches!(open, Some((_, Some(_)))) {
+                    self.close(open.take())?;
+                }
+
+                writeln!(self.writer)?;
+            }
+
+            let mut continuation = false;
+
+            for cell in cells.by_ref().take(width) {
+                // The cell after a wide character is covered by it.
+                if continuation {
+                    continuation = false;
+
+                    continue;
+                }
+
+                continuation = cell.width() == 2;
+
+                let key = (look_of(cell.style, &self.palette), cell.hyperlink);
+
+                if open != Some(key) {
+                    self.close(open.take())?;
+
+                    if let Some(hyperlink) = key.1 {
+                        write!(self.writer, "<a href=\"{}\">", Escaped(hyperlink.uri))?;
+                    }
+
+                    if key.0 != Look::PLAIN {
+                        self.open_span(&key.0)?;
+                    }
+
+                    open = Some(key);
+                }
+
+                let character = if cell.character.is_whitespace() || cell.character.is_control() {
+                    ' '
+                } else {
+                    cell.character
+                };
+
+                write!(self.writer, "{}", Escaped(character.encode_utf8(&mut [0; 4])))?;
+            }
+        }
+
+        self.close(open)?;
+
+        write!(self.writer, "</pre>")
+    }
+}
+
+impl<T: Write> Renderer for HtmlRenderer<T> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        self.render_html(&terminal).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+}
+
+/// The palette colours (and animations) that a terminal uses, so that the stylesheet only needs to
+/// include those.
+struct Used {
+    fg: [bool; 256],
+    bg: [bool; 256],
+    blink: bool,
+}
+
+/// A combination of lines (underline, strikethrough and overline), as bit flags.
+#[derive(Copy, Clone)]
+struct Decoration(u8);
+
+impl Decoration {
+    const fn of(look: &Look) -> Self {
+        Self(look.underline as u8 | (look.strikethrough as u8) << 1 | (look.overline as u8) << 2)
+    }
+
+    /// The suffix of the decoration's class, like `us` for underline and strikethrough.
+    const fn class(self) -> &'static str {
+        ["", "u", "s", "us", "o", "uo", "so", "uso"][self.0 as usize]
+    }
+
+    /// The value of the decoration's `text-decoration-line`.
+    const fn css(self) -> &'static str {
+        [
+            "none",
+            "underline",
+            "line-through",
+            "underline line-through",
+            "overline",
+            "underline overline",
+            "line-through overline",
+            "underline line-through overline",
+        ][self.0 as usize]
+    }
+}
+
+const fn variant_name(variant: Underline) -> &'static str {
+    match variant {
+        Underline::Single => "single",
+        Underline::Double => "double",
+        Underline::Curly => "curly",
+        Underline::Dotted => "dotted",
+        Underline::Dashed => "dashed",
+    }
+}
+
+const fn variant_css(variant: Underline) -> &'static str {
+    match variant {
+        Underline::Single => "solid",
+        Underline::Double => "double",
+        Underline::Curly => "wavy",
+        Underline::Dotted => "dotted",
+        Underline::Dashed => "dashed",
+    }
+}
+
+/// Writes the items of an HTML attribute (like the classes of `class`), but only writes the
+/// attribute itself when it has any items.
+struct List<'a, W> {
+    writer: &'a mut W,
+    start: &'static str,
+    separator: &'static str,
+    empty: bool,
+}
+
+impl<'a, W: Write> List<'a, W> {
+    const fn new(writer: &'a mut W, start: &'static str, separator: &'static str) -> Self {
+        Self { writer, start, separator, empty: true }
+    }
+
+    fn item(&mut self, item: impl Display) -> core::fmt::Result {
+        self.writer.write_str(if self.empty { self.start } else { self.separator })?;
+        self.empty = false;
+
+        write!(self.writer, "{item}")
+    }
+
+    fn end(self) -> core::fmt::Result {
+        if self.empty {
+            Ok(())
+        } else {
+            self.writer.write_char('"')
+        }
+    }
+}
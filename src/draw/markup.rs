@@ -0,0 +1,165 @@
+//! Resolved styles, which are shared by the renderers that draw styles themselves, and escaping
+//! for the renderers that write markup.
+
+use core::fmt::{Display, Formatter, Write};
+
+use crate::style::{Colour, Palette, Style, Underline};
+
+/// A colour, as far as markup is concerned.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Paint {
+    /// The palette's default colour.
+    Default,
+    /// One of the palette's ANSI colours.
+    Palette(u8),
+    /// A colour that is the same in every palette.
+    Rgb((u8, u8, u8)),
+}
+
+impl Paint {
+    pub const fn new(colour: Option<Colour>) -> Self {
+        match colour {
+            None | Some(Colour::TerminalDefault) => Self::Default,
+            Some(Colour::Ansi16(colour)) => Self::Palette(colour as u8),
+            Some(Colour::Ansi256(index)) => Self::Palette(index),
+            Some(Colour::Rgb24(red, green, blue)) => Self::Rgb((red, green, blue)),
+            Some(Colour::Luma8(luma)) => Self::Rgb((luma, luma, luma)),
+        }
+    }
+
+    pub const fn rgb(self, palette: &Palette) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::Default => None,
+            Self::Palette(index) => Some(palette.ansi[index as usize]),
+            Self::Rgb(rgb) => Some(rgb),
+        }
+    }
+}
+
+/// A fully resolved [`Style`], which is what decides whether neighbouring cells can share a span.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)] // They are independent attributes, not states.
+pub struct Look {
+    pub fg: Paint,
+    pub bg: Paint,
+    pub font_weight: u16,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub overline: bool,
+    pub underline_variant: Underline,
+    pub underline_colour: Paint,
+    pub dim: bool,
+    pub blink: bool,
+    pub hidden: bool,
+}
+
+impl Look {
+    /// The look of an unstyled cell, which doesn't need a span.
+    pub const PLAIN: Self = Self::new(Style::new(), &Palette::XTERM);
+
+    pub const fn new(style: Style, palette: &Palette) -> Self {
+        let (mut fg, mut bg) = (Paint::new(style.fg_colour), Paint::new(style.bg_colour));
+
+        if matches!(style.invert, Some(true)) {
+            // The default colours have to be spelled out once they're swapped.
+            let swapped_fg = match bg {
+                Paint::Default => Paint::Rgb(palette.background),
+                paint => paint,
+            };
+
+            bg = match fg {
+                Paint::Default => Paint::Rgb(palette.foreground),
+                paint => paint,
+            };
+            fg = swapped_fg;
+        }
+
+        let underline = matches!(style.underline, Some(true));
+
+        Self {
+            fg,
+            bg,
+            font_weight: match style.font_weight {
+                Some(weight) => weight,
+                None => 400,
+            },
+            italic: matches!(style.italic, Some(true)),
+            underline,
+            strikethrough: matches!(style.strikethrough, Some(true)),
+            overline: matches!(style.overline, Some(true)),
+            underline_variant: match style.underline_variant {
+                Some(variant) if underline => variant,
+                _ => Underline::Single,
+            },
+            underline_colour: if underline { Paint::new(style.underline_colour) } else { Paint::Default },
+            dim: matches!(style.dim, Some(true)),
+            blink: matches!(style.blink, Some(true)),
+            hidden: matches!(style.hidden, Some(true)),
+        }
+    }
+
+    /// The actual foreground, background and underline colours, for renderers that draw pixels.
+    /// Dim text is blended halfway into its background.
+    pub const fn colours(&self, palette: &Palette) -> Colours {
+        let background = match self.bg.rgb(palette) {
+            Some(colour) => colour,
+            None => palette.background,
+        };
+        let mut foreground = match self.fg.rgb(palette) {
+            Some(colour) => colour,
+            None => palette.foreground,
+        };
+
+        if self.dim {
+            let ((r1, g1, b1), (r2, g2, b2)) = (foreground, background);
+
+            foreground = (r1.midpoint(r2), g1.midpoint(g2), b1.midpoint(b2));
+        }
+
+        let underline = match self.underline_colour.rgb(palette) {
+            Some(colour) => colour,
+            None => foreground,
+        };
+
+        Colours { foreground, background, underline }
+    }
+}
+
+/// The colours that a [`Look`] is drawn with.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Colours {
+    pub foreground: (u8, u8, u8),
+    pub background: (u8, u8, u8),
+    pub underline: (u8, u8, u8),
+}
+
+/// Displays a colour as `#rrggbb`.
+pub struct Hex(pub (u8, u8, u8));
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (red, green, blue) = self.0;
+
+        write!(f, "#{red:02x}{green:02x}{blue:02x}")
+    }
+}
+
+/// Displays text with the characters that are special in HTML and XML escaped.
+pub struct Escaped<'a>(pub &'a str);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for character in self.0.chars() {
+            match character {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                character => f.write_char(character)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+//! # The draw module is responsible for the traits that let you draw to the screen
+//!
+//! Tuit itself does not handle I/O by itself. This means that Tuit users are left responsible
+//! for rendering the terminal as well as handling input and output.
+//!
+//! ## Example
+//! ```compile_fail
+//! use tuit::errors::Error;
+//! use tuit::prelude::*;
+//!
+//! #[derive(GpuMagic)]
+//! struct MyGPU {}
+//!
+//! impl Renderer for MyGPU {
+//!     fn render(&mut self, terminal: impl TerminalConst) -> tuit::Result<()> {
+//!         for character in terminal.cells() {
+//!             self.gpu_magic(character)
+//!         }
+//!
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+#[cfg(feature = "ansi_renderer")]
+use core::fmt::{Formatter, Write};
+#[cfg(feature = "ansi_renderer")]
+use anyhow::anyhow;
+#[cfg(feature = "ansi_renderer")]
+use crate::style::{Colour, ColourDepth, Style};
+#[cfg(feature = "ansi_renderer")]
+use crate::terminal::{Cell, Hyperlink};
+use crate::terminal::TerminalConst;
+
+/// Pixel-perfect images for terminals that support the Sixel or Kitty graphics protocols.
+///
+/// The [`graphics::GraphicsRenderer`] draws the terminal's text exactly like an [`AnsiRenderer`],
+/// and then draws each [`graphics::ImagePlacement`] on top of its rectangle of cells. The encoders
+/// are also available on their own, as [`graphics::encode_sixel`] and [`graphics::encode_kitty`].
+#[cfg(feature = "ansi_renderer")]
+pub mod graphics;
+/// Records terminals into asciicast v2 files, with the [`asciicast::AsciicastRecorder`], so that they
+/// can be replayed by asciinema.
+#[cfg(feature = "ansi_renderer")]
+pub mod asciicast;
+/// The [`PlainTextRenderer`], which writes only the characters of a terminal.
+pub mod plain_text;
+/// The [`HtmlRenderer`], which writes a terminal as an HTML fragment.
+pub mod html;
+mod markup;
+/// The [`SvgRenderer`], which draws a terminal as an SVG image.
+pub mod svg;
+/// Draws terminals into pixel buffers, for devices that have a display but no terminal.
+///
+/// The [`framebuffer::FramebufferRenderer`] rasterizes each cell with a bitmap
+/// [`Font`](crate::font::Font) into a [`framebuffer::Framebuffer`], which can use any of the
+/// [`framebuffer::PixelFormat`]s. It can also keep track of the cells it drew, so that only the
+/// changed part of the display needs to be updated.
+pub mod framebuffer;
+/// Draws terminals onto `embedded-graphics` displays, with the [`embedded::DrawTargetRenderer`].
+#[cfg(feature = "embedded_graphics")]
+pub mod embedded;
+
+pub use html::{HtmlRenderer, HtmlStyling};
+pub use plain_text::PlainTextRenderer;
+pub use svg::SvgRenderer;
+
+/// This trait is written by the implementor and is responsible for rendering the terminal's data
+/// to the screen.
+///
+/// It only has one method, which is [`Renderer::render`].
+///
+/// The method receives a reference to a type that implements the [`TerminalConst`] trait, and uses the data within to render the terminal.
+///
+/// ```feature,stdout_render
+/// use tuit::draw::Target;
+/// use tuit::terminal::ConstantSize;
+///
+/// let my_terminal = ConstantSize::<20, 20>::new();
+/// let mut stdout = std::io::stdout();
+///
+/// stdout.render(&my_terminal).expect("Failed to draw to stdout");
+/// ```
+///
+pub trait Renderer {
+    /// This method gets called when the implementor calls [`TerminalConst::display`]
+    ///
+    /// ## Dummy render implementation:
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::Cell;
+    /// struct MyGPU;
+    ///
+    /// impl MyGPU {
+    ///     pub fn gpu_magic(&mut self, character: &Cell) {
+    ///         // Huzzah!
+    ///     }
+    /// }
+    ///
+    /// impl Renderer for MyGPU {
+    ///     fn render(&mut self, terminal: impl TerminalConst) -> tuit::Result<()> {
+    ///         let cells = terminal.cells();
+    ///
+    ///         // Do some magic to render characters!
+    ///         for cell in cells {
+    ///             self.gpu_magic(cell)
+    ///         }
+    ///
+    ///         return Ok(());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// When you implement [`Renderer`], you can return an [`Err`] that will help you better cope
+    /// with render failures and may help with debugging.
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()>;
+}
+
+/// Doesn't really do anything when [`Renderer::render`] is called. I mean... what would you
+/// expect a struct called [`DummyTarget`] to accomplish? World peace?
+pub struct DummyTarget;
+
+impl Renderer for DummyTarget {
+    fn render(&mut self, _terminal: impl TerminalConst) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ansi_renderer")]
+/// A [`Renderer`] that takes in a writer and outputs ANSI escape codes to it to use for formatting.
+///
+/// Colours that can't be displayed at the renderer's [`ColourDepth`] are converted to the closest
+/// ones that can.
+///
+/// ```
+/// use tuit::draw::AnsiRenderer;
+/// use tuit::prelude::*;
+/// use tuit::style::{ColourDepth, Style};
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut terminal: ConstantSize<1, 1> = ConstantSize::new();
+/// terminal.cell_mut(0, 0).expect("Cell exists").style = Style::new().fg_rgb24(255, 0, 0);
+///
+/// let mut output = String::new();
+/// AnsiRenderer::new(&mut output).colour_depth(ColourDepth::Ansi16).render(&terminal).expect("Infallible");
+///
+/// // Bright red, instead of a true colour escape code.
+/// assert!(output.contains("\x1b[91m"));
+/// ```
+///
+/// Cells with a [`Hyperlink`] are wrapped in OSC 8 sequences, which most modern terminals display
+/// as clickable links. Links always end with their row.
+pub struct AnsiRenderer<T> {
+    /// The writer that the ANSI escape codes are written into.
+    pub writer: T,
+    /// The colours that the terminal on the other end of the writer can display.
+    pub colour_depth: ColourDepth,
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl<T: Write> AnsiRenderer<T> {
+    /// Create a new [`AnsiRenderer`] that outputs true colour.
+    pub const fn new(writer: T) -> Self {
+        Self {
+            writer,
+            colour_depth: ColourDepth::TrueColour,
+        }
+    }
+
+    /// Set the colours that the terminal can display.
+    #[must_use]
+    pub const fn colour_depth(mut self, colour_depth: ColourDepth) -> Self {
+        self.colour_depth = colour_depth;
+
+        self
+    }
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl<T: Write> Renderer for AnsiRenderer<T> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        let terminal_width = terminal.width();
+
+        let characters = terminal.cells();
+        let mut hyperlink = None;
+        // What the cells so far have left displayed, to know which attributes need turning off.
+        let mut shown = Style::new();
+        let mut continuation = false;
+
+        for (idx, character_cell) in characters.enumerate() {
+            let mut character_cell = *character_cell;
+
+            character_cell.style = character_cell.style.downsampled(self.colour_depth);
+
+            if idx % terminal_width == 0 {
+                // Links end with their row, so that they don't cover the newline.
+                if hyperlink.take().is_some() {
+                    write!(self.writer, "{}", Hyperlink::END).map_err(|e| anyhow!(e))?;
+                }
+
+                let style: anstyle::Style = character_cell.style.into();
+                write!(self.writer, "{style:#}").map_err(|e| anyhow!(e))?;
+                writeln!(self.writer).map_err(|e| anyhow!(e))?;
+                write!(self.writer, "{style}").map_err(|e| anyhow!(e))?;
+
+                if style != anstyle::Style::new() {
+                    shown = Style::new();
+                }
+
+                continuation = false;
+            }
+
+            // The terminal draws a wide character over the cell to its right as well.
+            if continuation {
+                continuation = false;
+
+                continue;
+            }
+
+            continuation = character_cell.width() == 2;
+
+            // Protect against alignment issues that can arise from characters
+            // like `\0` or `\t` by replacing them with a space.
+            if character_cell.character.is_whitespace() || character_cell.character.is_control() {
+                character_cell.character = ' ';
+            }
+
+            if character_cell.hyperlink != hyperlink {
+                match character_cell.hyperlink {
+                    Some(link) => write!(self.writer, "{link}").map_err(|e| anyhow!(e))?,
+                    None => write!(self.writer, "{}", Hyperlink::END).map_err(|e| anyhow!(e))?,
+                }
+
+                hyperlink = character_cell.hyperlink;
+            }
+
+            write!(self.writer, "{}{character_cell}", OffCodes::new(character_cell.style, shown)).map_err(|e| anyhow!(e))?;
+            shown = character_cell.style.inherits(shown);
+        }
+
+        if hyperlink.is_some() {
+            write!(self.writer, "{}", Hyperlink::END).map_err(|e| anyhow!(e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The escape codes that turn off the attributes that a style explicitly turns off (or sets to
+/// the terminal's default colours), which `anstyle` leaves out, after the `shown` style.
+///
+/// SGR 22 turns off both bold and dim text, so when only one of them is turned off, the other is
+/// turned back on if it is still shown.
+#[cfg(feature = "ansi_renderer")]
+pub(crate) struct OffCodes {
+    style: Style,
+    shown: Style,
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl OffCodes {
+    pub(crate) const fn new(style: Style, shown: Style) -> Self {
+        Self { style, shown }
+    }
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl core::fmt::Display for OffCodes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self { style, shown } = self;
+        let bold = |weight: Option<u16>| weight.is_some_and(|weight| weight >= 700);
+        let turns_off = |value: Option<bool>, shown: Option<bool>| value == Some(false) && shown != Some(false);
+
+        let unbolds = style.font_weight.is_some() && !bold(style.font_weight) && bold(shown.font_weight);
+
+        if unbolds || turns_off(style.dim, shown.dim) {
+            f.write_str("\x1b[22m")?;
+
+            if style.font_weight.is_none() && bold(shown.font_weight) {
+                f.write_str("\x1b[1m")?;
+            }
+
+            if style.dim.is_none() && shown.dim == Some(true) {
+                f.write_str("\x1b[2m")?;
+            }
+        }
+
+        let attributes = [
+            (style.italic, shown.italic, 23),
+            (style.underline, shown.underline, 24),
+            (style.blink, shown.blink, 25),
+            (style.invert, shown.invert, 27),
+            (style.hidden, shown.hidden, 28),
+            (style.strikethrough, shown.strikethrough, 29),
+        ];
+
+        for (value, shown, code) in attributes {
+            if turns_off(value, shown) {
+                write!(f, "\x1b[{code}m")?;
+            }
+        }
+
+        let colours = [
+            (style.fg_colour, shown.fg_colour, 39),
+            (style.bg_colour, shown.bg_colour, 49),
+            (style.underline_colour, shown.underline_colour, 59),
+        ];
+
+        for (colour, shown, code) in colours {
+            if colour == Some(Colour::TerminalDefault) && shown != Some(Colour::TerminalDefault) {
+                write!(f, "\x1b[{code}m")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl core::fmt::Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let style: anstyle::Style = self.style.into();
+
+        write!(f, "{style}")?;
+
+        // `anstyle` has no overline, so it's written separately.
+        match self.style.overline {
+            Some(true) => write!(f, "\x1b[53m")?,
+            Some(false) => write!(f, "\x1b[55m")?,
+            None => {}
+        }
+
+        write!(f, "{}", self.character)
+    }
+}
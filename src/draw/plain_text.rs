@@ -0,0 +1,168 @@
+use core::fmt::Write;
+
+use anyhow::anyhow;
+
+use crate::draw::Renderer;
+use crate::terminal::{Cell, TerminalConst};
+
+/// A [`Renderer`] that only writes the characters of a terminal, without any escape codes. Useful
+/// for logs, CI artifacts, snapshot tests and screen readers.
+///
+/// Every row ends with a newline. Control characters and whitespace are written as spaces, and the
+/// cell after a wide character (see [`Cell::width`]) is treated as its continuation, so it is left
+/// out to keep the columns lined up.
+///
+/// ```
+/// use tuit::draw::PlainTextRenderer;
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::Text;
+///
+/// let mut terminal: ConstantSize<8, 3> = ConstantSize::new();
+/// // Wide characters are followed by a cell for their second column.
+/// Text::new("Hi 世 界 !").drawn(&mut terminal).expect("There is enough space");
+///
+/// let mut output = String::new();
+/// PlainTextRenderer::new(&mut output).render(&terminal).expect("Infallible");
+///
+/// assert_eq!(output, "Hi 世界!\n        \n        \n");
+///
+/// let mut output = String::new();
+/// PlainTextRenderer::new(&mut output)
+///     .trim_trailing_whitespace(true)
+///     .skip_blank_rows(true)
+///     .render(&terminal)
+///     .expect("Infallible");
+///
+/// assert_eq!(output, "Hi 世界!\n");
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct PlainTextRenderer<T> {
+    /// The writer that the text is written into.
+    pub writer: T,
+    /// Whether to leave out the whitespace at the end of each row.
+    pub trim_trailing_whitespace: bool,
+    /// Whether to leave out rows that only contain whitespace.
+    pub skip_blank_rows: bool,
+    /// The character written in place of the continuation of a wide character. When it is `None`,
+    /// continuations are left out, which keeps the columns lined up in a monospaced font.
+    pub continuation: Option<char>,
+}
+
+impl<T: Write> PlainTextRenderer<T> {
+    /// Create a new [`PlainTextRenderer`] that writes every row in full.
+    pub const fn new(writer: T) -> Self {
+        Self {
+            writer,
+            trim_trailing_whitespace: false,
+            skip_blank_rows: false,
+            continuation: None,
+        }
+    }
+
+    /// Set whether to leave out the whitespace at the end of each row.
+    #[must_use]
+    pub const fn trim_trailing_whitespace(mut self, trim_trailing_whitespace: bool) -> Self {
+        self.trim_trailing_whitespace = trim_trailing_whitespace;
+
+        self
+    }
+
+    /// Set whether to leave out rows that only contain whitespace.
+    #[must_use]
+    pub const fn skip_blank_rows(mut self, skip_blank_rows: bool) -> Self {
+        self.skip_blank_rows = skip_blank_rows;
+
+        self
+    }
+
+    /// Set the character that is written in place of the continuation of a wide character.
+    ///
+    /// ```
+    /// use tuit::draw::PlainTextRenderer;
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::ConstantSize;
+    ///
+    /// let mut terminal: ConstantSize<3, 1> = ConstantSize::new();
+    /// terminal.cell_mut(0, 0).expect("Cell exists").character = '漢';
+    ///
+    /// let mut output = String::new();
+    /// PlainTextRenderer::new(&mut output).continuation(Some('_')).render(&terminal).expect("Infallible");
+    ///
+    /// assert_eq!(output, "漢_ \n");
+    /// ```
+    #[must_use]
+    pub const fn continuation(mut self, continuation: Option<char>) -> Self {
+        self.continuation = continuation;
+
+        self
+    }
+
+    /// Write a single row, followed by a newline.
+    fn render_row<'a>(&mut self, row: impl Iterator<Item = &'a Cell>) -> crate::Result<()> {
+        // Whitespace is held back until something else is written, so that it can be trimmed.
+        let mut pending_spaces = 0;
+        let mut blank = true;
+        let mut continuation = false;
+
+        for cell in row {
+            let character = if continuation {
+                continuation = false;
+
+                match self.continuation {
+                    Some(character) => character,
+                    None => continue,
+                }
+            } else {
+                continuation = cell.width() == 2;
+
+                cell.character
+            };
+
+            if character.is_whitespace() || character.is_control() {
+                pending_spaces += 1;
+
+                continue;
+            }
+
+            self.write_spaces(pending_spaces)?;
+            self.writer.write_char(character).map_err(|e| anyhow!(e))?;
+
+            pending_spaces = 0;
+            blank = false;
+        }
+
+        if blank && self.skip_blank_rows {
+            return Ok(());
+        }
+
+        if !self.trim_trailing_whitespace {
+            self.write_spaces(pending_spaces)?;
+        }
+
+        writeln!(self.writer).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+
+    fn write_spaces(&mut self, count: usize) -> crate::Result<()> {
+        for _ in 0..count {
+            self.writer.write_char(' ').map_err(|e| anyhow!(e))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Write> Renderer for PlainTextRenderer<T> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        let (width, height) = terminal.dimensions();
+        let mut cells = terminal.cells();
+
+        for _ in 0..height {
+            self.render_row(cells.by_ref().take(width))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,552 @@
+use core::fmt::Write;
+use core::ops::Range;
+
+use anyhow::anyhow;
+
+use crate::draw::markup::{Escaped, Hex, Look, Paint};
+use crate::draw::Renderer;
+use crate::style::{Palette, Underline};
+use crate::terminal::{Cell, Hyperlink, TerminalConst};
+
+/// The height of the title bar that is drawn when [`SvgRenderer::chrome`] is enabled, in pixels.
+const CHROME_HEIGHT: u32 = 32;
+
+/// A [`Renderer`] that writes an SVG image of a terminal, for screenshots in documentation.
+///
+/// Every character is placed on a grid of [`SvgRenderer::cell_size`] pixels, so the columns line up
+/// no matter which monospace font ends up being used. Backgrounds are drawn as rectangles, and
+/// underlines, strikethroughs and overlines are drawn as lines. The image can optionally be drawn
+/// inside a window, with a title bar.
+///
+/// The output only depends on the terminal and the renderer's settings, which makes it suitable for
+/// golden-file tests.
+///
+/// ```
+/// use tuit::draw::SvgRenderer;
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::Text;
+///
+/// let mut terminal: ConstantSize<4, 1> = ConstantSize::new();
+/// Text::new("Hi!").drawn(&mut terminal).expect("There is enough space");
+///
+/// let mut svg = String::new();
+/// SvgRenderer::new(&mut svg).font_family("monospace").render(&terminal).expect("Infallible");
+///
+/// assert_eq!(
+///     svg,
+///     "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"36\" height=\"18\" viewBox=\"0 0 36 18\" font-family=\"monospace\" font-size=\"15\">\n\
+///      <rect width=\"36\" height=\"18\" fill=\"#000000\"/>\n\
+///      <g fill=\"#e5e5e5\">\n\
+///      <text x=\"0 9 18\" y=\"14\">Hi!</text>\n\
+///      </g>\n\
+///      </svg>\n"
+/// );
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct SvgRenderer<T> {
+    /// The writer that the SVG is written into.
+    pub writer: T,
+    /// The colours used for the ANSI colours and the default colours.
+    pub palette: Palette,
+    /// The CSS `font-family` of the text.
+    pub font_family: &'static str,
+    /// The size of the text, in pixels.
+    pub font_size: u16,
+    /// The width and height of every cell, in pixels.
+    pub cell_size: (u16, u16),
+    /// The space around the cells, in pixels.
+    pub padding: u16,
+    /// Whether to draw the terminal inside a window, with a title bar.
+    pub chrome: bool,
+    /// The title in the title bar, when [`SvgRenderer::chrome`] is enabled.
+    pub title: &'static str,
+}
+
+impl<T: Write> SvgRenderer<T> {
+    /// Create a new [`SvgRenderer`] with 15 pixel text on a 9 by 18 pixel grid, and no window.
+    pub const fn new(writer: T) -> Self {
+        Self {
+            writer,
+            palette: Palette::XTERM,
+            font_family: "ui-monospace, Menlo, Consolas, 'DejaVu Sans Mono', monospace",
+            font_size: 15,
+            cell_size: (9, 18),
+            padding: 0,
+            chrome: false,
+            title: "",
+        }
+    }
+
+    /// Set the colours used for the ANSI colours and the default colours.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self
+    }
+
+    /// Set the CSS `font-family` of the text.
+    #[must_use]
+    pub const fn font_family(mut self, font_family: &'static str) -> Self {
+        self.font_family = font_family;
+
+        self
+    }
+
+    /// Set the size of the text, in pixels. The cells don't change size along with it.
+    #[must_use]
+    pub const fn font_size(mut self, font_size: u16) -> Self {
+        self.font_size = font_size;
+
+        self
+    }
+
+    /// Set the width and height of every cell, in pixels.
+    #[must_use]
+    pub const fn cell_size(mut self, cell_size: (u16, u16)) -> Self {
+        self.cell_size = cell_size;
+
+        self
+    }
+
+    /// Set the space around the cells, in pixels.
+    #[must_use]
+    pub const fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+
+        self
+    }
+
+    /// Set whether to draw the terminal inside a window, with a title bar.
+    ///
+    /// ```
+    /// use tuit::draw::SvgRenderer;
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::ConstantSize;
+    ///
+    /// let terminal: ConstantSize<10, 2> = ConstantSize::new();
+    ///
+    /// let mut svg = String::new();
+    /// SvgRenderer::new(&mut svg).chrome(true).title("cargo run").padding(8).render(&terminal).expect("Infallible");
+    ///
+    /// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"106\" height=\"84\""));
+    /// assert!(svg.contains(">cargo run</text>"));
+    /// ```
+    #[must_use]
+    pub const fn chrome(mut self, chrome: bool) -> Self {
+        self.chrome = chrome;
+
+        self
+    }
+
+    /// Set the title in the title bar, which is only drawn when [`SvgRenderer::chrome`] is enabled.
+    #[must_use]
+    pub const fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+
+        self
+    }
+
+    /// Draw the window's title bar.
+    fn write_chrome(&mut self, image_width: u32) -> core::fmt::Result {
+        let centre = CHROME_HEIGHT / 2;
+
+        for (index, colour) in ["#ff5f57", "#febc2e", "#28c840"].into_iter().enumerate() {
+            writeln!(self.writer, "<circle cx=\"{}\" cy=\"{centre}\" r=\"6\" fill=\"{colour}\"/>", 20 + 20 * index)?;
+        }
+
+        if !self.title.is_empty() {
+            writeln!(
+                self.writer,
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" fill=\"{}\" opacity=\"0.6\">{}</text>",
+                image_width / 2,
+                centre + u32::from(self.font_size) * 7 / 20,
+                Hex(self.palette.foreground),
+                Escaped(self.title)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw a horizontal line from `x1` to `x2`, along the middle of the pixels at `y`.
+    fn write_line(&mut self, (x1, x2): (u32, u32), y: u32, colour: (u8, u8, u8), variant: Underline) -> core::fmt::Result {
+        let writer = &mut self.writer;
+
+        match variant {
+            Underline::Single => writeln!(writer, "<line x1=\"{x1}\" y1=\"{y}.5\" x2=\"{x2}\" y2=\"{y}.5\" stroke=\"{}\"/>", Hex(colour)),
+            Underline::Double => {
+                self.write_line((x1, x2), y.saturating_sub(1), colour, Underline::Single)?;
+                self.write_line((x1, x2), y + 1, colour, Underline::Single)
+            }
+            Underline::Dotted | Underline::Dashed => {
+                let dashes = if variant == Underline::Dotted { "1 2" } else { "4 2" };
+
+                writeln!(writer, "<line x1=\"{x1}\" y1=\"{y}.5\" x2=\"{x2}\" y2=\"{y}.5\" stroke=\"{}\" stroke-dasharray=\"{dashes}\"/>", Hex(colour))
+            }
+            Underline::Curly => {
+                // Every segment is half of a wave, two pixels wide.
+                write!(writer, "<path d=\"M{x1} {y}.5q1 -1.5 2 0")?;
+
+                for _ in 1..(x2 - x1) / 2 {
+                    write!(writer, "t2 0")?;
+                }
+
+                writeln!(writer, "\" fill=\"none\" stroke=\"{}\"/>", Hex(colour))
+            }
+        }
+    }
+
+    /// Draw the text and the lines of a run of cells.
+    fn write_run(&mut self, terminal: &impl TerminalConst, grid: Grid, y: usize, run: &Run) -> core::fmt::Result {
+        let look = &run.look;
+        let has_text = row(terminal, y, run.columns.clone()).any(|(_, cell)| is_visible(cell.character));
+
+        if look.hidden || !(has_text || look.underline || look.strikethrough || look.overline) {
+            return Ok(());
+        }
+
+        if let Some(hyperlink) = run.hyperlink {
+            writeln!(self.writer, "<a href=\"{}\">", Escaped(hyperlink.uri))?;
+        }
+
+        if look.dim || look.blink {
+            writeln!(self.writer, "<g{}>", if look.dim { " opacity=\"0.5\"" } else { "" })?;
+
+            if look.blink {
+                writeln!(self.writer, "<animate attributeName=\"visibility\" values=\"visible;hidden\" dur=\"1s\" repeatCount=\"indefinite\"/>")?;
+            }
+        }
+
+        if has_text {
+            // Every character is positioned on its own, so that it ends up in its cell.
+            write!(self.writer, "<text x=\"")?;
+
+            for (index, (column, _)) in row(terminal, y, run.columns.clone()).filter(|(_, cell)| is_visible(cell.character)).enumerate() {
+                write!(self.writer, "{}{}", if index == 0 { "" } else { " " }, grid.x(column))?;
+            }
+
+            write!(self.writer, "\" y=\"{}\"", grid.baseline(y))?;
+
+            if let Some(colour) = look.fg.rgb(&self.palette) {
+                write!(self.writer, " fill=\"{}\"", Hex(colour))?;
+            }
+
+            if look.font_weight != 400 {
+                write!(self.writer, " font-weight=\"{}\"", look.font_weight)?;
+            }
+
+            if look.italic {
+                write!(self.writer, " font-style=\"italic\"")?;
+            }
+
+            write!(self.writer, ">")?;
+
+            for (_, cell) in row(terminal, y, run.columns.clone()).filter(|(_, cell)| is_visible(cell.character)) {
+                write!(self.writer, "{}", Escaped(cell.character.encode_utf8(&mut [0; 4])))?;
+            }
+
+            writeln!(self.writer, "</text>")?;
+        }
+
+        let span = (grid.x(run.columns.start), grid.x(run.columns.end));
+        let fg = look.fg.rgb(&self.palette).unwrap_or(self.palette.foreground);
+
+        if look.underline {
+            let colour = match look.underline_colour {
+                Paint::Default => fg,
+                paint => paint.rgb(&self.palette).unwrap_or(fg),
+            };
+
+            self.write_line(span, grid.underline(y), colour, look.underline_variant)?;
+        }
+
+        if look.strikethrough {
+            self.write_line(span, grid.strikethrough(y), fg, Underline::Single)?;
+        }
+
+        if look.overline {
+            self.write_line(span, grid.y(y), fg, Underline::Single)?;
+        }
+
+        if look.dim || look.blink {
+            writeln!(self.writer, "</g>")?;
+        }
+
+        if run.hyperlink.is_some() {
+            writeln!(self.writer, "</a>")?;
+        }
+
+        Ok(())
+    }
+
+    fn render_svg(&mut self, terminal: &impl TerminalConst) -> core::fmt::Result {
+        let (width, height) = terminal.dimensions();
+        let palette = self.palette;
+        let default_style = terminal.default_style();
+        let look_of = |cell: &Cell| Look::new(cell.style.inherits(default_style), &palette);
+
+        let (cell_width, line_height) = (u32::from(self.cell_size.0), u32::from(self.cell_size.1));
+        let font_size = u32::from(self.font_size);
+        let padding = u32::from(self.padding);
+        let top = padding + if self.chrome { CHROME_HEIGHT } else { 0 };
+        let baseline = u32::midpoint(line_height, font_size * 7 / 10);
+
+        let grid = Grid {
+            left: padding,
+            top,
+            cell_width,
+            line_height,
+            baseline,
+            underline: (baseline + 2).min(line_height.saturating_sub(2)),
+            strikethrough: baseline.saturating_sub(font_size * 3 / 10),
+        };
+
+        let image_width = grid.x(width) + padding;
+        let image_height = grid.y(height) + padding;
+
+        writeln!(
+            self.writer,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{image_width}\" height=\"{image_height}\" viewBox=\"0 0 {image_width} {image_height}\" font-family=\"{}\" font-size=\"{font_size}\">",
+            Escaped(self.font_family)
+        )?;
+        writeln!(
+            self.writer,
+            "<rect width=\"{image_width}\" height=\"{image_height}\"{} fill=\"{}\"/>",
+            if self.chrome { " rx=\"8\"" } else { "" },
+            Hex(palette.background)
+        )?;
+
+        if self.chrome {
+            self.write_chrome(image_width)?;
+        }
+
+        // The backgrounds go underneath all of the text, since some characters reach into the rows around them.
+        for y in 0..height {
+            for run in runs(row(terminal, y, 0..width), width, &look_of) {
+                if let Some(colour) = run.look.bg.rgb(&palette) {
+                    writeln!(
+                        self.writer,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{line_height}\" fill=\"{}\"/>",
+                        grid.x(run.columns.start),
+                        grid.y(y),
+                        grid.x(run.columns.end) - grid.x(run.columns.start),
+                        Hex(colour)
+                    )?;
+                }
+            }
+        }
+
+        writeln!(self.writer, "<g fill=\"{}\">", Hex(palette.foreground))?;
+
+        for y in 0..height {
+            for run in runs(row(terminal, y, 0..width), width, &look_of) {
+                self.write_run(terminal, grid, y, &run)?;
+            }
+        }
+
+        writeln!(self.writer, "</g>")?;
+        writeln!(self.writer, "</svg>")
+    }
+}
+
+impl<T: Write> Renderer for SvgRenderer<T> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        self.render_svg(&terminal).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+}
+
+/// Where the cells of the terminal are in the image, in pixels.
+#[derive(Copy, Clone)]
+struct Grid {
+    left: u32,
+    top: u32,
+    cell_width: u32,
+    line_height: u32,
+    /// The offsets of the baseline, the underline and the strikethrough from the top of a row.
+    baseline: u32,
+    underline: u32,
+    strikethrough: u32,
+}
+
+impl Grid {
+    const fn x(self, column: usize) -> u32 {
+        self.left + column as u32 * self.cell_width
+    }
+
+    const fn y(self, row: usize) -> u32 {
+        self.top + row as u32 * self.line_height
+    }
+
+    const fn baseline(self, row: usize) -> u32 {
+        self.y(row) + self.baseline
+    }
+
+    const fn underline(self, row: usize) -> u32 {
+        self.y(row) + self.underline
+    }
+
+    const fn strikethrough(self, row: usize) -> u32 {
+        self.y(row) + self.strikethrough
+    }
+}
+
+/// Neighbouring cells that look the same and share a hyperlink.
+struct Run {
+    columns: Range<usize>,
+    look: Look,
+    hyperlink: Option<Hyperlink>,
+}
+
+/// The cells of row `y` within `columns`, along with their columns. The cell after a wide character
+/// is its continuation, so it is left out.
+///
+/// `columns` must not start on a continuation.
+fn row<T: TerminalConst>(terminal: &T, y: usize, columns: Range<usize>) -> impl Iterator<Item = (usize, &Cell)> {
+    let mut continuation = false;
+
+    terminal
+        .cells()
+        .skip(y * terminal.width() + columns.start)
+        .zip(columns)
+        .filter(move |(cell, _)| {
+            let covered = continuation;
+
+            continuation = !covered && cell.width() == 2;
+
+            !covered
+        })
+        .map(|(cell, column)| (column, cell))
+}
+
+/// Splits the cells of a row that is `width` cells wide into [`Run`]s.
+fn runs<'a>(cells: impl Iterator<Item = (usize, &'a Cell)>, width: usize, look_of: impl Fn(&Cell) -> Look) -> impl Iterator<Item = Run> {
+    let mut cells = cells.peekable();
+
+    core::iter::from_fn(move || {
+        let (start, cell) = cells.next()?;
+        let key = (look_of(cell), cell.hyperlink);
+        let mut end = start + cell.width();
+
+        while let Some((column, cell)) = cells.next_if(|(_, cell)| (look_of(cell), cell.hyperlink) == key) {
+            end = column + cell.width();
+        }
+
+        Some(Run {
+            // A wide character in the last column doesn't have a continuation.
+            columns: start..end.min(width),
+            look: key.0,
+            hyperlink: key.1,
+        })
+    })
+}
+
+/// Whether a character needs to be drawn.
+fn is_visible(character: char) -> bool {
+    !(character.is_whitespace() || character.is_control())
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use crate::prelude::*;
+    use crate::style::{Ansi4, Colour, Style, Underline};
+    use crate::terminal::{ConstantSize, Hyperlink, Rectangle};
+    use crate::widgets::builtins::Text;
+
+    use super::SvgRenderer;
+
+    /// Compares `svg` with the golden file `src/draw/golden/{name}.svg`. Run the tests with
+    /// `TUIT_BLESS=1` to write the golden files instead.
+    fn assert_golden(name: &str, svg: &str) {
+        let path = format!("{}/src/draw/golden/{name}.svg", env!("CARGO_MANIFEST_DIR"));
+
+        if std::env::var_os("TUIT_BLESS").is_some() {
+            std::fs::write(&path, svg).expect("The golden file should be writable");
+
+            return;
+        }
+
+        let golden = std::fs::read_to_string(&path).expect("The golden file should exist");
+
+        assert_eq!(svg, golden, "{path} doesn't match; run the tests with TUIT_BLESS=1 to update it");
+    }
+
+    fn render(terminal: &impl TerminalConst, configure: impl FnOnce(SvgRenderer<String>) -> SvgRenderer<String>) -> String {
+        let mut renderer = configure(SvgRenderer::new(String::new()).font_family("monospace"));
+
+        renderer.render(terminal).expect("Writing to a `String` can't fail");
+
+        renderer.writer
+    }
+
+    fn draw_lines<const WIDTH: usize, const HEIGHT: usize>(terminal: &mut ConstantSize<WIDTH, HEIGHT>, lines: impl IntoIterator<Item = Text<'static>>) {
+        for (y, line) in lines.into_iter().enumerate() {
+            let mut row = terminal.view_mut(Rectangle::of_size((WIDTH, 1)).at((0, y))).expect("The row fits");
+
+            line.drawn(&mut row).expect("The line fits");
+        }
+    }
+
+    #[test]
+    fn plain() {
+        let mut terminal: ConstantSize<12, 2> = ConstantSize::new();
+
+        draw_lines(&mut terminal, [Text::new("Hello, <SVG>"), Text::new("  & goodbye")]);
+
+        assert_golden("plain", &render(&terminal, |renderer| renderer));
+    }
+
+    #[test]
+    fn styled() {
+        let mut terminal: ConstantSize<16, 5> = ConstantSize::new();
+
+        draw_lines(
+            &mut terminal,
+            [
+                Text::new("Bold on blue").styled(Style::new().bold().bg_ansi4(Ansi4::Blue)),
+                Text::new("italic 世 界").styled(Style::new().italic().fg(Colour::Rgb24(255, 136, 0))),
+                Text::new("under struck").styled(Style::new().underlined().strikethrough()),
+                Text::new("curly double").styled(Style::new().underline_variant(Underline::Curly).underline_colour_of(Colour::Ansi16(Ansi4::Red))),
+                Text::new("inverted").styled(Style::new().inverted()),
+            ],
+        );
+
+        terminal.cell_mut(15, 3).expect("The cell exists").style = Style::new().underline_variant(Underline::Double);
+
+        assert_golden("styled", &render(&terminal, |renderer| renderer));
+    }
+
+    #[test]
+    fn chrome() {
+        let mut terminal: ConstantSize<20, 3> = ConstantSize::new();
+
+        draw_lines(
+            &mut terminal,
+            [
+                Text::new("$ cargo run").styled(Style::new().fg_ansi4(Ansi4::Green)),
+                Text::new("docs.rs/tuit").linked(Hyperlink::new("https://docs.rs/tuit")),
+                Text::new("faint blink").styled(Style::new().dimmed().blinking()),
+            ],
+        );
+
+        assert_golden("chrome", &render(&terminal, |renderer| renderer.chrome(true).title("Tuit & friends").padding(12)));
+    }
+
+    #[test]
+    fn double_underline_at_the_top() {
+        let mut terminal: ConstantSize<1, 1> = ConstantSize::new();
+        terminal.cell_mut(0, 0).expect("The cell exists").style = Style::new().underline_variant(Underline::Double);
+
+        let svg = render(&terminal, |renderer| renderer.cell_size((9, 2)));
+
+        assert!(svg.contains("y1=\"0.5\""), "The upper line should be clamped to the top row");
+        assert!(svg.contains("y1=\"1.5\""), "The lower line should still be drawn");
+    }
+}
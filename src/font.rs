@@ -0,0 +1,342 @@
+//! # Bitmap fonts
+//!
+//! Monospaced bitmap [`Font`]s, which are used by the [`Banner`](crate::widgets::builtins::Banner)
+//! widget to draw large text out of cells, and by the
+//! [`FramebufferRenderer`](crate::draw::framebuffer::FramebufferRenderer) to draw cells out of pixels.
+
+/// A single character inside of a [`Font`].
+///
+/// Each row of the bitmap is a byte, and the rightmost [`Font::width`] bits of each byte are the
+/// pixels of the row, with the most significant bit on the left. Set bits are drawn.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Glyph<'a> {
+    /// The character that this glyph draws.
+    pub character: char,
+    /// The rows of the bitmap, from top to bottom.
+    pub rows: &'a [u8],
+}
+
+impl<'a> Glyph<'a> {
+    /// Create a new [`Glyph`] from its rows.
+    #[must_use]
+    pub const fn new(character: char, rows: &'a [u8]) -> Self {
+        Self { character, rows }
+    }
+
+    /// Whether the pixel at the specified position is set, in a font of the specified width.
+    ///
+    /// Rows only have 8 bits, so in fonts that are wider than 8 pixels, the pixels on the left
+    /// of the last 8 are never set.
+    #[must_use]
+    pub const fn pixel(&self, x: usize, y: usize, width: usize) -> bool {
+        if x >= width || y >= self.rows.len() {
+            return false;
+        }
+
+        let shift = width - 1 - x;
+
+        shift < u8::BITS as usize && (self.rows[y] >> shift) & 1 == 1
+    }
+}
+
+/// A monospaced bitmap font, made of [`Glyph`]s that are up to 8 pixels wide.
+///
+/// Tuit comes with [`Font::SMALL`] (3x5) and [`Font::LARGE`] (5x7), which both cover the digits,
+/// the uppercase letters (lowercase letters are drawn in uppercase) and some punctuation, and with
+/// [`Font::BASIC`] (8x8), which covers ASCII. You can also make your own:
+///
+/// ```
+/// use tuit::font::{Font, Glyph};
+///
+/// const ARROWS: Font = Font::new(3, 3, &[
+///     Glyph::new('>', &[0b100, 0b010, 0b100]),
+///     Glyph::new('<', &[0b001, 0b010, 0b001]),
+/// ]);
+///
+/// assert!(ARROWS.glyph('>').expect("There is a glyph for '>'").pixel(0, 0, 3));
+///
+/// // Rows only have 8 bits, so the pixels left of them are blank in wider fonts.
+/// assert!(!Glyph::new('|', &[0xFF]).pixel(0, 0, 12));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Font<'a> {
+    /// The width of every glyph, in pixels. Glyphs only have 8 pixels per row, so any columns
+    /// to the left of the last 8 are blank.
+    pub width: usize,
+    /// The height of every glyph, in pixels.
+    pub height: usize,
+    /// The glyphs of the font.
+    pub glyphs: &'a [Glyph<'a>],
+    /// The character whose glyph is drawn for characters that aren't in the font.
+    /// If it is `None` (or also missing), the glyph is left blank.
+    pub fallback: Option<char>,
+}
+
+impl<'a> Font<'a> {
+    /// A tiny 3x5 font.
+    pub const SMALL: Font<'static> = Font::new(3, 5, SMALL_GLYPHS).fallback(Some('?'));
+    /// A classic 5x7 font, like the ones in character LCDs.
+    pub const LARGE: Font<'static> = Font::new(5, 7, LARGE_GLYPHS).fallback(Some('?'));
+    /// The public domain `font8x8` font, which covers ASCII, the light box-drawing characters
+    /// and the full block. It is the font of the [`FramebufferRenderer`](crate::draw::framebuffer::FramebufferRenderer).
+    pub const BASIC: Font<'static> = Font::new(8, 8, BASIC_GLYPHS).fallback(Some('\u{fffd}'));
+
+    /// Create a new [`Font`] out of a table of glyphs.
+    #[must_use]
+    pub const fn new(width: usize, height: usize, glyphs: &'a [Glyph<'a>]) -> Self {
+        Self {
+            width,
+            height,
+            glyphs,
+            fallback: None,
+        }
+    }
+
+    /// Set the character whose glyph is drawn for characters that aren't in the font.
+    #[must_use]
+    pub const fn fallback(mut self, fallback: Option<char>) -> Self {
+        self.fallback = fallback;
+
+        self
+    }
+
+    /// Find the glyph for a character. Lowercase letters fall back to their uppercase glyphs.
+    #[must_use]
+    pub fn glyph(&self, character: char) -> Option<&Glyph<'a>> {
+        let find = |character: char| self.glyphs.iter().find(|glyph| glyph.character == character);
+
+        find(character)
+            .or_else(|| find(character.to_ascii_uppercase()))
+            .or_else(|| self.fallback.and_then(find))
+    }
+}
+
+const SMALL_GLYPHS: &[Glyph<'static>] = &[
+    Glyph::new('0', &[0b111, 0b101, 0b101, 0b101, 0b111]),
+    Glyph::new('1', &[0b010, 0b110, 0b010, 0b010, 0b111]),
+    Glyph::new('2', &[0b111, 0b001, 0b111, 0b100, 0b111]),
+    Glyph::new('3', &[0b111, 0b001, 0b111, 0b001, 0b111]),
+    Glyph::new('4', &[0b101, 0b101, 0b111, 0b001, 0b001]),
+    Glyph::new('5', &[0b111, 0b100, 0b111, 0b001, 0b111]),
+    Glyph::new('6', &[0b111, 0b100, 0b111, 0b101, 0b111]),
+    Glyph::new('7', &[0b111, 0b001, 0b001, 0b001, 0b001]),
+    Glyph::new('8', &[0b111, 0b101, 0b111, 0b101, 0b111]),
+    Glyph::new('9', &[0b111, 0b101, 0b111, 0b001, 0b111]),
+    Glyph::new('A', &[0b010, 0b101, 0b111, 0b101, 0b101]),
+    Glyph::new('B', &[0b110, 0b101, 0b110, 0b101, 0b110]),
+    Glyph::new('C', &[0b011, 0b100, 0b100, 0b100, 0b011]),
+    Glyph::new('D', &[0b110, 0b101, 0b101, 0b101, 0b110]),
+    Glyph::new('E', &[0b111, 0b100, 0b110, 0b100, 0b111]),
+    Glyph::new('F', &[0b111, 0b100, 0b110, 0b100, 0b100]),
+    Glyph::new('G', &[0b011, 0b100, 0b101, 0b101, 0b011]),
+    Glyph::new('H', &[0b101, 0b101, 0b111, 0b101, 0b101]),
+    Glyph::new('I', &[0b111, 0b010, 0b010, 0b010, 0b111]),
+    Glyph::new('J', &[0b001, 0b001, 0b001, 0b101, 0b010]),
+    Glyph::new('K', &[0b101, 0b101, 0b110, 0b101, 0b101]),
+    Glyph::new('L', &[0b100, 0b100, 0b100, 0b100, 0b111]),
+    Glyph::new('M', &[0b101, 0b111, 0b111, 0b101, 0b101]),
+    Glyph::new('N', &[0b110, 0b101, 0b101, 0b101, 0b101]),
+    Glyph::new('O', &[0b010, 0b101, 0b101, 0b101, 0b010]),
+    Glyph::new('P', &[0b110, 0b101, 0b110, 0b100, 0b100]),
+    Glyph::new('Q', &[0b010, 0b101, 0b101, 0b110, 0b011]),
+    Glyph::new('R', &[0b110, 0b101, 0b110, 0b101, 0b101]),
+    Glyph::new('S', &[0b011, 0b100, 0b010, 0b001, 0b110]),
+    Glyph::new('T', &[0b111, 0b010, 0b010, 0b010, 0b010]),
+    Glyph::new('U', &[0b101, 0b101, 0b101, 0b101, 0b111]),
+    Glyph::new('V', &[0b101, 0b101, 0b101, 0b101, 0b010]),
+    Glyph::new('W', &[0b101, 0b101, 0b111, 0b111, 0b101]),
+    Glyph::new('X', &[0b101, 0b101, 0b010, 0b101, 0b101]),
+    Glyph::new('Y', &[0b101, 0b101, 0b010, 0b010, 0b010]),
+    Glyph::new('Z', &[0b111, 0b001, 0b010, 0b100, 0b111]),
+    Glyph::new(' ', &[0b000, 0b000, 0b000, 0b000, 0b000]),
+    Glyph::new('.', &[0b000, 0b000, 0b000, 0b000, 0b010]),
+    Glyph::new(',', &[0b000, 0b000, 0b000, 0b010, 0b100]),
+    Glyph::new(':', &[0b000, 0b010, 0b000, 0b010, 0b000]),
+    Glyph::new(';', &[0b000, 0b010, 0b000, 0b010, 0b100]),
+    Glyph::new('!', &[0b010, 0b010, 0b010, 0b000, 0b010]),
+    Glyph::new('?', &[0b110, 0b001, 0b010, 0b000, 0b010]),
+    Glyph::new('-', &[0b000, 0b000, 0b111, 0b000, 0b000]),
+    Glyph::new('+', &[0b000, 0b010, 0b111, 0b010, 0b000]),
+    Glyph::new('=', &[0b000, 0b111, 0b000, 0b111, 0b000]),
+    Glyph::new('/', &[0b001, 0b001, 0b010, 0b100, 0b100]),
+    Glyph::new('%', &[0b101, 0b001, 0b010, 0b100, 0b101]),
+    Glyph::new('\'', &[0b010, 0b010, 0b000, 0b000, 0b000]),
+    Glyph::new('"', &[0b101, 0b101, 0b000, 0b000, 0b000]),
+    Glyph::new('(', &[0b001, 0b010, 0b010, 0b010, 0b001]),
+    Glyph::new(')', &[0b100, 0b010, 0b010, 0b010, 0b100]),
+    Glyph::new('_', &[0b000, 0b000, 0b000, 0b000, 0b111]),
+    Glyph::new('#', &[0b101, 0b111, 0b101, 0b111, 0b101]),
+    Glyph::new('*', &[0b000, 0b101, 0b010, 0b101, 0b000]),
+];
+
+const LARGE_GLYPHS: &[Glyph<'static>] = &[
+    Glyph::new('0', &[0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+    Glyph::new('1', &[0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    Glyph::new('2', &[0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+    Glyph::new('3', &[0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+    Glyph::new('4', &[0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+    Glyph::new('5', &[0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+    Glyph::new('6', &[0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+    Glyph::new('7', &[0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+    Glyph::new('8', &[0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+    Glyph::new('9', &[0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+    Glyph::new('A', &[0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    Glyph::new('B', &[0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+    Glyph::new('C', &[0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+    Glyph::new('D', &[0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100]),
+    Glyph::new('E', &[0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+    Glyph::new('F', &[0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+    Glyph::new('G', &[0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+    Glyph::new('H', &[0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    Glyph::new('I', &[0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    Glyph::new('J', &[0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+    Glyph::new('K', &[0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+    Glyph::new('L', &[0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111]),
+    Glyph::new('M', &[0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+    Glyph::new('N', &[0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+    Glyph::new('O', &[0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    Glyph::new('P', &[0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+    Glyph::new('Q', &[0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+    Glyph::new('R', &[0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+    Glyph::new('S', &[0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+    Glyph::new('T', &[0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+    Glyph::new('U', &[0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    Glyph::new('V', &[0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+    Glyph::new('W', &[0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+    Glyph::new('X', &[0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+    Glyph::new('Y', &[0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100]),
+    Glyph::new('Z', &[0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+    Glyph::new(' ', &[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    Glyph::new('.', &[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
+    Glyph::new(',', &[0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000]),
+    Glyph::new(':', &[0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000]),
+    Glyph::new(';', &[0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b00100, 0b01000]),
+    Glyph::new('!', &[0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100]),
+    Glyph::new('?', &[0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+    Glyph::new('-', &[0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+    Glyph::new('+', &[0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000]),
+    Glyph::new('=', &[0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000]),
+    Glyph::new('/', &[0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000]),
+    Glyph::new('%', &[0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011]),
+    Glyph::new('\'', &[0b01100, 0b00100, 0b01000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    Glyph::new('"', &[0b01010, 0b01010, 0b01010, 0b00000, 0b00000, 0b00000, 0b00000]),
+    Glyph::new('(', &[0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010]),
+    Glyph::new(')', &[0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000]),
+    Glyph::new('_', &[0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111]),
+    Glyph::new('#', &[0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010]),
+    Glyph::new('*', &[0b00000, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0b00000]),
+];
+
+/// Based on `font8x8_basic` by Daniel Hepper, which is in the public domain.
+#[allow(clippy::unreadable_literal)] // Separators would break up the pixel art.
+const BASIC_GLYPHS: &[Glyph<'static>] = &[
+    Glyph::new(' ', &[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('!', &[0b00011000, 0b00111100, 0b00111100, 0b00011000, 0b00011000, 0b00000000, 0b00011000, 0b00000000]),
+    Glyph::new('"', &[0b01101100, 0b01101100, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('#', &[0b01101100, 0b01101100, 0b11111110, 0b01101100, 0b11111110, 0b01101100, 0b01101100, 0b00000000]),
+    Glyph::new('$', &[0b00110000, 0b01111100, 0b11000000, 0b01111000, 0b00001100, 0b11111000, 0b00110000, 0b00000000]),
+    Glyph::new('%', &[0b00000000, 0b11000110, 0b11001100, 0b00011000, 0b00110000, 0b01100110, 0b11000110, 0b00000000]),
+    Glyph::new('&', &[0b00111000, 0b01101100, 0b00111000, 0b01110110, 0b11011100, 0b11001100, 0b01110110, 0b00000000]),
+    Glyph::new('\'', &[0b01100000, 0b01100000, 0b11000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('(', &[0b00011000, 0b00110000, 0b01100000, 0b01100000, 0b01100000, 0b00110000, 0b00011000, 0b00000000]),
+    Glyph::new(')', &[0b01100000, 0b00110000, 0b00011000, 0b00011000, 0b00011000, 0b00110000, 0b01100000, 0b00000000]),
+    Glyph::new('*', &[0b00000000, 0b01100110, 0b00111100, 0b11111111, 0b00111100, 0b01100110, 0b00000000, 0b00000000]),
+    Glyph::new('+', &[0b00000000, 0b00110000, 0b00110000, 0b11111100, 0b00110000, 0b00110000, 0b00000000, 0b00000000]),
+    Glyph::new(',', &[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00110000, 0b00110000, 0b01100000]),
+    Glyph::new('-', &[0b00000000, 0b00000000, 0b00000000, 0b11111100, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('.', &[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00110000, 0b00110000, 0b00000000]),
+    Glyph::new('/', &[0b00000110, 0b00001100, 0b00011000, 0b00110000, 0b01100000, 0b11000000, 0b10000000, 0b00000000]),
+    Glyph::new('0', &[0b01111100, 0b11000110, 0b11001110, 0b11011110, 0b11110110, 0b11100110, 0b01111100, 0b00000000]),
+    Glyph::new('1', &[0b00110000, 0b01110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b11111100, 0b00000000]),
+    Glyph::new('2', &[0b01111000, 0b11001100, 0b00001100, 0b00111000, 0b01100000, 0b11001100, 0b11111100, 0b00000000]),
+    Glyph::new('3', &[0b01111000, 0b11001100, 0b00001100, 0b00111000, 0b00001100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('4', &[0b00011100, 0b00111100, 0b01101100, 0b11001100, 0b11111110, 0b00001100, 0b00011110, 0b00000000]),
+    Glyph::new('5', &[0b11111100, 0b11000000, 0b11111000, 0b00001100, 0b00001100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('6', &[0b00111000, 0b01100000, 0b11000000, 0b11111000, 0b11001100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('7', &[0b11111100, 0b11001100, 0b00001100, 0b00011000, 0b00110000, 0b00110000, 0b00110000, 0b00000000]),
+    Glyph::new('8', &[0b01111000, 0b11001100, 0b11001100, 0b01111000, 0b11001100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('9', &[0b01111000, 0b11001100, 0b11001100, 0b01111100, 0b00001100, 0b00011000, 0b01110000, 0b00000000]),
+    Glyph::new(':', &[0b00000000, 0b00110000, 0b00110000, 0b00000000, 0b00000000, 0b00110000, 0b00110000, 0b00000000]),
+    Glyph::new(';', &[0b00000000, 0b00110000, 0b00110000, 0b00000000, 0b00000000, 0b00110000, 0b00110000, 0b01100000]),
+    Glyph::new('<', &[0b00011000, 0b00110000, 0b01100000, 0b11000000, 0b01100000, 0b00110000, 0b00011000, 0b00000000]),
+    Glyph::new('=', &[0b00000000, 0b00000000, 0b11111100, 0b00000000, 0b00000000, 0b11111100, 0b00000000, 0b00000000]),
+    Glyph::new('>', &[0b01100000, 0b00110000, 0b00011000, 0b00001100, 0b00011000, 0b00110000, 0b01100000, 0b00000000]),
+    Glyph::new('?', &[0b01111000, 0b11001100, 0b00001100, 0b00011000, 0b00110000, 0b00000000, 0b00110000, 0b00000000]),
+    Glyph::new('@', &[0b01111100, 0b11000110, 0b11011110, 0b11011110, 0b11011110, 0b11000000, 0b01111000, 0b00000000]),
+    Glyph::new('A', &[0b00110000, 0b01111000, 0b11001100, 0b11001100, 0b11111100, 0b11001100, 0b11001100, 0b00000000]),
+    Glyph::new('B', &[0b11111100, 0b01100110, 0b01100110, 0b01111100, 0b01100110, 0b01100110, 0b11111100, 0b00000000]),
+    Glyph::new('C', &[0b00111100, 0b01100110, 0b11000000, 0b11000000, 0b11000000, 0b01100110, 0b00111100, 0b00000000]),
+    Glyph::new('D', &[0b11111000, 0b01101100, 0b01100110, 0b01100110, 0b01100110, 0b01101100, 0b11111000, 0b00000000]),
+    Glyph::new('E', &[0b11111110, 0b01100010, 0b01101000, 0b01111000, 0b01101000, 0b01100010, 0b11111110, 0b00000000]),
+    Glyph::new('F', &[0b11111110, 0b01100010, 0b01101000, 0b01111000, 0b01101000, 0b01100000, 0b11110000, 0b00000000]),
+    Glyph::new('G', &[0b00111100, 0b01100110, 0b11000000, 0b11000000, 0b11001110, 0b01100110, 0b00111110, 0b00000000]),
+    Glyph::new('H', &[0b11001100, 0b11001100, 0b11001100, 0b11111100, 0b11001100, 0b11001100, 0b11001100, 0b00000000]),
+    Glyph::new('I', &[0b01111000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b01111000, 0b00000000]),
+    Glyph::new('J', &[0b00011110, 0b00001100, 0b00001100, 0b00001100, 0b11001100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('K', &[0b11100110, 0b01100110, 0b01101100, 0b01111000, 0b01101100, 0b01100110, 0b11100110, 0b00000000]),
+    Glyph::new('L', &[0b11110000, 0b01100000, 0b01100000, 0b01100000, 0b01100010, 0b01100110, 0b11111110, 0b00000000]),
+    Glyph::new('M', &[0b11000110, 0b11101110, 0b11111110, 0b11111110, 0b11010110, 0b11000110, 0b11000110, 0b00000000]),
+    Glyph::new('N', &[0b11000110, 0b11100110, 0b11110110, 0b11011110, 0b11001110, 0b11000110, 0b11000110, 0b00000000]),
+    Glyph::new('O', &[0b00111000, 0b01101100, 0b11000110, 0b11000110, 0b11000110, 0b01101100, 0b00111000, 0b00000000]),
+    Glyph::new('P', &[0b11111100, 0b01100110, 0b01100110, 0b01111100, 0b01100000, 0b01100000, 0b11110000, 0b00000000]),
+    Glyph::new('Q', &[0b01111000, 0b11001100, 0b11001100, 0b11001100, 0b11011100, 0b01111000, 0b00011100, 0b00000000]),
+    Glyph::new('R', &[0b11111100, 0b01100110, 0b01100110, 0b01111100, 0b01101100, 0b01100110, 0b11100110, 0b00000000]),
+    Glyph::new('S', &[0b01111000, 0b11001100, 0b11100000, 0b01110000, 0b00011100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('T', &[0b11111100, 0b10110100, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b01111000, 0b00000000]),
+    Glyph::new('U', &[0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11111100, 0b00000000]),
+    Glyph::new('V', &[0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b01111000, 0b00110000, 0b00000000]),
+    Glyph::new('W', &[0b11000110, 0b11000110, 0b11000110, 0b11010110, 0b11111110, 0b11101110, 0b11000110, 0b00000000]),
+    Glyph::new('X', &[0b11000110, 0b11000110, 0b01101100, 0b00111000, 0b00111000, 0b01101100, 0b11000110, 0b00000000]),
+    Glyph::new('Y', &[0b11001100, 0b11001100, 0b11001100, 0b01111000, 0b00110000, 0b00110000, 0b01111000, 0b00000000]),
+    Glyph::new('Z', &[0b11111110, 0b11000110, 0b10001100, 0b00011000, 0b00110010, 0b01100110, 0b11111110, 0b00000000]),
+    Glyph::new('[', &[0b01111000, 0b01100000, 0b01100000, 0b01100000, 0b01100000, 0b01100000, 0b01111000, 0b00000000]),
+    Glyph::new('\\', &[0b11000000, 0b01100000, 0b00110000, 0b00011000, 0b00001100, 0b00000110, 0b00000010, 0b00000000]),
+    Glyph::new(']', &[0b01111000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b00011000, 0b01111000, 0b00000000]),
+    Glyph::new('^', &[0b00010000, 0b00111000, 0b01101100, 0b11000110, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('_', &[0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b11111111]),
+    Glyph::new('`', &[0b00110000, 0b00110000, 0b00011000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('a', &[0b00000000, 0b00000000, 0b01111000, 0b00001100, 0b01111100, 0b11001100, 0b01110110, 0b00000000]),
+    Glyph::new('b', &[0b11100000, 0b01100000, 0b01100000, 0b01111100, 0b01100110, 0b01100110, 0b11011100, 0b00000000]),
+    Glyph::new('c', &[0b00000000, 0b00000000, 0b01111000, 0b11001100, 0b11000000, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('d', &[0b00011100, 0b00001100, 0b00001100, 0b01111100, 0b11001100, 0b11001100, 0b01110110, 0b00000000]),
+    Glyph::new('e', &[0b00000000, 0b00000000, 0b01111000, 0b11001100, 0b11111100, 0b11000000, 0b01111000, 0b00000000]),
+    Glyph::new('f', &[0b00111000, 0b01101100, 0b01100000, 0b11110000, 0b01100000, 0b01100000, 0b11110000, 0b00000000]),
+    Glyph::new('g', &[0b00000000, 0b00000000, 0b01110110, 0b11001100, 0b11001100, 0b01111100, 0b00001100, 0b11111000]),
+    Glyph::new('h', &[0b11100000, 0b01100000, 0b01101100, 0b01110110, 0b01100110, 0b01100110, 0b11100110, 0b00000000]),
+    Glyph::new('i', &[0b00110000, 0b00000000, 0b01110000, 0b00110000, 0b00110000, 0b00110000, 0b01111000, 0b00000000]),
+    Glyph::new('j', &[0b00001100, 0b00000000, 0b00001100, 0b00001100, 0b00001100, 0b11001100, 0b11001100, 0b01111000]),
+    Glyph::new('k', &[0b11100000, 0b01100000, 0b01100110, 0b01101100, 0b01111000, 0b01101100, 0b11100110, 0b00000000]),
+    Glyph::new('l', &[0b01110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b00110000, 0b01111000, 0b00000000]),
+    Glyph::new('m', &[0b00000000, 0b00000000, 0b11001100, 0b11111110, 0b11111110, 0b11010110, 0b11000110, 0b00000000]),
+    Glyph::new('n', &[0b00000000, 0b00000000, 0b11111000, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b00000000]),
+    Glyph::new('o', &[0b00000000, 0b00000000, 0b01111000, 0b11001100, 0b11001100, 0b11001100, 0b01111000, 0b00000000]),
+    Glyph::new('p', &[0b00000000, 0b00000000, 0b11011100, 0b01100110, 0b01100110, 0b01111100, 0b01100000, 0b11110000]),
+    Glyph::new('q', &[0b00000000, 0b00000000, 0b01110110, 0b11001100, 0b11001100, 0b01111100, 0b00001100, 0b00011110]),
+    Glyph::new('r', &[0b00000000, 0b00000000, 0b11011100, 0b01110110, 0b01100110, 0b01100000, 0b11110000, 0b00000000]),
+    Glyph::new('s', &[0b00000000, 0b00000000, 0b01111100, 0b11000000, 0b01111000, 0b00001100, 0b11111000, 0b00000000]),
+    Glyph::new('t', &[0b00010000, 0b00110000, 0b01111100, 0b00110000, 0b00110000, 0b00110100, 0b00011000, 0b00000000]),
+    Glyph::new('u', &[0b00000000, 0b00000000, 0b11001100, 0b11001100, 0b11001100, 0b11001100, 0b01110110, 0b00000000]),
+    Glyph::new('v', &[0b00000000, 0b00000000, 0b11001100, 0b11001100, 0b11001100, 0b01111000, 0b00110000, 0b00000000]),
+    Glyph::new('w', &[0b00000000, 0b00000000, 0b11000110, 0b11010110, 0b11111110, 0b11111110, 0b01101100, 0b00000000]),
+    Glyph::new('x', &[0b00000000, 0b00000000, 0b11000110, 0b01101100, 0b00111000, 0b01101100, 0b11000110, 0b00000000]),
+    Glyph::new('y', &[0b00000000, 0b00000000, 0b11001100, 0b11001100, 0b11001100, 0b01111100, 0b00001100, 0b11111000]),
+    Glyph::new('z', &[0b00000000, 0b00000000, 0b11111100, 0b10011000, 0b00110000, 0b01100100, 0b11111100, 0b00000000]),
+    Glyph::new('{', &[0b00011100, 0b00110000, 0b00110000, 0b11100000, 0b00110000, 0b00110000, 0b00011100, 0b00000000]),
+    Glyph::new('|', &[0b00011000, 0b00011000, 0b00011000, 0b00000000, 0b00011000, 0b00011000, 0b00011000, 0b00000000]),
+    Glyph::new('}', &[0b11100000, 0b00110000, 0b00110000, 0b00011100, 0b00110000, 0b00110000, 0b11100000, 0b00000000]),
+    Glyph::new('~', &[0b01110110, 0b11011100, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('─', &[0b00000000, 0b00000000, 0b00000000, 0b11111111, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('│', &[0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('┌', &[0b00000000, 0b00000000, 0b00000000, 0b00011111, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('┐', &[0b00000000, 0b00000000, 0b00000000, 0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('└', &[0b00010000, 0b00010000, 0b00010000, 0b00011111, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('┘', &[0b00010000, 0b00010000, 0b00010000, 0b11110000, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('├', &[0b00010000, 0b00010000, 0b00010000, 0b00011111, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('┤', &[0b00010000, 0b00010000, 0b00010000, 0b11110000, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('┬', &[0b00000000, 0b00000000, 0b00000000, 0b11111111, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('┴', &[0b00010000, 0b00010000, 0b00010000, 0b11111111, 0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+    Glyph::new('┼', &[0b00010000, 0b00010000, 0b00010000, 0b11111111, 0b00010000, 0b00010000, 0b00010000, 0b00010000]),
+    Glyph::new('█', &[0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111, 0b11111111]),
+    Glyph::new('▸', &[0b00000000, 0b00100000, 0b00110000, 0b00111000, 0b00110000, 0b00100000, 0b00000000, 0b00000000]),
+    Glyph::new('\u{fffd}', &[0b01111110, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01000010, 0b01111110, 0b00000000]),
+];
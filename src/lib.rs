@@ -28,6 +28,7 @@ pub use errors::Error;
 pub mod allocations;
 pub mod draw;
 pub mod errors;
+pub mod font;
 #[cfg(feature = "std")]
 pub mod std;
 pub mod terminal;
@@ -0,0 +1,334 @@
+extern crate std;
+
+use std::io::{IsTerminal, Read, Write};
+use std::string::String;
+
+use anyhow::anyhow;
+
+use crate::style::ColourDepth;
+
+/// What the terminal on the other end of stdout can do, as guessed from the environment and
+/// (optionally) from the terminal's answers to a few queries.
+///
+/// ```
+/// use tuit::std::capabilities::Capabilities;
+/// use tuit::style::ColourDepth;
+///
+/// let vars = |name: &str| match name {
+///     "TERM" => Some("xterm-256color".into()),
+///     "LANG" => Some("en_GB.UTF-8".into()),
+///     _ => None,
+/// };
+///
+/// let capabilities = Capabilities::from_vars(vars, true);
+///
+/// assert_eq!(capabilities.colour_depth, ColourDepth::Ansi256);
+/// assert!(capabilities.mouse);
+/// assert!(capabilities.unicode);
+///
+/// // Output that isn't going to a terminal gets no colours, unless they're forced.
+/// assert_eq!(Capabilities::from_vars(vars, false).colour_depth, ColourDepth::NoColour);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)] // They are independent features, not states.
+pub struct Capabilities {
+    /// Whether stdout is a terminal, rather than a file or a pipe.
+    pub is_terminal: bool,
+    /// The colours that the terminal can display.
+    pub colour_depth: ColourDepth,
+    /// Whether the terminal can report mouse events.
+    pub mouse: bool,
+    /// Whether the terminal can display Unicode characters (such as box-drawing characters).
+    pub unicode: bool,
+    /// Whether the terminal can display Sixel graphics. This is only ever detected by [`Capabilities::query`].
+    pub sixel: bool,
+}
+
+impl Capabilities {
+    /// The queries that [`Capabilities::query`] sends to the terminal: XTGETTCAP requests for the
+    /// `RGB`, `Tc` and `colors` capabilities, followed by Primary Device Attributes (DA1).
+    ///
+    /// Nearly every terminal answers DA1, and terminals answer in order, so the DA1 response marks
+    /// the end of the answers.
+    pub const QUERY: &'static [u8] = b"\x1bP+q524742\x1b\\\x1bP+q5463\x1b\\\x1bP+q636F6C6F7273\x1b\\\x1b[c";
+
+    /// Guess the capabilities of stdout from the environment variables and whether stdout is a terminal.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok(), std::io::stdout().is_terminal())
+    }
+
+    /// Guess the capabilities from a set of environment variables, looked up by `var`.
+    ///
+    /// - `NO_COLOR` (when set and not empty) disables colours.
+    /// - `CLICOLOR_FORCE` (when set and not `0`) enables colours, even when `is_terminal` is false.
+    /// - `COLORTERM=truecolor`/`24bit`, or a `TERM` that ends in `-direct`, means true colour.
+    /// - A `TERM` containing `256color` means 256 colours; other terminals get 16 colours.
+    /// - `TERM=dumb` disables colours and the mouse.
+    /// - Without `TERM`, the terminal is unknown and gets 16 colours, or true colour on Windows
+    ///   (and in the Windows Terminal, which sets `WT_SESSION`).
+    /// - The locale (`LC_ALL`, `LC_CTYPE`, then `LANG`) decides whether Unicode is supported.
+    #[must_use]
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>, is_terminal: bool) -> Self {
+        let set = |name: &str| var(name).filter(|value| !value.is_empty());
+
+        let term = set("TERM").unwrap_or_default();
+        let colour_term = set("COLORTERM").unwrap_or_default().to_ascii_lowercase();
+        let no_colour = set("NO_COLOR").is_some();
+        let forced = set("CLICOLOR_FORCE").is_some_and(|value| value != "0");
+        let dumb = term == "dumb";
+        let windows = cfg!(windows) || set("WT_SESSION").is_some();
+
+        let colour_depth = if no_colour || (!forced && (!is_terminal || dumb)) {
+            ColourDepth::NoColour
+        } else if colour_term == "truecolor" || colour_term == "24bit" || term.ends_with("-direct") || (term.is_empty() && windows) {
+            ColourDepth::TrueColour
+        } else if term.contains("256color") {
+            ColourDepth::Ansi256
+        } else {
+            ColourDepth::Ansi16
+        };
+
+        // The Linux console and the VT terminals have no mouse reporting of their own.
+        let mouse = is_terminal && !dumb && term != "linux" && !term.starts_with("vt");
+
+        let locale = set("LC_ALL").or_else(|| set("LC_CTYPE")).or_else(|| set("LANG")).unwrap_or_default();
+        let locale = locale.to_ascii_lowercase();
+        let unicode = locale.contains("utf-8") || locale.contains("utf8");
+
+        Self {
+            is_terminal,
+            colour_depth,
+            mouse,
+            unicode,
+            sixel: false,
+        }
+    }
+
+    /// The most bytes that [`Capabilities::query`] reads while it waits for the DA1 response.
+    pub const MAX_RESPONSE_LEN: usize = 4096;
+
+    /// Send [`Capabilities::QUERY`] to the terminal through `output`, read its answers from `input`
+    /// until the DA1 response, and update the capabilities using them (see [`Capabilities::apply_response`]).
+    ///
+    /// The terminal has to be in raw mode while this runs, or its answers will be echoed and held back
+    /// until the user presses enter. This does nothing when stdout is not a terminal.
+    ///
+    /// At most [`Capabilities::MAX_RESPONSE_LEN`] bytes are read. A terminal that doesn't answer at
+    /// all still blocks reading, so `input` should time out (for example, by setting `VTIME` on the
+    /// terminal) when the caller can't wait forever.
+    ///
+    /// ```
+    /// use tuit::std::capabilities::Capabilities;
+    ///
+    /// let vars = |name: &str| (name == "TERM").then(|| "xterm".into());
+    /// let mut capabilities = Capabilities::from_vars(vars, true);
+    ///
+    /// // Unrelated input that arrived before the answers, like a pasted line, is skipped.
+    /// let mut input = vec![b'x'; 1000];
+    /// input.extend_from_slice(b"\x1b[?62;4c");
+    ///
+    /// let mut output = Vec::new();
+    /// capabilities.query(input.as_slice(), &mut output).expect("The terminal answered");
+    ///
+    /// assert_eq!(output, Capabilities::QUERY);
+    /// assert!(capabilities.sixel);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails when writing to `output` or reading from `input` fails, or when `input` ends (or
+    /// [`Capabilities::MAX_RESPONSE_LEN`] bytes have been read) before the terminal answers DA1.
+    pub fn query(&mut self, mut input: impl Read, mut output: impl Write) -> crate::Result<()> {
+        if !self.is_terminal {
+            return Ok(());
+        }
+
+        output.write_all(Self::QUERY)?;
+        output.flush()?;
+
+        // The XTGETTCAP answers come first, so only the start of a long response is kept. The DA1
+        // response is found as the bytes come in, wherever it is.
+        let mut response = [0_u8; 512];
+        let mut len = 0;
+        let mut da1 = Da1Scanner::default();
+        let mut byte = [0_u8];
+
+        for _ in 0..Self::MAX_RESPONSE_LEN {
+            input.read_exact(&mut byte)?;
+
+            if len < response.len() {
+                response[len] = byte[0];
+                len += 1;
+            }
+
+            if da1.advance(byte[0]) {
+                self.apply_response(&response[..len]);
+                self.sixel |= has_sixel(da1.attributes());
+
+                return Ok(());
+            }
+        }
+
+        Err(anyhow!("The terminal didn't answer DA1 within {} bytes", Self::MAX_RESPONSE_LEN).into())
+    }
+
+    /// Update the capabilities using the terminal's answers to [`Capabilities::QUERY`].
+    ///
+    /// - An XTGETTCAP answer for `RGB` or `Tc` enables true colour.
+    /// - An XTGETTCAP answer for `colors` of 256 or more enables (at least) 256 colours.
+    /// - A DA1 answer that includes attribute `4` enables Sixel graphics.
+    ///
+    /// Colours are never enabled when they have been disabled, such as by `NO_COLOR`.
+    ///
+    /// ```
+    /// use tuit::std::capabilities::Capabilities;
+    /// use tuit::style::ColourDepth;
+    ///
+    /// let vars = |name: &str| (name == "TERM").then(|| "xterm".into());
+    /// let mut capabilities = Capabilities::from_vars(vars, true);
+    ///
+    /// assert_eq!(capabilities.colour_depth, ColourDepth::Ansi16);
+    ///
+    /// // The terminal supports `RGB`, doesn't know about `Tc`, and has Sixel graphics.
+    /// capabilities.apply_response(b"\x1bP1+r524742=38\x1b\\\x1bP0+r5463\x1b\\\x1b[?62;4;22c");
+    ///
+    /// assert_eq!(capabilities.colour_depth, ColourDepth::TrueColour);
+    /// assert!(capabilities.sixel);
+    /// ```
+    pub fn apply_response(&mut self, response: &[u8]) {
+        let colours_allowed = self.colour_depth != ColourDepth::NoColour;
+
+        for answer in response.split(|&byte| byte == 0x1b) {
+            // Successful XTGETTCAP answers look like `ESC P 1 + r <name>[=<value>] ESC \`, in hex.
+            let Some(capabilities) = answer.strip_prefix(b"P1+r") else {
+                continue;
+            };
+
+            for capability in capabilities.split(|&byte| byte == b';') {
+                let mut parts = capability.splitn(2, |&byte| byte == b'=');
+                let name = parts.next().unwrap_or_default();
+                let value = parts.next().unwrap_or_default();
+
+                if !colours_allowed {
+                    break;
+                } else if name.eq_ignore_ascii_case(b"524742") || name.eq_ignore_ascii_case(b"5463") {
+                    self.colour_depth = ColourDepth::TrueColour;
+                } else if name.eq_ignore_ascii_case(b"636F6C6F7273") && decode_number(value) >= 256 {
+                    self.colour_depth = self.colour_depth.max(ColourDepth::Ansi256);
+                }
+            }
+        }
+
+        if let Some(attributes) = find_da1(response) {
+            self.sixel |= has_sixel(attributes);
+        }
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+/// Find the attributes of a DA1 response (`ESC [ ? <attributes> c`).
+fn find_da1(response: &[u8]) -> Option<&[u8]> {
+    let start = response.windows(3).position(|window| window == b"\x1b[?")? + 3;
+    let attributes = &response[start..];
+    let end = attributes.iter().position(|&byte| !(byte.is_ascii_digit() || byte == b';'))?;
+
+    (attributes[end] == b'c').then_some(&attributes[..end])
+}
+
+/// Whether the attributes of a DA1 response include Sixel graphics (attribute `4`).
+fn has_sixel(attributes: &[u8]) -> bool {
+    attributes.split(|&byte| byte == b';').any(|attribute| attribute == b"4")
+}
+
+/// Finds a DA1 response (`ESC [ ? <attributes> c`) in a stream of bytes, one byte at a time.
+#[derive(Default)]
+struct Da1Scanner {
+    /// How many bytes of the `ESC [ ?` prefix have been matched.
+    matched: usize,
+    attributes: [u8; 32],
+    len: usize,
+}
+
+impl Da1Scanner {
+    const PREFIX: &'static [u8] = b"\x1b[?";
+
+    /// Scan the next byte. Returns whether it ended a DA1 response.
+    fn advance(&mut self, byte: u8) -> bool {
+        if self.matched < Self::PREFIX.len() {
+            self.matched = if byte == Self::PREFIX[self.matched] { self.matched + 1 } else { usize::from(byte == 0x1b) };
+            self.len = 0;
+
+            return false;
+        }
+
+        match byte {
+            b'0'..=b'9' | b';' => {
+                // Attributes past the end of the buffer are dropped; Sixel is usually near the start.
+                if self.len < self.attributes.len() {
+                    self.attributes[self.len] = byte;
+                    self.len += 1;
+                }
+
+                false
+            }
+            b'c' => true,
+            _ => {
+                self.matched = usize::from(byte == 0x1b);
+
+                false
+            }
+        }
+    }
+
+    /// The attributes of the DA1 response, once it has ended.
+    fn attributes(&self) -> &[u8] {
+        &self.attributes[..self.len]
+    }
+}
+
+/// Decode a hex-encoded decimal number, such as `323536` for `256`. Returns 0 if it is malformed.
+fn decode_number(hex: &[u8]) -> u32 {
+    let mut number: u32 = 0;
+
+    for pair in hex.chunks(2) {
+        let digit = std::str::from_utf8(pair).ok().and_then(|pair| u8::from_str_radix(pair, 16).ok());
+
+        match digit {
+            Some(digit @ b'0'..=b'9') => number = number.saturating_mul(10).saturating_add(u32::from(digit - b'0')),
+            _ => return 0,
+        }
+    }
+
+    number
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::string::String;
+
+    use super::Capabilities;
+    use crate::style::ColourDepth;
+
+    #[test]
+    fn unknown_terminal() {
+        let no_term = |_: &str| None::<String>;
+        let windows_terminal = |name: &str| (name == "WT_SESSION").then(|| "0".into());
+
+        let expected = if cfg!(windows) { ColourDepth::TrueColour } else { ColourDepth::Ansi16 };
+
+        assert_eq!(Capabilities::from_vars(no_term, true).colour_depth, expected);
+        assert_eq!(Capabilities::from_vars(windows_terminal, true).colour_depth, ColourDepth::TrueColour);
+
+        let dumb = |name: &str| (name == "TERM").then(|| "dumb".into());
+
+        assert_eq!(Capabilities::from_vars(dumb, true).colour_depth, ColourDepth::NoColour);
+    }
+}
@@ -0,0 +1,417 @@
+extern crate std;
+
+use std::collections::VecDeque;
+use std::string::{String, ToString};
+use std::sync::{Mutex, PoisonError};
+use std::vec::Vec;
+
+use log::{Level, LevelFilter, Log, SetLoggerError};
+
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::{Style, Theme};
+use crate::terminal::{keys, KeyState, Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// A single log record, captured by a [`LogCapture`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CapturedRecord {
+    /// The level of the record.
+    pub level: Level,
+    /// The target of the record; usually the module path that it was logged from.
+    pub target: String,
+    /// The formatted message.
+    pub message: String,
+    /// The number of records captured before this one. It keeps counting up when old records are
+    /// dropped from the ring buffer, so it can be used to identify a record.
+    pub sequence: u64,
+}
+
+#[derive(Debug)]
+struct Ring {
+    records: VecDeque<CapturedRecord>,
+    next_sequence: u64,
+}
+
+/// A [`log::Log`] implementation that keeps the latest records in a ring buffer, instead of
+/// writing them to stderr (where they would corrupt a full-screen UI). Display them with a [`LogView`].
+///
+/// Since loggers have to live forever, a [`LogCapture`] is usually kept in a `static`:
+///
+/// ```
+/// use tuit::std::log_view::LogCapture;
+///
+/// static CAPTURE: LogCapture = LogCapture::new(500);
+///
+/// CAPTURE.install().expect("No other logger has been installed");
+///
+/// log::warn!(target: "example", "Something odd happened");
+///
+/// let last = CAPTURE.with_records(|records| records.back().cloned()).expect("A record was captured");
+///
+/// assert_eq!(last.level, log::Level::Warn);
+/// assert_eq!(last.message, "Something odd happened");
+/// ```
+#[derive(Debug)]
+pub struct LogCapture {
+    ring: Mutex<Ring>,
+    capacity: usize,
+    level: LevelFilter,
+}
+
+impl LogCapture {
+    /// Create a new [`LogCapture`] that holds on to the latest `capacity` records of any level.
+    #[must_use]
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            ring: Mutex::new(Ring {
+                records: VecDeque::new(),
+                next_sequence: 0,
+            }),
+            capacity,
+            level: LevelFilter::Trace,
+        }
+    }
+
+    /// Only capture records up to (and including) the specified level.
+    #[must_use]
+    pub const fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+
+        self
+    }
+
+    /// Set this [`LogCapture`] as the global logger, and raise [`log::max_level`] to match it.
+    ///
+    /// # Errors
+    ///
+    /// This fails if a global logger has already been set.
+    pub fn install(&'static self) -> Result<(), SetLoggerError> {
+        log::set_logger(self)?;
+        log::set_max_level(self.level);
+
+        Ok(())
+    }
+
+    /// Run a closure with the captured records, from oldest to newest.
+    ///
+    /// Logging from inside the closure will deadlock, as the records are locked while it runs.
+    pub fn with_records<R>(&self, f: impl FnOnce(&VecDeque<CapturedRecord>) -> R) -> R {
+        let ring = self.ring.lock().unwrap_or_else(PoisonError::into_inner);
+
+        f(&ring.records)
+    }
+
+    /// Returns the number of records that are currently held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.with_records(VecDeque::len)
+    }
+
+    /// Returns whether no records are currently held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every captured record.
+    pub fn clear(&self) {
+        self.ring.lock().unwrap_or_else(PoisonError::into_inner).records.clear();
+    }
+}
+
+impl Log for LogCapture {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) || self.capacity == 0 {
+            return;
+        }
+
+        let mut ring = self.ring.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if ring.records.len() >= self.capacity {
+            ring.records.pop_front();
+        }
+
+        let sequence = ring.next_sequence;
+        ring.next_sequence += 1;
+
+        ring.records.push_back(CapturedRecord {
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            sequence,
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// A widget that displays the records inside a [`LogCapture`], one per row, newest at the bottom.
+///
+/// By default, the [`LogView`] follows the tail of the log, so that new records show up as they
+/// arrive. Scrolling up (with the arrow keys, page up, or home) pins the view in place, and scrolling
+/// back down to the newest record (or pressing end) resumes following.
+///
+/// ```
+/// use log::LevelFilter;
+/// use tuit::prelude::*;
+/// use tuit::std::log_view::{LogCapture, LogView};
+/// use tuit::terminal::ConstantSize;
+///
+/// static CAPTURE: LogCapture = LogCapture::new(100);
+///
+/// CAPTURE.install().expect("No other logger has been installed");
+///
+/// log::info!(target: "net", "Connected");
+/// log::debug!(target: "net", "Sent 20 bytes");
+/// log::error!(target: "disk", "Out of space");
+///
+/// let mut terminal: ConstantSize<30, 2> = ConstantSize::new();
+/// let log_view = LogView::new(&CAPTURE).level(LevelFilter::Info);
+///
+/// log_view.drawn(&mut terminal).expect("Infallible");
+///
+/// let row: String = terminal.cells().take(30).map(|cell| cell.character).collect();
+///
+/// assert_eq!(row.trim_end(), "INFO  net: Connected");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LogView<'a> {
+    /// The records to display.
+    pub capture: &'a LogCapture,
+    /// Only records up to (and including) this level are shown.
+    pub level: LevelFilter,
+    /// If set, only records whose target starts with this prefix are shown.
+    pub target: Option<&'a str>,
+    /// Whether to show each record's target before its message.
+    pub show_target: bool,
+    /// The style of the level label of [`Level::Error`] records. Unset properties come from [`Theme::error`].
+    pub error_style: Style,
+    /// The style of the level label of [`Level::Warn`] records. Unset properties come from [`Theme::warning`].
+    pub warn_style: Style,
+    /// The style of the level label of [`Level::Info`] records. Unset properties come from [`Theme::success`].
+    pub info_style: Style,
+    /// The style of the level label of [`Level::Debug`] records. Unset properties come from [`Theme::info`].
+    pub debug_style: Style,
+    /// The style of the level label of [`Level::Trace`] records. Unset properties come from [`Theme::muted`].
+    pub trace_style: Style,
+    /// The style of each record's target. Unset properties come from [`Theme::muted`].
+    pub target_style: Style,
+    /// The style of each record's message. Unset properties come from [`Theme::text`].
+    pub message_style: Style,
+    /// The sequence number of the record shown on the bottom row, or `None` when following the tail.
+    anchor: Option<u64>,
+}
+
+impl<'a> LogView<'a> {
+    /// The width of the level labels, like `"ERROR"`, plus a space after them.
+    const LABEL_WIDTH: usize = 6;
+
+    /// Create a new [`LogView`] that shows every record, and follows the tail of the log.
+    #[must_use]
+    pub const fn new(capture: &'a LogCapture) -> Self {
+        Self {
+            capture,
+            level: LevelFilter::Trace,
+            target: None,
+            show_target: true,
+            error_style: Style::new(),
+            warn_style: Style::new(),
+            info_style: Style::new(),
+            debug_style: Style::new(),
+            trace_style: Style::new(),
+            target_style: Style::new(),
+            message_style: Style::new(),
+            anchor: None,
+        }
+    }
+
+    /// Only show records up to (and including) the specified level.
+    #[must_use]
+    pub const fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+
+        self
+    }
+
+    /// Only show records whose target starts with the specified prefix, or all records if `None`.
+    #[must_use]
+    pub const fn target(mut self, target: Option<&'a str>) -> Self {
+        self.target = target;
+
+        self
+    }
+
+    /// The [`Style`] used for the level label of records of the specified [`Level`], with the specified [`Theme`].
+    #[must_use]
+    pub const fn style_of(&self, level: Level, theme: &Theme) -> Style {
+        match level {
+            Level::Error => self.error_style.inherits(theme.error),
+            Level::Warn => self.warn_style.inherits(theme.warning),
+            Level::Info => self.info_style.inherits(theme.success),
+            Level::Debug => self.debug_style.inherits(theme.info),
+            Level::Trace => self.trace_style.inherits(theme.muted),
+        }
+    }
+
+    /// Returns whether the [`LogView`] is following the tail of the log.
+    #[must_use]
+    pub const fn is_following(&self) -> bool {
+        self.anchor.is_none()
+    }
+
+    /// Jump to the newest record, and keep following the tail of the log.
+    pub const fn follow(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Scroll by the specified number of rows; negative numbers scroll up (towards older records).
+    ///
+    /// The `height` is the number of rows that the [`LogView`] is drawn into, so that it doesn't
+    /// scroll past the oldest record. Scrolling down to the newest record resumes following the tail.
+    pub fn scroll(&mut self, rows: isize, height: usize) {
+        let sequences = self.visible_sequences();
+
+        let Some(last) = sequences.len().checked_sub(1) else {
+            return;
+        };
+
+        let bottom = self.bottom_position(&sequences);
+        let top_limit = height.min(sequences.len()).saturating_sub(1);
+
+        let new_bottom = bottom.saturating_add_signed(rows).clamp(top_limit, last);
+
+        self.anchor = (new_bottom != last).then(|| sequences[new_bottom]);
+    }
+
+    /// Whether a record passes the level and target filters.
+    fn shows(&self, record: &CapturedRecord) -> bool {
+        record.level <= self.level && self.target.is_none_or(|target| record.target.starts_with(target))
+    }
+
+    /// The sequence numbers of the records that pass the filters, from oldest to newest.
+    fn visible_sequences(&self) -> Vec<u64> {
+        self.capture.with_records(|records| {
+            records
+                .iter()
+                .filter(|record| self.shows(record))
+                .map(|record| record.sequence)
+                .collect()
+        })
+    }
+
+    /// The position of the bottom row's record inside the filtered records.
+    fn bottom_position(&self, sequences: &[u64]) -> usize {
+        let last = sequences.len().saturating_sub(1);
+
+        self.anchor.map_or(last, |anchor| {
+            sequences
+                .iter()
+                .rposition(|&sequence| sequence <= anchor)
+                .unwrap_or(0)
+        })
+    }
+
+    fn draw_record(&self, mut terminal: impl Terminal, record: &CapturedRecord, y: usize) -> crate::Result<()> {
+        let width = terminal.width();
+        let theme = terminal.theme();
+        let (target_style, message_style) = (self.target_style.inherits(theme.muted), self.message_style.inherits(theme.text));
+
+        let label = match record.level {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+
+        let padding = core::iter::repeat_n((' ', message_style), Self::LABEL_WIDTH - label.len());
+        let label_style = self.style_of(record.level, &theme);
+        let label = label.chars().map(|character| (character, label_style));
+
+        let target = self
+            .show_target
+            .then(|| record.target.chars().chain(": ".chars()).map(|character| (character, target_style)))
+            .into_iter()
+            .flatten();
+
+        // Control characters (like newlines inside of a message) would break the layout.
+        let message = record.message.chars().map(|character| {
+            let character = if character.is_control() { ' ' } else { character };
+
+            (character, message_style)
+        });
+
+        let line = label.chain(padding).chain(target).chain(message);
+
+        for (x, (character, style)) in line.take(width).enumerate() {
+            let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+
+            cell.character = character;
+            cell.style = style.inherits(cell.style);
+        }
+
+        Ok(())
+    }
+}
+
+impl Widget for LogView<'_> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let height = terminal.height();
+
+        let rows = match update_info {
+            UpdateInfo::KeyboardInput(keys::UP_ARROW, KeyState::KeyDown | KeyState::KeyHeld) => -1,
+            UpdateInfo::KeyboardInput(keys::DOWN_ARROW, KeyState::KeyDown | KeyState::KeyHeld) => 1,
+            UpdateInfo::KeyboardInput(keys::PAGE_UP, KeyState::KeyDown | KeyState::KeyHeld) => -(height as isize),
+            UpdateInfo::KeyboardInput(keys::PAGE_DOWN, KeyState::KeyDown | KeyState::KeyHeld) => height as isize,
+            UpdateInfo::KeyboardInput(keys::HOME, KeyState::KeyDown) => isize::MIN,
+            UpdateInfo::KeyboardInput(keys::END, KeyState::KeyDown) => {
+                self.follow();
+
+                return Ok(UpdateResult::Interacted);
+            }
+            _ => return Ok(UpdateResult::NoEvent),
+        };
+
+        self.scroll(rows, height);
+
+        Ok(UpdateResult::Interacted)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        for cell in terminal.cells_mut() {
+            cell.character = ' ';
+        }
+
+        let height = terminal.height();
+
+        self.capture.with_records(|records| {
+            let visible: Vec<&CapturedRecord> = records.iter().filter(|record| self.shows(record)).collect();
+            let sequences: Vec<u64> = visible.iter().map(|record| record.sequence).collect();
+
+            // Keep the view full when scrolled all the way up, even if the anchor is near the top.
+            let end = (self.bottom_position(&sequences) + 1).max(height).min(visible.len());
+            let start = end.saturating_sub(height);
+
+            for (y, record) in visible[start..end].iter().enumerate() {
+                self.draw_record(&mut terminal, record, y)?;
+            }
+
+            Ok(UpdateResult::NoEvent)
+        })
+    }
+}
+
+impl BoundingBox for LogView<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(rect)
+    }
+
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        true
+    }
+}
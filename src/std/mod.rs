@@ -3,4 +3,12 @@
 extern crate std;
 /// A terminal that outputs to [`std::io::stdout`].
 pub mod stdout_render;
+/// Detection of what the terminal can do, such as how many colours it can display.
+pub mod capabilities;
 mod errors;
+/// A [`log::Log`] implementation that captures records for the [`log_view::LogView`] widget.
+#[cfg(feature = "debug")]
+pub mod log_view;
+/// A widget that runs a program inside a pseudoterminal, and displays its screen.
+#[cfg(all(feature = "pty", unix))]
+pub mod pty;
@@ -0,0 +1,421 @@
+extern crate std;
+
+use std::io::{Read, Write};
+use std::process::{Child, Command, ExitStatus};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::vec;
+use std::vec::Vec;
+
+use anyhow::anyhow;
+use pty_process::blocking::Pty;
+use pty_process::Size;
+
+use crate::prelude::{Metadata, Terminal, TerminalConst, TerminalMut, Widget};
+use crate::style::Style;
+use crate::terminal::vt::VtParser;
+use crate::terminal::{keys, Cell, KeyState, Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// A widget that runs a program inside a pseudoterminal, and displays its screen.
+///
+/// The program's output is read on a background thread, and applied to the widget's own screen by
+/// a [`VtParser`] whenever the widget is updated (or [`PtyTerminal::poll`] is called). Keys are
+/// sent to the program the way an xterm would send them, and when the widget is updated with a
+/// terminal of a different size, the pseudoterminal is resized to match, so full-screen programs
+/// redraw themselves to fit the layout.
+///
+/// [`Widget::update`] returns [`UpdateResult::LifecycleEnd`] once the program has exited and all
+/// of its output has been applied. Dropping the widget kills the program if it is still running.
+///
+/// ```
+/// use std::process::Command;
+/// use std::time::{Duration, Instant};
+/// use tuit::prelude::*;
+/// use tuit::std::pty::PtyTerminal;
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut command = Command::new("printf");
+/// command.arg("Hello,\\n\\033[1mworld!");
+///
+/// let mut pty_terminal = PtyTerminal::spawn(&command, (10, 2)).expect("The program can be started");
+/// let deadline = Instant::now() + Duration::from_secs(10);
+///
+/// while pty_terminal.exit_status().is_none() && Instant::now() < deadline {
+///     pty_terminal.poll().expect("The program can be polled");
+///     std::thread::sleep(Duration::from_millis(10));
+/// }
+///
+/// let mut terminal: ConstantSize<10, 2> = ConstantSize::new();
+/// pty_terminal.drawn(&mut terminal).expect("Infallible");
+///
+/// let row: String = terminal.cells().skip(10).take(6).map(|cell| cell.character).collect();
+///
+/// assert_eq!(row, "world!");
+/// assert!(terminal.cell(0, 1).expect("Cell exists").style.font_weight > Some(400));
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct PtyTerminal {
+    /// The style of the cell under the cursor, while the program shows the cursor. Unset
+    /// properties come from the cell.
+    pub cursor_style: Style,
+    pty: Arc<Pty>,
+    child: Child,
+    output: Receiver<Vec<u8>>,
+    parser: VtParser<'static>,
+    screen: Screen,
+    exit_status: Option<ExitStatus>,
+}
+
+impl PtyTerminal {
+    /// Run a command inside a new pseudoterminal of the specified size, as `(width, height)`.
+    ///
+    /// The command's program, arguments, environment variables and working directory are used.
+    /// Unless the command sets `TERM`, it is set to `xterm-256color`.
+    ///
+    /// # Errors
+    ///
+    /// This fails if the pseudoterminal can't be opened or resized, or the program can't be started.
+    pub fn spawn(command: &Command, size: (usize, usize)) -> crate::Result<Self> {
+        let (pty, pts) = pty_process::blocking::open().map_err(|e| anyhow!(e))?;
+        pty.resize(pty_size(size)).map_err(|e| anyhow!(e))?;
+
+        let mut pty_command = pty_process::blocking::Command::new(command.get_program()).args(command.get_args());
+
+        for (key, value) in command.get_envs() {
+            pty_command = match value {
+                Some(value) => pty_command.env(key, value),
+                None => pty_command.env_remove(key),
+            };
+        }
+
+        if !command.get_envs().any(|(key, _)| key == "TERM") {
+            pty_command = pty_command.env("TERM", "xterm-256color");
+        }
+
+        if let Some(dir) = command.get_current_dir() {
+            pty_command = pty_command.current_dir(dir);
+        }
+
+        let child = pty_command.spawn(pts).map_err(|e| anyhow!(e))?;
+        let pty = Arc::new(pty);
+        let (sender, output) = mpsc::channel();
+        let reader = Arc::clone(&pty);
+
+        // Reading stops with an error once the program (and everything that it started) has
+        // closed the pseudoterminal.
+        std::thread::spawn(move || {
+            let mut buffer = [0; 4096];
+
+            while let Ok(read @ 1..) = (&*reader).read(&mut buffer) {
+                if sender.send(buffer[..read].to_vec()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            cursor_style: Style::new().inverted(),
+            pty,
+            child,
+            output,
+            parser: VtParser::new(),
+            screen: Screen::new(size),
+            exit_status: None,
+        })
+    }
+
+    /// Set the style of the cell under the cursor.
+    #[must_use]
+    pub const fn cursor_style(mut self, cursor_style: Style) -> Self {
+        self.cursor_style = cursor_style;
+
+        self
+    }
+
+    /// Send some bytes to the program, as if they were typed into its terminal.
+    ///
+    /// # Errors
+    ///
+    /// This fails if the bytes can't be written to the pseudoterminal.
+    pub fn write_input(&mut self, bytes: &[u8]) -> crate::Result<()> {
+        (&*self.pty).write_all(bytes)?;
+
+        Ok(())
+    }
+
+    /// Apply the output that the program has written since the last poll to the screen, without
+    /// waiting for more. Returns whether anything changed.
+    ///
+    /// # Errors
+    ///
+    /// This fails if the program's status can't be checked.
+    pub fn poll(&mut self) -> crate::Result<bool> {
+        let mut changed = false;
+
+        loop {
+            match self.output.try_recv() {
+                Ok(bytes) => {
+                    self.parser.parse(&mut self.screen, &bytes);
+                    changed = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    if self.exit_status.is_none() {
+                        self.exit_status = self.child.try_wait()?;
+                        changed |= self.exit_status.is_some();
+                    }
+
+                    break;
+                }
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Resize the screen and the pseudoterminal, as `(width, height)`. The program is told about
+    /// the new size, and usually redraws itself.
+    ///
+    /// # Errors
+    ///
+    /// This fails if the pseudoterminal can't be resized.
+    pub fn resize(&mut self, size: (usize, usize)) -> crate::Result<()> {
+        self.screen.resize(size);
+        self.pty.resize(pty_size(size)).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+
+    /// The program's exit status, once it has exited and all of its output has been applied.
+    #[must_use]
+    pub const fn exit_status(&self) -> Option<ExitStatus> {
+        self.exit_status
+    }
+
+    /// The program's screen, with the cursor left out.
+    #[must_use]
+    pub fn screen(&self) -> impl TerminalConst + '_ {
+        &self.screen
+    }
+
+    /// The [`VtParser`] that applies the program's output, which knows where the cursor is.
+    #[must_use]
+    pub const fn parser(&self) -> &VtParser<'static> {
+        &self.parser
+    }
+}
+
+impl Drop for PtyTerminal {
+    fn drop(&mut self) {
+        if self.exit_status.is_none() {
+            // The program might have already exited on its own, in which case these fail.
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+impl Widget for PtyTerminal {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let resized = terminal.dimensions() != self.screen.dimensions();
+
+        if resized {
+            self.resize(terminal.dimensions())?;
+        }
+
+        let mut buffer = [0; 4];
+
+        let input = match update_info {
+            UpdateInfo::KeyboardCharacter('\n', KeyState::KeyDown | KeyState::KeyHeld) => Some(&b"\r"[..]),
+            UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown | KeyState::KeyHeld) => Some(character.encode_utf8(&mut buffer).as_bytes()),
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => key_sequence(key),
+            _ => None,
+        };
+
+        if let Some(input) = input {
+            self.write_input(input)?;
+        }
+
+        let changed = self.poll()?;
+
+        if self.exit_status.is_some() {
+            Ok(UpdateResult::LifecycleEnd)
+        } else if changed || resized || input.is_some() {
+            Ok(UpdateResult::Interacted)
+        } else {
+            Ok(UpdateResult::NoEvent)
+        }
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let width = terminal.width().min(self.screen.width);
+        let height = terminal.height().min(self.screen.height);
+
+        for y in 0..height {
+            for x in 0..width {
+                let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+
+                *cell = self.screen.cells[y * self.screen.width + x];
+            }
+        }
+
+        let (x, y) = self.parser.cursor();
+
+        if self.parser.cursor_visible() && self.exit_status.is_none() && x < width && y < height {
+            if let Some(cell) = terminal.cell_mut(x, y) {
+                cell.style = self.cursor_style.inherits(cell.style);
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for PtyTerminal {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(rect)
+    }
+
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        true
+    }
+}
+
+/// The bytes that an xterm sends for a non-printable key.
+const fn key_sequence(key: u8) -> Option<&'static [u8]> {
+    let sequence: &[u8] = match key {
+        keys::ENTER => b"\r",
+        keys::ESCAPE => b"\x1b",
+        keys::BACKSPACE => b"\x7f",
+        keys::TAB => b"\t",
+        keys::SPACE => b" ",
+        keys::INSERT => b"\x1b[2~",
+        keys::HOME => b"\x1b[H",
+        keys::PAGE_UP => b"\x1b[5~",
+        keys::DELETE => b"\x1b[3~",
+        keys::END => b"\x1b[F",
+        keys::PAGE_DOWN => b"\x1b[6~",
+        keys::RIGHT_ARROW => b"\x1b[C",
+        keys::LEFT_ARROW => b"\x1b[D",
+        keys::DOWN_ARROW => b"\x1b[B",
+        keys::UP_ARROW => b"\x1b[A",
+        _ => return None,
+    };
+
+    Some(sequence)
+}
+
+/// A pseudoterminal size, from a `(width, height)`.
+fn pty_size((width, height): (usize, usize)) -> Size {
+    let clamp = |length: usize| u16::try_from(length).unwrap_or(u16::MAX);
+
+    Size::new(clamp(height), clamp(width))
+}
+
+/// The screen that the program's output is applied to.
+struct Screen {
+    cells: Vec<Cell>,
+    width: usize,
+    height: usize,
+}
+
+impl Screen {
+    fn new((width, height): (usize, usize)) -> Self {
+        Self {
+            cells: vec![Cell::new(' '); width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Change the size of the screen, keeping the cells that are still inside of it.
+    fn resize(&mut self, (width, height): (usize, usize)) {
+        let mut cells = vec![Cell::new(' '); width * height];
+
+        for y in 0..height.min(self.height) {
+            for x in 0..width.min(self.width) {
+                cells[y * width + x] = self.cells[y * self.width + x];
+            }
+        }
+
+        *self = Self { cells, width, height };
+    }
+}
+
+impl Metadata for Screen {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn default_style(&self) -> Style {
+        Style::new()
+    }
+}
+
+impl TerminalConst for Screen {
+    fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.cells.iter()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        (x < self.width).then(|| self.cells.get(y * self.width + x)).flatten()
+    }
+}
+
+impl TerminalMut for Screen {
+    fn cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        self.cells.iter_mut()
+    }
+
+    fn cell_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        (x < self.width).then(|| self.cells.get_mut(y * self.width + x)).flatten()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    use super::PtyTerminal;
+    use crate::prelude::*;
+    use crate::terminal::{keys, ConstantSize, KeyState, UpdateInfo, UpdateResult};
+
+    fn row(terminal: &impl TerminalConst, y: usize) -> String {
+        let width = terminal.width();
+
+        terminal.cells().skip(y * width).take(width).map(|cell| cell.character).collect()
+    }
+
+    #[test]
+    fn input_and_resizing() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "read line; printf '<%s> ' \"$line\"; stty size"]);
+
+        let mut pty_terminal = PtyTerminal::spawn(&command, (10, 2)).expect("The program can be started");
+        let terminal: ConstantSize<20, 3> = ConstantSize::new();
+
+        pty_terminal.update(UpdateInfo::KeyboardCharacter('o', KeyState::KeyDown), &terminal).expect("The key can be sent");
+        pty_terminal.update(UpdateInfo::KeyboardCharacter('k', KeyState::KeyDown), &terminal).expect("The key can be sent");
+        pty_terminal.update(UpdateInfo::KeyboardInput(keys::ENTER, KeyState::KeyDown), &terminal).expect("The key can be sent");
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut result = UpdateResult::NoEvent;
+
+        while result != UpdateResult::LifecycleEnd && Instant::now() < deadline {
+            result = pty_terminal.update(UpdateInfo::NoInfo, &terminal).expect("The program can be polled");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        assert_eq!(result, UpdateResult::LifecycleEnd);
+        assert!(pty_terminal.exit_status().is_some_and(|status| status.success()));
+        assert_eq!(pty_terminal.screen().dimensions(), (20, 3));
+        assert_eq!(row(&pty_terminal.screen(), 0).trim_end(), "ok");
+        assert_eq!(row(&pty_terminal.screen(), 1).trim_end(), "<ok> 3 20");
+    }
+}
@@ -4,11 +4,14 @@ use std::io::Write;
 
 use anyhow::anyhow;
 
-use crate::draw::Renderer;
-use crate::terminal::TerminalConst;
+use crate::draw::{OffCodes, Renderer};
+use crate::std::capabilities::Capabilities;
+use crate::style::{ColourDepth, Style};
+use crate::terminal::{Hyperlink, TerminalConst};
 
-/// Use [`StdoutRenderer::default`] to create a new [`StdoutRenderer`].
-/// 
+/// Use [`StdoutRenderer::default`] to create a new [`StdoutRenderer`]. Its colour depth is detected
+/// from the environment (see [`Capabilities::from_env`]).
+///
 /// # Example
 /// ```
 /// use tuit::std::stdout_render::StdoutRenderer;
@@ -20,11 +23,36 @@ use crate::terminal::TerminalConst;
 ///
 /// stdout.render(&terminal).expect("Failed to draw to stdout");
 /// ```
-pub struct StdoutRenderer(pub std::io::Stdout);
+pub struct StdoutRenderer {
+    /// The handle to stdout that is written into.
+    pub stdout: std::io::Stdout,
+    /// The colours that the terminal can display. See [`ColourDepth`].
+    pub colour_depth: ColourDepth,
+}
+
+impl StdoutRenderer {
+    /// Set the colours that the terminal can display.
+    #[must_use]
+    pub const fn colour_depth(mut self, colour_depth: ColourDepth) -> Self {
+        self.colour_depth = colour_depth;
+
+        self
+    }
+
+    /// Configure the renderer for the specified [`Capabilities`], such as ones that have been
+    /// updated using [`Capabilities::query`].
+    #[must_use]
+    pub const fn capabilities(self, capabilities: &Capabilities) -> Self {
+        self.colour_depth(capabilities.colour_depth)
+    }
+}
 
 impl Default for StdoutRenderer {
     fn default() -> Self {
-        Self(std::io::stdout())
+        Self {
+            stdout: std::io::stdout(),
+            colour_depth: Capabilities::from_env().colour_depth,
+        }
     }
 }
 
@@ -38,29 +66,67 @@ impl Renderer for StdoutRenderer {
         let terminal_width = terminal.width();
 
         let characters = terminal.cells();
+        let mut hyperlink = None;
+        // What the cells so far have left displayed, to know which attributes need turning off.
+        let mut shown = Style::new();
+        let mut continuation = false;
 
         for (idx, character_cell) in characters.enumerate() {
+            let mut character_cell = *character_cell;
+
+            character_cell.style = character_cell.style.downsampled(self.colour_depth);
+
             if idx % terminal_width == 0 {
+                // Links end with their row, so that they don't cover the newline.
+                if hyperlink.take().is_some() {
+                    write!(self.stdout, "{}", Hyperlink::END).map_err(|e| anyhow!(e))?;
+                }
+
                 let style: anstyle::Style = character_cell.style.into();
-                write!(self.0, "{style:#}").map_err(|e| anyhow!(e))?;
-                writeln!(self.0).map_err(|e| anyhow!(e))?;
-                write!(self.0, "{style}").map_err(|e| anyhow!(e))?;
+                write!(self.stdout, "{style:#}").map_err(|e| anyhow!(e))?;
+                writeln!(self.stdout).map_err(|e| anyhow!(e))?;
+                write!(self.stdout, "{style}").map_err(|e| anyhow!(e))?;
+
+                if style != anstyle::Style::new() {
+                    shown = Style::new();
+                }
+
+                continuation = false;
             }
 
-            let mut character_cell = *character_cell;
+            // The terminal draws a wide character over the cell to its right as well.
+            if continuation {
+                continuation = false;
+
+                continue;
+            }
+
+            continuation = character_cell.width() == 2;
 
             // Protect against alignment issues that can arise from characters
             // like `\0` or `\t` by replacing them with a space.
-            //
-            // FIXME: Wide characters not handled.
             if character_cell.character.is_whitespace() || character_cell.character.is_control() {
                 character_cell.character = ' ';
             }
 
-            write!(self.0, "{character_cell}").map_err(|e| anyhow!(e))?;
+            if character_cell.hyperlink != hyperlink {
+                match character_cell.hyperlink {
+                    Some(link) => write!(self.stdout, "{link}").map_err(|e| anyhow!(e))?,
+                    None => write!(self.stdout, "{}", Hyperlink::END).map_err(|e| anyhow!(e))?,
+                }
+
+                hyperlink = character_cell.hyperlink;
+            }
+
+            write!(self.stdout, "{}{character_cell}", OffCodes::new(character_cell.style, shown)).map_err(|e| anyhow!(e))?;
+            shown = character_cell.style.inherits(shown);
+        }
+
+        if hyperlink.is_some() {
+            write!(self.stdout, "{}", Hyperlink::END).map_err(|e| anyhow!(e))?;
         }
 
-        self.0.flush()?;
+        self.stdout.flush()?;
 
         Ok(())
     }
@@ -0,0 +1,386 @@
+use crate::style::Colour;
+
+/// A colour in terms of its hue, saturation and lightness. Convert it into a [`Colour`] with [`Colour::from_hsl`].
+///
+/// ```
+/// use tuit::style::{Colour, Hsl};
+///
+/// let orange = Hsl::new(30.0, 1.0, 0.5);
+///
+/// assert_eq!(Colour::from_hsl(orange), Colour::Rgb24(255, 128, 0));
+/// assert_eq!(Colour::Rgb24(255, 0, 0).to_hsl(), Some(Hsl::new(0.0, 1.0, 0.5)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct Hsl {
+    /// The hue, in degrees (from 0 up to 360). Red is at 0, green at 120, and blue at 240.
+    pub hue: f32,
+    /// How colourful the colour is, from 0 (gray) to 1.
+    pub saturation: f32,
+    /// How light the colour is, from 0 (black) through 0.5 (the pure colour) to 1 (white).
+    pub lightness: f32,
+}
+
+impl Hsl {
+    /// Create a new [`Hsl`] colour.
+    #[must_use]
+    pub const fn new(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self { hue, saturation, lightness }
+    }
+}
+
+/// A colour in terms of its hue, saturation and value. Convert it into a [`Colour`] with [`Colour::from_hsv`].
+///
+/// ```
+/// use tuit::style::{Colour, Hsv};
+///
+/// assert_eq!(Colour::from_hsv(Hsv::new(240.0, 1.0, 1.0)), Colour::Rgb24(0, 0, 255));
+/// assert_eq!(Colour::Rgb24(128, 128, 128).to_hsv().map(|hsv| hsv.saturation), Some(0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct Hsv {
+    /// The hue, in degrees (from 0 up to 360). Red is at 0, green at 120, and blue at 240.
+    pub hue: f32,
+    /// How colourful the colour is, from 0 (gray) to 1.
+    pub saturation: f32,
+    /// How bright the colour is, from 0 (black) to 1.
+    pub value: f32,
+}
+
+impl Hsv {
+    /// Create a new [`Hsv`] colour.
+    #[must_use]
+    pub const fn new(hue: f32, saturation: f32, value: f32) -> Self {
+        Self { hue, saturation, value }
+    }
+}
+
+impl Colour {
+    /// The linear light of each sRGB channel value, for calculating [`Colour::relative_luminance`].
+    ///
+    /// The sRGB transfer function needs `powf`, which isn't available in `core` (let alone in a `const fn`).
+    const LINEAR: [f32; 256] = [
+        0.0, 0.000_303_527, 0.000_607_054, 0.000_910_581, 0.001_214_108, 0.001_517_635, 0.001_821_162, 0.002_124_689,
+        0.002_428_216, 0.002_731_743, 0.003_035_27, 0.003_346_536, 0.003_676_507, 0.004_024_717, 0.004_391_442, 0.004_776_953,
+        0.005_181_517, 0.005_605_392, 0.006_048_833, 0.006_512_091, 0.006_995_41, 0.007_499_032, 0.008_023_193, 0.008_568_126,
+        0.009_134_059, 0.009_721_217, 0.010_329_82, 0.010_960_09, 0.011_612_25, 0.012_286_49, 0.012_983_03, 0.013_702_08,
+        0.014_443_84, 0.015_208_51, 0.015_996_29, 0.016_807_38, 0.017_641_95, 0.018_500_22, 0.019_382_36, 0.020_288_56,
+        0.021_219_01, 0.022_173_88, 0.023_153_37, 0.024_157_63, 0.025_186_86, 0.026_241_22, 0.027_320_89, 0.028_426_04,
+        0.029_556_83, 0.030_713_44, 0.031_896_03, 0.033_104_77, 0.034_339_81, 0.035_601_31, 0.036_889_45, 0.038_204_37,
+        0.039_546_24, 0.040_915_2, 0.042_311_41, 0.043_735_03, 0.045_186_2, 0.046_665_09, 0.048_171_82, 0.049_706_57,
+        0.051_269_46, 0.052_860_65, 0.054_480_28, 0.056_128_49, 0.057_805_43, 0.059_511_24, 0.061_246_05, 0.063_010_02,
+        0.064_803_27, 0.066_625_94, 0.068_478_17, 0.070_360_1, 0.072_271_85, 0.074_213_57, 0.076_185_38, 0.078_187_42,
+        0.080_219_82, 0.082_282_71, 0.084_376_21, 0.086_500_46, 0.088_655_59, 0.090_841_71, 0.093_058_96, 0.095_307_47,
+        0.097_587_35, 0.099_898_73, 0.102_241_7, 0.104_616_5, 0.107_023_1, 0.109_461_7, 0.111_932_4, 0.114_435_4,
+        0.116_970_7, 0.119_538_4, 0.122_138_8, 0.124_771_8, 0.127_437_7, 0.130_136_5, 0.132_868_3, 0.135_633_3,
+        0.138_431_6, 0.141_263_3, 0.144_128_5, 0.147_027_3, 0.149_959_8, 0.152_926_2, 0.155_926_5, 0.158_960_8,
+        0.162_029_4, 0.165_132_2, 0.168_269_4, 0.171_441_1, 0.174_647_4, 0.177_888_4, 0.181_164_2, 0.184_475,
+        0.187_820_8, 0.191_201_7, 0.194_617_8, 0.198_069_3, 0.201_556_3, 0.205_078_7, 0.208_636_9, 0.212_230_8,
+        0.215_860_5, 0.219_526_2, 0.223_228, 0.226_965_9, 0.230_74, 0.234_550_6, 0.238_397_6, 0.242_281_1,
+        0.246_201_3, 0.250_158_3, 0.254_152_1, 0.258_182_9, 0.262_250_7, 0.266_355_6, 0.270_497_8, 0.274_677_3,
+        0.278_894_3, 0.283_148_7, 0.287_440_8, 0.291_770_6, 0.296_138_3, 0.300_543_8, 0.304_987_3, 0.309_468_9,
+        0.313_988_7, 0.318_546_8, 0.323_143_2, 0.327_778_1, 0.332_451_5, 0.337_163_6, 0.341_914_4, 0.346_704_1,
+        0.351_532_6, 0.356_400_1, 0.361_306_8, 0.366_252_6, 0.371_237_7, 0.376_262_1, 0.381_326, 0.386_429_4,
+        0.391_572_5, 0.396_755_2, 0.401_977_8, 0.407_240_2, 0.412_542_6, 0.417_885_1, 0.423_267_7, 0.428_690_5,
+        0.434_153_6, 0.439_657_2, 0.445_201_2, 0.450_785_8, 0.456_411, 0.462_077, 0.467_783_8, 0.473_531_5,
+        0.479_320_2, 0.485_149_9, 0.491_020_8, 0.496_933, 0.502_886_5, 0.508_881_3, 0.514_917_7, 0.520_995_6,
+        0.527_115_1, 0.533_276_4, 0.539_479_5, 0.545_724_5, 0.552_011_4, 0.558_340_4, 0.564_711_5, 0.571_124_8,
+        0.577_580_4, 0.584_078_4, 0.590_618_8, 0.597_201_8, 0.603_827_3, 0.610_495_6, 0.617_206_6, 0.623_960_4,
+        0.630_757_1, 0.637_596_9, 0.644_479_7, 0.651_405_6, 0.658_374_8, 0.665_387_3, 0.672_443_2, 0.679_542_5,
+        0.686_685_3, 0.693_871_8, 0.701_101_9, 0.708_375_8, 0.715_693_5, 0.723_055_1, 0.730_460_7, 0.737_910_4,
+        0.745_404_2, 0.752_942_2, 0.760_524_5, 0.768_151_1, 0.775_822_2, 0.783_537_8, 0.791_297_9, 0.799_102_7,
+        0.806_952_3, 0.814_846_6, 0.822_785_8, 0.830_769_9, 0.838_799, 0.846_873_2, 0.854_992_6, 0.863_157_2,
+        0.871_367_1, 0.879_622_4, 0.887_923_1, 0.896_269_4, 0.904_661_2, 0.913_098_7, 0.921_581_9, 0.930_110_9,
+        0.938_685_7, 0.947_306_5, 0.955_973_4, 0.964_686_2, 0.973_445_3, 0.982_250_6, 0.991_102_1, 1.0,
+    ];
+
+    /// Convert the colour into RGB.
+    ///
+    /// [`Colour::Ansi16`] and [`Colour::Ansi256`] are converted using xterm's default palette:
+    ///
+    /// | Colour  | Normal          | Bright          |
+    /// |---------|-----------------|-----------------|
+    /// | Black   | `(0, 0, 0)`     | `(127, 127, 127)` |
+    /// | Red     | `(205, 0, 0)`   | `(255, 0, 0)`   |
+    /// | Green   | `(0, 205, 0)`   | `(0, 255, 0)`   |
+    /// | Yellow  | `(205, 205, 0)` | `(255, 255, 0)` |
+    /// | Blue    | `(0, 0, 238)`   | `(92, 92, 255)` |
+    /// | Magenta | `(205, 0, 205)` | `(255, 0, 255)` |
+    /// | Cyan    | `(0, 205, 205)` | `(0, 255, 255)` |
+    /// | White   | `(229, 229, 229)` | `(255, 255, 255)` |
+    ///
+    /// The first 16 of the 256 colours are the colours above. They are followed by a 6x6x6 colour
+    /// cube (16 to 231) whose channels use the levels 0, 95, 135, 175, 215 and 255, and by a
+    /// grayscale ramp (232 to 255) that goes from 8 to 238 in steps of 10.
+    ///
+    /// Terminal themes often change these colours, so the result is only a best guess. The
+    /// terminal's default colour is unknown, so [`Colour::TerminalDefault`] returns `None`.
+    ///
+    /// ```
+    /// use tuit::style::{Ansi4, Colour};
+    ///
+    /// assert_eq!(Colour::Ansi16(Ansi4::Blue).to_rgb(), Some((0, 0, 238)));
+    /// assert_eq!(Colour::Ansi256(208).to_rgb(), Some((255, 135, 0)));
+    /// assert_eq!(Colour::Luma8(42).to_rgb(), Some((42, 42, 42)));
+    /// assert_eq!(Colour::TerminalDefault.to_rgb(), None);
+    /// ```
+    #[must_use]
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::Rgb24(red, green, blue) => Some((red, green, blue)),
+            Self::Luma8(luma) => Some((luma, luma, luma)),
+            Self::Ansi16(colour) => Some(Self::ANSI16[colour as usize].1),
+            Self::Ansi256(index) => Some(Self::ansi256_rgb(index)),
+            Self::TerminalDefault => None,
+        }
+    }
+
+    /// Convert the colour into [`Hsl`]. Returns `None` for [`Colour::TerminalDefault`].
+    #[must_use]
+    pub const fn to_hsl(self) -> Option<Hsl> {
+        let Some(rgb) = self.to_rgb() else {
+            return None;
+        };
+
+        let (hue, max, min) = hue_max_min(rgb);
+        let lightness = f32::midpoint(max, min);
+        let saturation = if max - min > 0.0 {
+            (max - min) / (1.0 - abs(2.0 * lightness - 1.0))
+        } else {
+            0.0
+        };
+
+        Some(Hsl::new(hue, saturation, lightness))
+    }
+
+    /// Create a [`Colour::Rgb24`] out of an [`Hsl`] colour. The hue wraps around, and the
+    /// saturation and lightness are clamped between 0 and 1.
+    #[must_use]
+    pub const fn from_hsl(hsl: Hsl) -> Self {
+        let lightness = clamp_unit(hsl.lightness);
+        let chroma = (1.0 - abs(2.0 * lightness - 1.0)) * clamp_unit(hsl.saturation);
+
+        from_hue_chroma(hsl.hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Convert the colour into [`Hsv`]. Returns `None` for [`Colour::TerminalDefault`].
+    #[must_use]
+    pub const fn to_hsv(self) -> Option<Hsv> {
+        let Some(rgb) = self.to_rgb() else {
+            return None;
+        };
+
+        let (hue, max, min) = hue_max_min(rgb);
+        let saturation = if max > 0.0 { (max - min) / max } else { 0.0 };
+
+        Some(Hsv::new(hue, saturation, max))
+    }
+
+    /// Create a [`Colour::Rgb24`] out of an [`Hsv`] colour. The hue wraps around, and the
+    /// saturation and value are clamped between 0 and 1.
+    #[must_use]
+    pub const fn from_hsv(hsv: Hsv) -> Self {
+        let value = clamp_unit(hsv.value);
+        let chroma = value * clamp_unit(hsv.saturation);
+
+        from_hue_chroma(hsv.hue, chroma, value - chroma)
+    }
+
+    /// Linearly interpolate between two colours in RGB: a `factor` of 0 gives `self`, and 1 gives
+    /// `other`. The `factor` is clamped between 0 and 1.
+    ///
+    /// When either colour is [`Colour::TerminalDefault`], which can't be mixed, the colour that
+    /// `factor` is closer to is returned as-is.
+    ///
+    /// ```
+    /// use tuit::style::Colour;
+    ///
+    /// let black = Colour::Rgb24(0, 0, 0);
+    /// let white = Colour::Luma8(255);
+    ///
+    /// assert_eq!(black.lerp(white, 0.5), Colour::Rgb24(128, 128, 128));
+    /// assert_eq!(black.lerp(Colour::TerminalDefault, 0.25), black);
+    /// ```
+    #[must_use]
+    pub const fn lerp(self, other: Self, factor: f32) -> Self {
+        const fn mix(from: u8, to: u8, factor: f32) -> u8 {
+            to_channel((from as f32 + (to as f32 - from as f32) * factor) / 255.0)
+        }
+
+        let factor = clamp_unit(factor);
+
+        match (self.to_rgb(), other.to_rgb()) {
+            (Some(from), Some(to)) => Self::Rgb24(mix(from.0, to.0, factor), mix(from.1, to.1, factor), mix(from.2, to.2, factor)),
+            _ if factor < 0.5 => self,
+            _ => other,
+        }
+    }
+
+    /// Draw the colour over a `background` with the specified opacity (`alpha`), from 0
+    /// (invisible) to 1 (opaque). This is the same as `background.lerp(self, alpha)`.
+    ///
+    /// ```
+    /// use tuit::style::Colour;
+    ///
+    /// let highlight = Colour::Rgb24(255, 255, 0).blend(Colour::Rgb24(0, 0, 255), 0.25);
+    ///
+    /// assert_eq!(highlight, Colour::Rgb24(64, 64, 191));
+    /// ```
+    #[must_use]
+    pub const fn blend(self, background: Self, alpha: f32) -> Self {
+        background.lerp(self, alpha)
+    }
+
+    /// Make the colour lighter, by adding `amount` to its [`Hsl::lightness`].
+    ///
+    /// [`Colour::TerminalDefault`] is returned as-is, as are all of these helpers.
+    ///
+    /// ```
+    /// use tuit::style::Colour;
+    ///
+    /// let red = Colour::Rgb24(255, 0, 0);
+    ///
+    /// assert_eq!(red.lighten(0.25), Colour::Rgb24(255, 128, 128));
+    /// assert_eq!(red.darken(0.25), Colour::Rgb24(128, 0, 0));
+    /// assert_eq!(red.desaturate(1.0), Colour::Rgb24(128, 128, 128));
+    /// ```
+    #[must_use]
+    pub const fn lighten(self, amount: f32) -> Self {
+        match self.to_hsl() {
+            Some(hsl) => Self::from_hsl(Hsl::new(hsl.hue, hsl.saturation, hsl.lightness + amount)),
+            None => self,
+        }
+    }
+
+    /// Make the colour darker, by subtracting `amount` from its [`Hsl::lightness`].
+    #[must_use]
+    pub const fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Make the colour more colourful, by adding `amount` to its [`Hsl::saturation`].
+    #[must_use]
+    pub const fn saturate(self, amount: f32) -> Self {
+        match self.to_hsl() {
+            Some(hsl) => Self::from_hsl(Hsl::new(hsl.hue, hsl.saturation + amount, hsl.lightness)),
+            None => self,
+        }
+    }
+
+    /// Make the colour more gray, by subtracting `amount` from its [`Hsl::saturation`].
+    #[must_use]
+    pub const fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// The [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance) of the
+    /// colour, from 0 (black) to 1 (white). Returns `None` for [`Colour::TerminalDefault`].
+    #[must_use]
+    pub const fn relative_luminance(self) -> Option<f32> {
+        let Some((red, green, blue)) = self.to_rgb() else {
+            return None;
+        };
+
+        Some(0.2126 * Self::LINEAR[red as usize] + 0.7152 * Self::LINEAR[green as usize] + 0.0722 * Self::LINEAR[blue as usize])
+    }
+
+    /// The [contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between two colours,
+    /// from 1 (no contrast) to 21 (black on white). WCAG asks for at least 4.5 for normal text.
+    ///
+    /// Returns `None` if either colour is [`Colour::TerminalDefault`].
+    ///
+    /// ```
+    /// use tuit::style::Colour;
+    ///
+    /// let ratio = Colour::Luma8(0).contrast_ratio(Colour::Luma8(255)).expect("Both colours are known");
+    ///
+    /// assert!((ratio - 21.0).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub const fn contrast_ratio(self, other: Self) -> Option<f32> {
+        let (Some(lhs), Some(rhs)) = (self.relative_luminance(), other.relative_luminance()) else {
+            return None;
+        };
+
+        let (lighter, darker) = if lhs > rhs { (lhs, rhs) } else { (rhs, lhs) };
+
+        Some((lighter + 0.05) / (darker + 0.05))
+    }
+}
+
+/// The hue of an RGB colour, along with its largest and smallest channels (from 0 to 1).
+const fn hue_max_min((red, green, blue): (u8, u8, u8)) -> (f32, f32, f32) {
+    let (red, green, blue) = (red as f32 / 255.0, green as f32 / 255.0, blue as f32 / 255.0);
+
+    let max = if red > green { red } else { green };
+    let max = if max > blue { max } else { blue };
+    let min = if red < green { red } else { green };
+    let min = if min < blue { min } else { blue };
+    let delta = max - min;
+
+    let sector = if delta <= 0.0 {
+        0.0
+    } else if red >= green && red >= blue {
+        (green - blue) / delta
+    } else if green >= blue {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+
+    (wrap(sector * 60.0, 360.0), max, min)
+}
+
+/// Create a colour out of its hue, its chroma and the amount that is added to every channel.
+#[allow(clippy::cast_sign_loss)] // The sector is never negative, since the hue is wrapped.
+const fn from_hue_chroma(hue: f32, chroma: f32, offset: f32) -> Colour {
+    let sector = wrap(hue, 360.0) / 60.0;
+    let second = chroma * (1.0 - abs(wrap(sector, 2.0) - 1.0));
+
+    let (red, green, blue) = match sector as u8 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+
+    Colour::Rgb24(to_channel(red + offset), to_channel(green + offset), to_channel(blue + offset))
+}
+
+/// Turn a channel from 0 to 1 into a byte, rounding it to the closest value.
+#[allow(clippy::cast_sign_loss)] // Float to int casts saturate, so negative values become 0.
+const fn to_channel(value: f32) -> u8 {
+    (value * 255.0 + 0.5) as u8
+}
+
+/// Like `value.abs()`, but `const` and available without `std`.
+const fn abs(value: f32) -> f32 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Like `value.clamp(0.0, 1.0)`, but `const`.
+const fn clamp_unit(value: f32) -> f32 {
+    if value < 0.0 {
+        0.0
+    } else if value > 1.0 {
+        1.0
+    } else {
+        value
+    }
+}
+
+/// Like `value.rem_euclid(modulus)`, but `const` and available without `std`.
+const fn wrap(value: f32, modulus: f32) -> f32 {
+    let wrapped = value - (value / modulus) as i32 as f32 * modulus;
+
+    if wrapped < 0.0 {
+        wrapped + modulus
+    } else {
+        wrapped
+    }
+}
@@ -1,5 +1,15 @@
 //! All the important types for styling in Tuit. Contains structs like `Colour` and `Style`.
 
+pub use colour_math::{Hsl, Hsv};
+pub use palette::Palette;
+pub use parse::{ParseError, ParseErrorKind};
+pub use theme::Theme;
+
+mod colour_math;
+mod palette;
+mod parse;
+mod theme;
+
 /// Represents a 4-bit ANSI terminal colour.
 /// <br /> <br />
 /// Usually, two of these are used in a terminal to create an 8-bit colour consisting
@@ -17,6 +27,7 @@
 /// let my_colour: u8 = my_foreground_colour | my_background_colour;
 /// ```
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 #[allow(missing_docs)]
 pub enum Ansi4 {
@@ -58,6 +69,7 @@ pub enum Ansi4 {
 /// rule of thumb though, they should never panic when they see an unimplemented colour.
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Colour {
     /// True colour
     Rgb24(u8, u8, u8),
@@ -72,7 +84,179 @@ pub enum Colour {
     TerminalDefault,
 }
 
+/// How many colours a terminal can display. Renderers use it to convert colours that the terminal
+/// can't display into the closest ones that it can, using [`Colour::downsample`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColourDepth {
+    /// No colours at all; only attributes like bold and underline are kept.
+    NoColour,
+    /// The 16 ANSI colours.
+    Ansi16,
+    /// The 256 ANSI colours.
+    Ansi256,
+    /// 24-bit "true" colour. Every colour is displayed as-is.
+    #[default]
+    TrueColour,
+}
+
+impl Colour {
+    /// The 16 ANSI colours, as xterm displays them by default. Terminal themes often change these.
+    pub(crate) const ANSI16: [(Ansi4, (u8, u8, u8)); 16] = [
+        (Ansi4::Black, (0, 0, 0)),
+        (Ansi4::Red, (205, 0, 0)),
+        (Ansi4::Green, (0, 205, 0)),
+        (Ansi4::Yellow, (205, 205, 0)),
+        (Ansi4::Blue, (0, 0, 238)),
+        (Ansi4::Magenta, (205, 0, 205)),
+        (Ansi4::Cyan, (0, 205, 205)),
+        (Ansi4::White, (229, 229, 229)),
+        (Ansi4::BrightBlack, (127, 127, 127)),
+        (Ansi4::BrightRed, (255, 0, 0)),
+        (Ansi4::BrightGreen, (0, 255, 0)),
+        (Ansi4::BrightYellow, (255, 255, 0)),
+        (Ansi4::BrightBlue, (92, 92, 255)),
+        (Ansi4::BrightMagenta, (255, 0, 255)),
+        (Ansi4::BrightCyan, (0, 255, 255)),
+        (Ansi4::BrightWhite, (255, 255, 255)),
+    ];
+
+    /// The levels of each channel inside the 6x6x6 colour cube of the 256 ANSI colours.
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    /// Convert the colour into the closest colour that can be displayed at the specified [`ColourDepth`].
+    ///
+    /// Colours are matched using a perceptual distance (which weighs the channels according to
+    /// how sensitive the eye is to them), so the result is the colour that looks the most similar,
+    /// not the one with the closest numbers. [`Colour::TerminalDefault`] is never changed, and
+    /// [`ColourDepth::NoColour`] always returns `None`.
+    ///
+    /// ```
+    /// use tuit::style::{Ansi4, Colour, ColourDepth};
+    ///
+    /// let orange = Colour::Rgb24(255, 135, 0);
+    ///
+    /// assert_eq!(orange.downsample(ColourDepth::TrueColour), Some(orange));
+    /// assert_eq!(orange.downsample(ColourDepth::Ansi256), Some(Colour::Ansi256(208)));
+    /// assert_eq!(orange.downsample(ColourDepth::Ansi16), Some(Colour::Ansi16(Ansi4::Yellow)));
+    /// assert_eq!(orange.downsample(ColourDepth::NoColour), None);
+    /// ```
+    #[must_use]
+    pub const fn downsample(self, depth: ColourDepth) -> Option<Self> {
+        let rgb = match (self, depth) {
+            (_, ColourDepth::NoColour) => return None,
+            (Self::TerminalDefault | Self::Ansi16(_), _) | (_, ColourDepth::TrueColour) | (Self::Ansi256(_), ColourDepth::Ansi256) => return Some(self),
+            (Self::Ansi256(index), ColourDepth::Ansi16) if index < 16 => return Some(Self::Ansi16(Self::ANSI16[index as usize].0)),
+            (Self::Rgb24(red, green, blue), _) => (red, green, blue),
+            (Self::Luma8(luma), _) => (luma, luma, luma),
+            (Self::Ansi256(index), _) => Self::ansi256_rgb(index),
+        };
+
+        if matches!(depth, ColourDepth::Ansi16) {
+            Some(Self::Ansi16(Self::nearest_ansi16(rgb)))
+        } else {
+            Some(Self::Ansi256(Self::nearest_ansi256(rgb)))
+        }
+    }
+
+    /// The RGB value of one of the 256 ANSI colours, assuming xterm's default palette.
+    pub(crate) const fn ansi256_rgb(index: u8) -> (u8, u8, u8) {
+        match index {
+            0..16 => Self::ANSI16[index as usize].1,
+            16..232 => {
+                let cube = index - 16;
+
+                (
+                    Self::CUBE_LEVELS[(cube / 36) as usize],
+                    Self::CUBE_LEVELS[(cube / 6 % 6) as usize],
+                    Self::CUBE_LEVELS[(cube % 6) as usize],
+                )
+            }
+            _ => {
+                let gray = 8 + (index - 232) * 10;
+
+                (gray, gray, gray)
+            }
+        }
+    }
+
+    /// Find the closest of the 16 ANSI colours.
+    pub(crate) const fn nearest_ansi16(rgb: (u8, u8, u8)) -> Ansi4 {
+        let mut closest = 0;
+        let mut idx = 1;
+
+        while idx < Self::ANSI16.len() {
+            if perceptual_distance(rgb, Self::ANSI16[idx].1) < perceptual_distance(rgb, Self::ANSI16[closest].1) {
+                closest = idx;
+            }
+
+            idx += 1;
+        }
+
+        Self::ANSI16[closest].0
+    }
+
+    /// Find the closest of the 256 ANSI colours, out of the colour cube and the grayscale ramp.
+    ///
+    /// The first 16 colours are skipped, because terminal themes often change them.
+    pub(crate) const fn nearest_ansi256(rgb: (u8, u8, u8)) -> u8 {
+        const fn level(channel: u8) -> u8 {
+            match channel {
+                0..48 => 0,
+                48..115 => 1,
+                _ => (channel - 35) / 40,
+            }
+        }
+
+        let (red, green, blue) = (level(rgb.0), level(rgb.1), level(rgb.2));
+        let cube = 16 + red * 36 + green * 6 + blue;
+
+        // The grayscale ramp goes from 8 to 238, in steps of 10.
+        let average = ((rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3) as u8;
+        let gray_step = average.saturating_sub(3) / 10;
+        let gray = 232 + if gray_step > 23 { 23 } else { gray_step };
+
+        if perceptual_distance(rgb, Self::ansi256_rgb(gray)) < perceptual_distance(rgb, Self::ansi256_rgb(cube)) {
+            gray
+        } else {
+            cube
+        }
+    }
+}
+
+/// The "redmean" colour distance: a squared RGB distance whose channel weights depend on how red
+/// the colours are, which approximates human perception much better than a plain RGB distance.
+pub(crate) const fn perceptual_distance(lhs: (u8, u8, u8), rhs: (u8, u8, u8)) -> u32 {
+    let red_mean = u32::midpoint(lhs.0 as u32, rhs.0 as u32);
+
+    let red = lhs.0.abs_diff(rhs.0) as u32;
+    let green = lhs.1.abs_diff(rhs.1) as u32;
+    let blue = lhs.2.abs_diff(rhs.2) as u32;
+
+    (((512 + red_mean) * red * red) >> 8) + 4 * green * green + (((767 - red_mean) * blue * blue) >> 8)
+}
+
+/// The shape of the line that underlines a cell. See [`Style::underline_variant`].
+///
+/// Many terminals only support [`Underline::Single`], and draw the other variants as a single underline.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Underline {
+    /// A single straight line.
+    #[default]
+    Single,
+    /// Two straight lines.
+    Double,
+    /// A wavy line, like the ones that spell-checkers use.
+    Curly,
+    /// A dotted line.
+    Dotted,
+    /// A dashed line.
+    Dashed,
+}
+
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// This struct contains a cell's styling data.
 /// If a field is set to none, it will use the data from the last cell in the terminal that had it set.
@@ -121,6 +305,31 @@ pub struct Style {
     ///
     /// When it is None, assume the italicization to be unset (use the italicization setting of the preceding cell)
     pub italic: Option<bool>,
+    /// Whether the cell is dimmed (drawn with a fainter colour). This is separate from the
+    /// [`Style::font_weight`], since terminals usually show it using colour instead of a thinner font.
+    ///
+    /// When it is None, assume the dimming to be unset (use the dimming of the preceding cell)
+    pub dim: Option<bool>,
+    /// Whether the cell blinks.
+    ///
+    /// When it is None, assume the blinking to be unset (use the blinking of the preceding cell)
+    pub blink: Option<bool>,
+    /// Whether the cell's character is hidden (concealed), like when typing a password.
+    ///
+    /// When it is None, assume the concealment to be unset (use the concealment of the preceding cell)
+    pub hidden: Option<bool>,
+    /// Whether the cell has a line running along its top.
+    ///
+    /// When it is None, assume the overline to be unset (use the overline of the preceding cell)
+    pub overline: Option<bool>,
+    /// The shape of the underline, when [`Style::underline`] is enabled.
+    ///
+    /// When it is None, assume the shape to be unset (use the shape of the preceding cell, or [`Underline::Single`])
+    pub underline_variant: Option<Underline>,
+    /// The colour of the underline, when [`Style::underline`] is enabled.
+    ///
+    /// When it is None, assume the colour to be unset (use the colour of the preceding cell, or the foreground colour)
+    pub underline_colour: Option<Colour>,
 }
 
 impl Style {
@@ -138,7 +347,13 @@ impl Style {
             underline: None,
             invert: None,
             strikethrough: None,
-            italic: None
+            italic: None,
+            dim: None,
+            blink: None,
+            hidden: None,
+            overline: None,
+            underline_variant: None,
+            underline_colour: None,
         }
     }
 
@@ -438,6 +653,117 @@ impl Style {
         self.italicization(false)
     }
 
+    /// Used to set the terminal's dimming to a user-defined value.
+    ///
+    /// Refer to [`Style::dim`] for an explanation on dimming.
+    #[must_use]
+    pub const fn dimming(mut self, dim: bool) -> Self {
+        self.dim = Some(dim);
+
+        self
+    }
+
+    /// Used to set the terminal's dimming to specifically **true**
+    #[must_use]
+    pub const fn dimmed(self) -> Self {
+        self.dimming(true)
+    }
+
+    /// Used to set the terminal's dimming to specifically **false**
+    #[must_use]
+    pub const fn not_dimmed(self) -> Self {
+        self.dimming(false)
+    }
+
+    /// Used to set the terminal's blinking to a user-defined value.
+    #[must_use]
+    pub const fn with_blink(mut self, blink: bool) -> Self {
+        self.blink = Some(blink);
+
+        self
+    }
+
+    /// Used to set the terminal's blinking to specifically **true**
+    #[must_use]
+    pub const fn blinking(self) -> Self {
+        self.with_blink(true)
+    }
+
+    /// Used to set the terminal's blinking to specifically **false**
+    #[must_use]
+    pub const fn not_blinking(self) -> Self {
+        self.with_blink(false)
+    }
+
+    /// Used to set the terminal's concealment to a user-defined value.
+    ///
+    /// Refer to [`Style::hidden`] for an explanation on concealment.
+    #[must_use]
+    pub const fn concealment(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+
+        self
+    }
+
+    /// Used to set the terminal's concealment to specifically **true**, hiding the character.
+    #[must_use]
+    pub const fn hidden(self) -> Self {
+        self.concealment(true)
+    }
+
+    /// Used to set the terminal's concealment to specifically **false**, showing the character.
+    #[must_use]
+    pub const fn visible(self) -> Self {
+        self.concealment(false)
+    }
+
+    /// Used to set the terminal's overline to a user-defined value.
+    #[must_use]
+    pub const fn with_overline(mut self, overline: bool) -> Self {
+        self.overline = Some(overline);
+
+        self
+    }
+
+    /// Used to set the terminal's overline to specifically **true**
+    #[must_use]
+    pub const fn overlined(self) -> Self {
+        self.with_overline(true)
+    }
+
+    /// Used to set the terminal's overline to specifically **false**
+    #[must_use]
+    pub const fn not_overlined(self) -> Self {
+        self.with_overline(false)
+    }
+
+    /// Used to underline the cell using the specified [`Underline`] shape.
+    ///
+    /// ```
+    /// use tuit::style::{Colour, Style, Underline};
+    ///
+    /// let misspelled = Style::new()
+    ///     .underline_variant(Underline::Curly)
+    ///     .underline_colour_of(Colour::Rgb24(255, 0, 0));
+    ///
+    /// assert_eq!(misspelled.underline, Some(true));
+    /// assert_eq!(misspelled.underline_variant, Some(Underline::Curly));
+    /// ```
+    #[must_use]
+    pub const fn underline_variant(mut self, variant: Underline) -> Self {
+        self.underline_variant = Some(variant);
+
+        self.underlined()
+    }
+
+    /// Used to set the colour of the cell's underline.
+    #[must_use]
+    pub const fn underline_colour_of(mut self, colour: Colour) -> Self {
+        self.underline_colour = Some(colour);
+
+        self
+    }
+
     /// Will replace all `None` properties in a style with defined properties from the right-hand style.
     ///
     /// ```
@@ -482,6 +808,40 @@ impl Style {
             invert: or!(self.invert, fallback.invert),
             strikethrough: or!(self.strikethrough, fallback.strikethrough),
             italic: or!(self.italic, fallback.italic),
+            dim: or!(self.dim, fallback.dim),
+            blink: or!(self.blink, fallback.blink),
+            hidden: or!(self.hidden, fallback.hidden),
+            overline: or!(self.overline, fallback.overline),
+            underline_variant: or!(self.underline_variant, fallback.underline_variant),
+            underline_colour: or!(self.underline_colour, fallback.underline_colour),
+        }
+    }
+
+    /// Convert the style's colours into the closest ones that can be displayed at the specified
+    /// [`ColourDepth`]. See [`Colour::downsample`].
+    ///
+    /// ```
+    /// use tuit::style::{Ansi4, ColourDepth, Style};
+    ///
+    /// let style = Style::new().fg_rgb24(250, 10, 10).bg_luma8(0).bold();
+    ///
+    /// assert_eq!(style.downsampled(ColourDepth::Ansi16), Style::new().fg_ansi4(Ansi4::BrightRed).bg_ansi4(Ansi4::Black).bold());
+    /// assert_eq!(style.downsampled(ColourDepth::NoColour), Style::new().bold());
+    /// ```
+    #[must_use]
+    pub const fn downsampled(mut self, depth: ColourDepth) -> Self {
+        if let Some(fg_colour) = self.fg_colour {
+            self.fg_colour = fg_colour.downsample(depth);
         }
+
+        if let Some(bg_colour) = self.bg_colour {
+            self.bg_colour = bg_colour.downsample(depth);
+        }
+
+        if let Some(underline_colour) = self.underline_colour {
+            self.underline_colour = underline_colour.downsample(depth);
+        }
+
+        self
     }
-}
\ No newline at end of file
+}
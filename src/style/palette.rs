@@ -0,0 +1,92 @@
+use crate::style::Colour;
+
+/// The actual colours that a terminal displays for its ANSI colours and its default colours.
+///
+/// Terminals (and their themes) choose their own colours for [`Colour::Ansi16`], [`Colour::Ansi256`]
+/// and [`Colour::TerminalDefault`]. Renderers that don't draw to a real terminal, like the
+/// [`HtmlRenderer`](crate::draw::HtmlRenderer), use a [`Palette`] to decide what they look like.
+///
+/// ```
+/// use tuit::style::{Ansi4, Colour, Palette};
+///
+/// // The Solarized Dark colours, for example.
+/// let solarized = Palette::from_ansi16(
+///     [
+///         (7, 54, 66), (220, 50, 47), (133, 153, 0), (181, 137, 0),
+///         (38, 139, 210), (211, 54, 130), (42, 161, 152), (238, 232, 213),
+///         (0, 43, 54), (203, 75, 22), (88, 110, 117), (101, 123, 131),
+///         (131, 148, 150), (108, 113, 196), (147, 161, 161), (253, 246, 227),
+///     ],
+///     (131, 148, 150),
+///     (0, 43, 54),
+/// );
+///
+/// assert_eq!(solarized.rgb(Colour::Ansi16(Ansi4::Red)), Some((220, 50, 47)));
+/// assert_eq!(solarized.rgb(Colour::Ansi256(1)), Some((220, 50, 47)));
+/// assert_eq!(solarized.rgb(Colour::Ansi256(208)), Palette::XTERM.rgb(Colour::Ansi256(208)));
+/// assert_eq!(solarized.rgb(Colour::TerminalDefault), None);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Palette {
+    /// The default foreground (text) colour.
+    pub foreground: (u8, u8, u8),
+    /// The default background colour.
+    pub background: (u8, u8, u8),
+    /// The 256 ANSI colours. The first 16 are also used for [`Colour::Ansi16`].
+    pub ansi: [(u8, u8, u8); 256],
+}
+
+impl Palette {
+    /// xterm's default colours: light gray text on a black background. These are the same colours
+    /// that [`Colour::to_rgb`] uses.
+    pub const XTERM: Self = Self::from_ansi16(Self::xterm_ansi16(), (229, 229, 229), (0, 0, 0));
+
+    /// Create a palette out of the 16 ANSI colours and the default foreground and background colours.
+    /// The rest of the 256 ANSI colours (the colour cube and the grayscale ramp) are the usual ones.
+    #[must_use]
+    pub const fn from_ansi16(ansi16: [(u8, u8, u8); 16], foreground: (u8, u8, u8), background: (u8, u8, u8)) -> Self {
+        let mut ansi = [(0, 0, 0); 256];
+        let mut idx = 0;
+
+        while idx < ansi.len() {
+            ansi[idx] = if idx < ansi16.len() {
+                ansi16[idx]
+            } else {
+                Colour::ansi256_rgb(idx as u8)
+            };
+
+            idx += 1;
+        }
+
+        Self { foreground, background, ansi }
+    }
+
+    /// The colour that the palette displays for `colour`. Returns `None` for [`Colour::TerminalDefault`],
+    /// since it means either [`Palette::foreground`] or [`Palette::background`].
+    #[must_use]
+    pub const fn rgb(&self, colour: Colour) -> Option<(u8, u8, u8)> {
+        match colour {
+            Colour::Ansi16(colour) => Some(self.ansi[colour as usize]),
+            Colour::Ansi256(index) => Some(self.ansi[index as usize]),
+            colour => colour.to_rgb(),
+        }
+    }
+
+    const fn xterm_ansi16() -> [(u8, u8, u8); 16] {
+        let mut ansi16 = [(0, 0, 0); 16];
+        let mut idx = 0;
+
+        while idx < ansi16.len() {
+            ansi16[idx] = Colour::ansi256_rgb(idx as u8);
+            idx += 1;
+        }
+
+        ansi16
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::XTERM
+    }
+}
@@ -0,0 +1,613 @@
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::style::{Ansi4, Colour, Style, Underline};
+
+/// What went wrong while parsing a [`Style`] or a [`Colour`]. See [`ParseError`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Error)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A value was expected, such as the colour after `on` or `fg=`.
+    #[error("expected a value")]
+    MissingValue,
+    /// A word that isn't a known colour name or colour format.
+    #[error("unknown colour")]
+    UnknownColour,
+    /// A word that is neither an attribute (like `bold`) nor a colour.
+    #[error("unknown attribute or colour")]
+    UnknownAttribute,
+    /// A `key=value` pair with an unknown key.
+    #[error("unknown key")]
+    UnknownKey,
+    /// An underline style other than `single`, `double`, `curly`, `dotted` or `dashed`.
+    #[error("unknown underline style")]
+    UnknownUnderline,
+    /// A hex colour that isn't in the `#rgb` or `#rrggbb` format.
+    #[error("invalid hex colour")]
+    InvalidHex,
+    /// A number that isn't a decimal integer, or that is out of range.
+    #[error("invalid number")]
+    InvalidNumber,
+}
+
+/// The error returned when a [`Style`] or a [`Colour`] can't be parsed.
+///
+/// It points at the part of the text that couldn't be parsed, so that it can be highlighted.
+///
+/// ```
+/// use tuit::style::{ParseErrorKind, Style};
+///
+/// let text = "bold fg=purpel";
+/// let error = Style::parse(text).expect_err("`purpel` is not a colour");
+///
+/// assert_eq!(error.kind, ParseErrorKind::UnknownColour);
+/// assert_eq!(&text[error.position..error.position + error.length], "purpel");
+/// assert_eq!(error.to_string(), "unknown colour at bytes 8..14");
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Error)]
+#[error("{kind} at bytes {}..{}", .position, .position + .length)]
+pub struct ParseError {
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+    /// The byte offset in the text where the problem starts.
+    pub position: usize,
+    /// The length of the problem in bytes. This is 0 when something is missing.
+    pub length: usize,
+}
+
+impl ParseError {
+    const fn new(kind: ParseErrorKind, position: usize, length: usize) -> Self {
+        Self { kind, position, length }
+    }
+}
+
+/// The names of the colours. The 16 ANSI colours come first, in the order of [`Ansi4`], followed
+/// by a handful of CSS colours.
+const COLOUR_NAMES: [(&str, Colour); 36] = [
+    ("black", Colour::Ansi16(Ansi4::Black)),
+    ("red", Colour::Ansi16(Ansi4::Red)),
+    ("green", Colour::Ansi16(Ansi4::Green)),
+    ("yellow", Colour::Ansi16(Ansi4::Yellow)),
+    ("blue", Colour::Ansi16(Ansi4::Blue)),
+    ("magenta", Colour::Ansi16(Ansi4::Magenta)),
+    ("cyan", Colour::Ansi16(Ansi4::Cyan)),
+    ("white", Colour::Ansi16(Ansi4::White)),
+    ("bright-black", Colour::Ansi16(Ansi4::BrightBlack)),
+    ("bright-red", Colour::Ansi16(Ansi4::BrightRed)),
+    ("bright-green", Colour::Ansi16(Ansi4::BrightGreen)),
+    ("bright-yellow", Colour::Ansi16(Ansi4::BrightYellow)),
+    ("bright-blue", Colour::Ansi16(Ansi4::BrightBlue)),
+    ("bright-magenta", Colour::Ansi16(Ansi4::BrightMagenta)),
+    ("bright-cyan", Colour::Ansi16(Ansi4::BrightCyan)),
+    ("bright-white", Colour::Ansi16(Ansi4::BrightWhite)),
+    ("aqua", Colour::Rgb24(0, 255, 255)),
+    ("beige", Colour::Rgb24(245, 245, 220)),
+    ("brown", Colour::Rgb24(165, 42, 42)),
+    ("coral", Colour::Rgb24(255, 127, 80)),
+    ("crimson", Colour::Rgb24(220, 20, 60)),
+    ("fuchsia", Colour::Rgb24(255, 0, 255)),
+    ("gold", Colour::Rgb24(255, 215, 0)),
+    ("gray", Colour::Rgb24(128, 128, 128)),
+    ("grey", Colour::Rgb24(128, 128, 128)),
+    ("indigo", Colour::Rgb24(75, 0, 130)),
+    ("lime", Colour::Rgb24(0, 255, 0)),
+    ("maroon", Colour::Rgb24(128, 0, 0)),
+    ("navy", Colour::Rgb24(0, 0, 128)),
+    ("olive", Colour::Rgb24(128, 128, 0)),
+    ("orange", Colour::Rgb24(255, 165, 0)),
+    ("pink", Colour::Rgb24(255, 192, 203)),
+    ("purple", Colour::Rgb24(128, 0, 128)),
+    ("silver", Colour::Rgb24(192, 192, 192)),
+    ("teal", Colour::Rgb24(0, 128, 128)),
+    ("violet", Colour::Rgb24(238, 130, 238)),
+];
+
+/// The names of the underline styles, in the order of [`Underline`].
+const UNDERLINE_NAMES: [(&str, Underline); 5] = [
+    ("single", Underline::Single),
+    ("double", Underline::Double),
+    ("curly", Underline::Curly),
+    ("dotted", Underline::Dotted),
+    ("dashed", Underline::Dashed),
+];
+
+impl Colour {
+    /// Parse a colour from text. This is also available through [`str::parse`].
+    ///
+    /// The following forms are accepted, ignoring case:
+    /// - The names of the 16 ANSI colours, like `red` or `bright-cyan` (`bright_cyan` works too).
+    /// - A handful of CSS colour names, like `orange`, `purple` or `grey`, as 24-bit colours.
+    /// - `#rrggbb` and `#rgb` for 24-bit colours.
+    /// - `ansi:N` for the 16 ANSI colours by number (0 to 15), and `ansi256:N` for the 256 ANSI colours.
+    /// - `luma:N` for the grayscale colours.
+    /// - `default` for the terminal's default colour.
+    ///
+    /// Displaying a colour gives text that parses back into the same colour.
+    ///
+    /// ```
+    /// use tuit::style::{Ansi4, Colour};
+    ///
+    /// const ORANGE: Colour = match Colour::parse("#ff8800") {
+    ///     Ok(colour) => colour,
+    ///     Err(_) => panic!("Invalid colour"),
+    /// };
+    ///
+    /// assert_eq!(ORANGE, Colour::Rgb24(255, 136, 0));
+    /// assert_eq!("Bright-Cyan".parse(), Ok(Colour::Ansi16(Ansi4::BrightCyan)));
+    /// assert_eq!("ansi:4".parse(), Ok(Colour::Ansi16(Ansi4::Blue)));
+    /// assert_eq!("#fff".parse(), Ok(Colour::Rgb24(255, 255, 255)));
+    ///
+    /// for colour in [ORANGE, Colour::Luma8(40), Colour::Ansi256(200), Colour::Ansi16(Ansi4::Blue)] {
+    ///     assert_eq!(colour.to_string().parse(), Ok(colour));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] pointing at the part of the text that isn't a colour.
+    pub const fn parse(text: &str) -> Result<Self, ParseError> {
+        parse_colour(text.as_bytes(), 0)
+    }
+}
+
+impl FromStr for Colour {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl Display for Colour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Rgb24(red, green, blue) => write!(f, "#{red:02x}{green:02x}{blue:02x}"),
+            Self::Luma8(luma) => write!(f, "luma:{luma}"),
+            Self::Ansi16(colour) => write!(f, "{}", COLOUR_NAMES[colour as usize].0),
+            Self::Ansi256(colour) => write!(f, "ansi256:{colour}"),
+            Self::TerminalDefault => write!(f, "default"),
+        }
+    }
+}
+
+impl Style {
+    /// Parse a style from text. This is also available through [`str::parse`].
+    ///
+    /// The text is a list of words separated by whitespace. Later words override earlier ones.
+    /// - Attributes: `bold`, `regular`, `thin`, `italic`, `upright`, `underline`, `strikethrough`,
+    ///   `invert`, `dim`, `blink`, `hidden`, `visible` and `overline`. `italic`, `underline`,
+    ///   `strikethrough`, `invert`, `dim`, `blink`, `hidden` and `overline` can also be turned off
+    ///   explicitly with a `no-` prefix, like `no-underline`.
+    /// - Underline styles: `single-underline`, `double-underline`, `curly-underline`,
+    ///   `dotted-underline` and `dashed-underline`.
+    /// - Colours (see [`Colour::parse`]): a colour on its own sets the foreground, and `on`
+    ///   followed by a colour sets the background.
+    /// - Keys: `fg=`, `bg=` and `underline-colour=` take a colour, `weight=` takes a font weight,
+    ///   and `underline-style=` takes an underline style (like `curly`).
+    ///
+    /// Displaying a style gives text that parses back into the same style.
+    ///
+    /// ```
+    /// use tuit::style::{Ansi4, Colour, Style, Underline};
+    ///
+    /// let style: Style = "bold italic #ff8800 on ansi:4".parse().expect("Valid style");
+    ///
+    /// assert_eq!(style, Style::new().bold().italic().fg_rgb24(255, 136, 0).bg_ansi4(Ansi4::Blue));
+    ///
+    /// let style: Style = "fg=bright-cyan bg=luma:40 underline".parse().expect("Valid style");
+    ///
+    /// assert_eq!(style, Style::new().fg_ansi4(Ansi4::BrightCyan).bg_luma8(40).underlined());
+    ///
+    /// // Styles round-trip through `Display`.
+    /// let style = Style::new()
+    ///     .thin()
+    ///     .not_dimmed()
+    ///     .underline_variant(Underline::Curly)
+    ///     .underline_colour_of(Colour::Rgb24(255, 0, 0))
+    ///     .bg_default();
+    ///
+    /// assert_eq!(style.to_string(), "thin curly-underline no-dim bg=default underline-colour=#ff0000");
+    /// assert_eq!(style.to_string().parse(), Ok(style));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] pointing at the first word (or part of a word) that couldn't be parsed.
+    pub const fn parse(text: &str) -> Result<Self, ParseError> {
+        let bytes = text.as_bytes();
+        let mut style = Self::new();
+        let mut position = 0;
+        let mut background_next = false;
+
+        loop {
+            while position < bytes.len() && bytes[position].is_ascii_whitespace() {
+                position += 1;
+            }
+
+            if position == bytes.len() {
+                break;
+            }
+
+            let start = position;
+
+            while position < bytes.len() && !bytes[position].is_ascii_whitespace() {
+                position += 1;
+            }
+
+            let word = bytes.split_at(position).0.split_at(start).1;
+
+            if background_next {
+                match parse_colour(word, start) {
+                    Ok(colour) => style.bg_colour = Some(colour),
+                    Err(error) => return Err(error),
+                }
+
+                background_next = false;
+            } else if eq_ignore_case(word, b"on") {
+                background_next = true;
+            } else {
+                match apply_word(style, word, start) {
+                    Ok(new_style) => style = new_style,
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        if background_next {
+            return Err(ParseError::new(ParseErrorKind::MissingValue, bytes.len(), 0));
+        }
+
+        Ok(style)
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut first = true;
+        let mut word = |f: &mut Formatter<'_>, arguments: core::fmt::Arguments<'_>| {
+            if !first {
+                f.write_str(" ")?;
+            }
+
+            first = false;
+            f.write_fmt(arguments)
+        };
+
+        match self.font_weight {
+            Some(700) => word(f, format_args!("bold"))?,
+            Some(400) => word(f, format_args!("regular"))?,
+            Some(200) => word(f, format_args!("thin"))?,
+            Some(weight) => word(f, format_args!("weight={weight}"))?,
+            None => {}
+        }
+
+        if let Some(italic) = self.italic {
+            word(f, format_args!("{}", if italic { "italic" } else { "upright" }))?;
+        }
+
+        match (self.underline, self.underline_variant) {
+            (Some(true), Some(variant)) => word(f, format_args!("{}-underline", UNDERLINE_NAMES[variant as usize].0))?,
+            (underline, variant) => {
+                if let Some(underline) = underline {
+                    word(f, format_args!("{}underline", if underline { "" } else { "no-" }))?;
+                }
+
+                if let Some(variant) = variant {
+                    word(f, format_args!("underline-style={}", UNDERLINE_NAMES[variant as usize].0))?;
+                }
+            }
+        }
+
+        let toggles = [
+            (self.strikethrough, "strikethrough", "no-strikethrough"),
+            (self.invert, "invert", "no-invert"),
+            (self.dim, "dim", "no-dim"),
+            (self.blink, "blink", "no-blink"),
+            (self.hidden, "hidden", "visible"),
+            (self.overline, "overline", "no-overline"),
+        ];
+
+        for (value, on, off) in toggles {
+            if let Some(value) = value {
+                word(f, format_args!("{}", if value { on } else { off }))?;
+            }
+        }
+
+        let colours = [
+            (self.fg_colour, "fg"),
+            (self.bg_colour, "bg"),
+            (self.underline_colour, "underline-colour"),
+        ];
+
+        for (colour, key) in colours {
+            if let Some(colour) = colour {
+                word(f, format_args!("{key}={colour}"))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Apply a single word (that isn't `on` or the colour after it) of a style to `style`.
+const fn apply_word(mut style: Style, word: &[u8], position: usize) -> Result<Style, ParseError> {
+    let mut equals = 0;
+
+    while equals < word.len() && word[equals] != b'=' {
+        equals += 1;
+    }
+
+    if equals < word.len() {
+        let (key, value) = word.split_at(equals);
+        let (_, value) = value.split_at(1);
+        let value_position = position + equals + 1;
+
+        if eq_ignore_case(key, b"weight") {
+            return match parse_number(value, u16::MAX as u32) {
+                Some(weight) => Ok(style.font_weight(weight as u16)),
+                None => Err(ParseError::new(ParseErrorKind::InvalidNumber, value_position, value.len())),
+            };
+        }
+
+        if eq_ignore_case(key, b"underline-style") {
+            let mut idx = 0;
+
+            while idx < UNDERLINE_NAMES.len() {
+                if eq_ignore_case(value, UNDERLINE_NAMES[idx].0.as_bytes()) {
+                    style.underline_variant = Some(UNDERLINE_NAMES[idx].1);
+
+                    return Ok(style);
+                }
+
+                idx += 1;
+            }
+
+            return Err(ParseError::new(ParseErrorKind::UnknownUnderline, value_position, value.len()));
+        }
+
+        let colour = match parse_colour(value, value_position) {
+            Ok(colour) => Some(colour),
+            Err(error) => return Err(error),
+        };
+
+        if eq_ignore_case(key, b"fg") {
+            style.fg_colour = colour;
+        } else if eq_ignore_case(key, b"bg") {
+            style.bg_colour = colour;
+        } else if eq_ignore_case(key, b"underline-colour") || eq_ignore_case(key, b"underline-color") {
+            style.underline_colour = colour;
+        } else {
+            return Err(ParseError::new(ParseErrorKind::UnknownKey, position, key.len()));
+        }
+
+        return Ok(style);
+    }
+
+    // Underline styles, like `curly-underline`.
+    let mut idx = 0;
+
+    while idx < UNDERLINE_NAMES.len() {
+        let (name, variant) = UNDERLINE_NAMES[idx];
+
+        if let Some(rest) = strip_prefix(word, name.as_bytes()) {
+            if eq_ignore_case(rest, b"-underline") {
+                return Ok(style.underline_variant(variant));
+            }
+        }
+
+        idx += 1;
+    }
+
+    let (negated, attribute) = match strip_prefix(word, b"no-") {
+        Some(attribute) => (true, attribute),
+        None => (false, word),
+    };
+    let value = Some(!negated);
+
+    if !negated && eq_ignore_case(attribute, b"bold") {
+        style.font_weight = Some(700);
+    } else if !negated && eq_ignore_case(attribute, b"regular") {
+        style.font_weight = Some(400);
+    } else if !negated && eq_ignore_case(attribute, b"thin") {
+        style.font_weight = Some(200);
+    } else if eq_ignore_case(attribute, b"italic") {
+        style.italic = value;
+    } else if !negated && eq_ignore_case(attribute, b"upright") {
+        style.italic = Some(false);
+    } else if eq_ignore_case(attribute, b"underline") {
+        style.underline = value;
+    } else if eq_ignore_case(attribute, b"strikethrough") {
+        style.strikethrough = value;
+    } else if eq_ignore_case(attribute, b"invert") {
+        style.invert = value;
+    } else if eq_ignore_case(attribute, b"dim") {
+        style.dim = value;
+    } else if eq_ignore_case(attribute, b"blink") {
+        style.blink = value;
+    } else if eq_ignore_case(attribute, b"hidden") {
+        style.hidden = value;
+    } else if !negated && eq_ignore_case(attribute, b"visible") {
+        style.hidden = Some(false);
+    } else if eq_ignore_case(attribute, b"overline") {
+        style.overline = value;
+    } else {
+        // Anything else should be a foreground colour.
+        match parse_colour(word, position) {
+            Ok(colour) => style.fg_colour = Some(colour),
+            Err(ParseError { kind: ParseErrorKind::UnknownColour, .. }) => {
+                return Err(ParseError::new(ParseErrorKind::UnknownAttribute, position, word.len()));
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(style)
+}
+
+/// Parse a colour from `text`, which starts at byte `position` of the text being parsed.
+const fn parse_colour(text: &[u8], position: usize) -> Result<Colour, ParseError> {
+    if text.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::MissingValue, position, 0));
+    }
+
+    if let Some(hex) = strip_prefix(text, b"#") {
+        return match parse_hex(hex) {
+            Some(colour) => Ok(colour),
+            None => Err(ParseError::new(ParseErrorKind::InvalidHex, position, text.len())),
+        };
+    }
+
+    let numbered: [(&[u8], u32); 3] = [(b"ansi:", 15), (b"ansi256:", 255), (b"luma:", 255)];
+    let mut idx = 0;
+
+    while idx < numbered.len() {
+        let (prefix, max) = numbered[idx];
+
+        if let Some(digits) = strip_prefix(text, prefix) {
+            let Some(number) = parse_number(digits, max) else {
+                return Err(ParseError::new(ParseErrorKind::InvalidNumber, position + prefix.len(), digits.len()));
+            };
+
+            let number = number as u8;
+
+            return Ok(match idx {
+                0 => COLOUR_NAMES[number as usize].1,
+                1 => Colour::Ansi256(number),
+                _ => Colour::Luma8(number),
+            });
+        }
+
+        idx += 1;
+    }
+
+    if eq_ignore_case(text, b"default") {
+        return Ok(Colour::TerminalDefault);
+    }
+
+    let mut idx = 0;
+
+    while idx < COLOUR_NAMES.len() {
+        if eq_ignore_case(text, COLOUR_NAMES[idx].0.as_bytes()) {
+            return Ok(COLOUR_NAMES[idx].1);
+        }
+
+        idx += 1;
+    }
+
+    Err(ParseError::new(ParseErrorKind::UnknownColour, position, text.len()))
+}
+
+/// Parse the digits of a `#rgb` or `#rrggbb` colour.
+const fn parse_hex(digits: &[u8]) -> Option<Colour> {
+    let mut channels = [0_u8; 3];
+    let mut idx = 0;
+
+    while idx < 3 {
+        let channel = match digits.len() {
+            3 => match hex_digit(digits[idx]) {
+                Some(digit) => Some(digit * 17),
+                None => None,
+            },
+            6 => match (hex_digit(digits[idx * 2]), hex_digit(digits[idx * 2 + 1])) {
+                (Some(high), Some(low)) => Some(high * 16 + low),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let Some(channel) = channel else {
+            return None;
+        };
+
+        channels[idx] = channel;
+        idx += 1;
+    }
+
+    Some(Colour::Rgb24(channels[0], channels[1], channels[2]))
+}
+
+const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parse a decimal number that is at most `max`.
+const fn parse_number(digits: &[u8], max: u32) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut number: u32 = 0;
+    let mut idx = 0;
+
+    while idx < digits.len() {
+        if !digits[idx].is_ascii_digit() {
+            return None;
+        }
+
+        number = number * 10 + (digits[idx] - b'0') as u32;
+
+        if number > max {
+            return None;
+        }
+
+        idx += 1;
+    }
+
+    Some(number)
+}
+
+/// Remove `prefix` from the start of `text`, ignoring case.
+const fn strip_prefix<'a>(text: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if text.len() < prefix.len() {
+        return None;
+    }
+
+    let (start, rest) = text.split_at(prefix.len());
+
+    if eq_ignore_case(start, prefix) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/// Compare two words, ignoring case and treating `_` the same as `-`.
+const fn eq_ignore_case(lhs: &[u8], rhs: &[u8]) -> bool {
+    const fn normalise(byte: u8) -> u8 {
+        match byte.to_ascii_lowercase() {
+            b'_' => b'-',
+            byte => byte,
+        }
+    }
+
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+
+    while idx < lhs.len() {
+        if normalise(lhs[idx]) != normalise(rhs[idx]) {
+            return false;
+        }
+
+        idx += 1;
+    }
+
+    true
+}
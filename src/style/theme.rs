@@ -0,0 +1,129 @@
+use crate::style::{Ansi4, Style};
+
+/// A set of named [`Style`]s ("roles") that the builtin widgets draw with by default.
+///
+/// Every terminal has a theme (see [`Metadata::theme`](crate::terminal::Metadata::theme)), which is
+/// [`Theme::DEFAULT`] unless it's been changed by wrapping a widget in a
+/// [`Themed`](crate::widgets::builtins::Themed). Views into the terminal share its theme, so the
+/// theme is passed down through every layout container.
+///
+/// The style fields of the builtin widgets (like [`Buttons::selected_button_style`](crate::widgets::builtins::Buttons::selected_button_style))
+/// are overrides: whatever they leave unset is taken from the matching role of the theme.
+///
+/// ```
+/// use tuit::style::{Ansi4, Style, Theme};
+///
+/// // Only change what you need; the rest comes from the default theme.
+/// let ocean = Theme {
+///     primary: Style::new().fg_ansi4(Ansi4::BrightWhite).bg_ansi4(Ansi4::Blue),
+///     selected: Style::new().fg_ansi4(Ansi4::Black).bg_ansi4(Ansi4::Cyan),
+///     ..Theme::DEFAULT
+/// };
+///
+/// assert_eq!(ocean.disabled, Theme::DEFAULT.disabled);
+/// ```
+///
+/// With the `serde` feature, themes can be loaded from files. Roles that are left out come from
+/// [`Theme::DEFAULT`].
+///
+/// ```
+/// use tuit::style::{Ansi4, Style, Theme};
+///
+/// let theme: Theme = serde_json::from_str(r#"{ "error": { "fg_colour": { "Ansi16": "Magenta" } } }"#).expect("Valid theme");
+///
+/// assert_eq!(theme.error, Style::new().fg_ansi4(Ansi4::Magenta));
+/// assert_eq!(theme.primary, Theme::DEFAULT.primary);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))] // Missing roles come from `Theme::DEFAULT`.
+pub struct Theme {
+    /// Ordinary text, such as the contents of a [`Text`](crate::widgets::builtins::Text).
+    pub text: Style,
+    /// Prominent surfaces, like the bar of a [`MenuBar`](crate::widgets::builtins::MenuBar).
+    pub primary: Style,
+    /// Decorations that should stand out from the text, like checkboxes, spinners and banners.
+    pub accent: Style,
+    /// Titles and headings.
+    pub title: Style,
+    /// Frames and box-drawing characters.
+    pub border: Style,
+    /// The selected or hovered item, and cursors.
+    pub selected: Style,
+    /// Items that can't be interacted with.
+    pub disabled: Style,
+    /// Secondary information, like line numbers and offsets.
+    pub muted: Style,
+    /// Whatever is dimmed behind a modal overlay, like a [`Dialog`](crate::widgets::builtins::Dialog).
+    pub overlay: Style,
+    /// Informational messages.
+    pub info: Style,
+    /// Messages about something that went well.
+    pub success: Style,
+    /// Warnings.
+    pub warning: Style,
+    /// Errors.
+    pub error: Style,
+    /// Informational notifications, like [`Toasts`](crate::widgets::builtins::Toasts) of
+    /// [`Severity::Info`](crate::widgets::builtins::toasts::Severity::Info). They are filled with a colour,
+    /// so they set a background as well as a foreground.
+    pub info_toast: Style,
+    /// Notifications about something that went well.
+    pub success_toast: Style,
+    /// Notifications that warn about something.
+    pub warning_toast: Style,
+    /// Notifications about errors.
+    pub error_toast: Style,
+}
+
+impl Theme {
+    /// The theme that Tuit uses unless you choose another one. It only uses the 16 ANSI colours,
+    /// so that it follows the colour scheme of the terminal.
+    pub const DEFAULT: Self = Self {
+        text: Style::new(),
+        primary: Style::new().fg_ansi4(Ansi4::Black).bg_ansi4(Ansi4::White),
+        accent: Style::new(),
+        title: Style::new().bold(),
+        border: Style::new(),
+        selected: Style::new().inverted(),
+        disabled: Style::new().fg_ansi4(Ansi4::BrightBlack),
+        muted: Style::new().fg_ansi4(Ansi4::BrightBlack),
+        overlay: Style::new().fg_ansi4(Ansi4::BrightBlack).bg_ansi4(Ansi4::Black),
+        info: Style::new().fg_ansi4(Ansi4::Blue),
+        success: Style::new().fg_ansi4(Ansi4::Green),
+        warning: Style::new().fg_ansi4(Ansi4::Yellow),
+        error: Style::new().fg_ansi4(Ansi4::Red).bold(),
+        info_toast: Style::new().fg_ansi4(Ansi4::BrightWhite).bg_ansi4(Ansi4::Blue),
+        success_toast: Style::new().fg_ansi4(Ansi4::Black).bg_ansi4(Ansi4::Green),
+        warning_toast: Style::new().fg_ansi4(Ansi4::Black).bg_ansi4(Ansi4::Yellow),
+        error_toast: Style::new().fg_ansi4(Ansi4::BrightWhite).bg_ansi4(Ansi4::Red).bold(),
+    };
+
+    /// A theme without any colours, for terminals that can't display them. Roles are told apart
+    /// using font weight, dimming, inversion and underlines instead.
+    pub const MONOCHROME: Self = Self {
+        text: Style::new(),
+        primary: Style::new().inverted(),
+        accent: Style::new().bold(),
+        title: Style::new().bold().underlined(),
+        border: Style::new(),
+        selected: Style::new().inverted(),
+        disabled: Style::new().dimmed(),
+        muted: Style::new().dimmed(),
+        overlay: Style::new().dimmed(),
+        info: Style::new(),
+        success: Style::new(),
+        warning: Style::new().bold(),
+        error: Style::new().bold().underlined(),
+        info_toast: Style::new().inverted(),
+        success_toast: Style::new().inverted(),
+        warning_toast: Style::new().inverted().bold(),
+        error_toast: Style::new().inverted().bold().underlined(),
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -4,7 +4,7 @@ use anstyle::AnsiColor as AnstyleAnsi;
 use anstyle::Color as AnstyleColour;
 use anstyle::Style as AnstyleStyle;
 
-use crate::style::{Ansi4, Colour as TuitColour};
+use crate::style::{Ansi4, Colour as TuitColour, Underline};
 use crate::style::Style as TuitStyle;
 use crate::style::Ansi4 as TuitAnsi4;
 
@@ -117,7 +117,16 @@ impl TryFrom<AnstyleStyle> for TuitStyle {
 
     fn try_from(value: AnstyleStyle) -> Result<Self, Self::Error> {
         let effects = value.get_effects();
-        let underline = effects.contains(anstyle::Effects::UNDERLINE);
+        let underline_variant = [
+            (anstyle::Effects::UNDERLINE, Underline::Single),
+            (anstyle::Effects::DOUBLE_UNDERLINE, Underline::Double),
+            (anstyle::Effects::CURLY_UNDERLINE, Underline::Curly),
+            (anstyle::Effects::DOTTED_UNDERLINE, Underline::Dotted),
+            (anstyle::Effects::DASHED_UNDERLINE, Underline::Dashed),
+        ]
+        .into_iter()
+        .find_map(|(effect, variant)| effects.contains(effect).then_some(variant));
+        let underline = underline_variant.is_some();
         let bold = effects.contains(anstyle::Effects::BOLD);
         let italic = effects.contains(anstyle::Effects::ITALIC);
         let strikethrough = effects.contains(anstyle::Effects::STRIKETHROUGH);
@@ -125,10 +134,14 @@ impl TryFrom<AnstyleStyle> for TuitStyle {
 
         let bg_colour = value.get_bg_color().map(TuitColour::from);
         let fg_colour = value.get_fg_color().map(TuitColour::from);
+        let underline_colour = value.get_underline_color().map(TuitColour::from);
 
-        // check if any Anstyle styling was lost.
-        let lossy = effects.contains(anstyle::Effects::CURLY_UNDERLINE | anstyle::Effects::DOTTED_UNDERLINE | anstyle::Effects::DASHED_UNDERLINE | anstyle::Effects::DOUBLE_UNDERLINE);
-        let lossy = lossy || effects.contains(anstyle::Effects::HIDDEN | anstyle::Effects::BLINK | anstyle::Effects::DIMMED);
+        // Multiple underline effects at once can't be represented.
+        let lossy = effects.contains(anstyle::Effects::UNDERLINE) && underline_variant != Some(Underline::Single)
+            || [anstyle::Effects::DOUBLE_UNDERLINE, anstyle::Effects::CURLY_UNDERLINE, anstyle::Effects::DOTTED_UNDERLINE, anstyle::Effects::DASHED_UNDERLINE]
+                .into_iter()
+                .filter(|&effect| effects.contains(effect))
+                .count() > 1;
 
         // Get the defined values for "boldness"
         let font_weight = if bold {
@@ -144,7 +157,13 @@ impl TryFrom<AnstyleStyle> for TuitStyle {
             underline: Some(underline),
             invert: Some(invert),
             strikethrough: Some(strikethrough),
-            italic: Some(italic)
+            italic: Some(italic),
+            dim: Some(effects.contains(anstyle::Effects::DIMMED)),
+            blink: Some(effects.contains(anstyle::Effects::BLINK)),
+            hidden: Some(effects.contains(anstyle::Effects::HIDDEN)),
+            overline: None,
+            underline_variant,
+            underline_colour,
         };
 
         if lossy {
@@ -164,7 +183,13 @@ impl From<TuitStyle> for AnstyleStyle {
             underline,
             invert,
             strikethrough,
-            italic
+            italic,
+            dim,
+            blink,
+            hidden,
+            overline: _,
+            underline_variant,
+            underline_colour,
         } = value;
 
         let mut output = AnstyleStyle::new();
@@ -177,8 +202,32 @@ impl From<TuitStyle> for AnstyleStyle {
             output = output.bg_color(bg_colour.try_into().ok());
         }
 
-        if let Some(true) = underline {
-            output = output.underline()
+        if underline == Some(true) {
+            let effect = match underline_variant.unwrap_or_default() {
+                Underline::Single => anstyle::Effects::UNDERLINE,
+                Underline::Double => anstyle::Effects::DOUBLE_UNDERLINE,
+                Underline::Curly => anstyle::Effects::CURLY_UNDERLINE,
+                Underline::Dotted => anstyle::Effects::DOTTED_UNDERLINE,
+                Underline::Dashed => anstyle::Effects::DASHED_UNDERLINE,
+            };
+
+            output = output.effects(output.get_effects() | effect);
+        }
+
+        if let Some(underline_colour) = underline_colour {
+            output = output.underline_color(underline_colour.try_into().ok());
+        }
+
+        if dim == Some(true) {
+            output = output.dimmed();
+        }
+
+        if blink == Some(true) {
+            output = output.blink();
+        }
+
+        if hidden == Some(true) {
+            output = output.hidden();
         }
 
         if let Some(true) = invert {
@@ -1,6 +1,8 @@
 use crate::prelude::*;
 use crate::style::Style;
 use crate::terminal::Cell;
+#[cfg(feature = "serde")]
+use crate::terminal::serde_impls::{deserialize_terminal, serialize_terminal, RowsInto};
 
 /// A zero-allocation terminal of constant size. The terminal's size is determined at compile time,
 /// and can't be changed at runtime.
@@ -91,3 +93,47 @@ impl<const WIDTH: usize, const HEIGHT: usize> TerminalMut for ConstantSize<WIDTH
         row.get_mut(x)
     }
 }
+
+/// The terminal is stored as its default style and its cells, as a list of rows. Deserializing
+/// fails unless there are exactly `HEIGHT` rows of `WIDTH` cells.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Style;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::Text;
+///
+/// let mut terminal: ConstantSize<12, 1> = ConstantSize::new();
+/// Text::new("Hello world!").styled(Style::new().bold()).drawn(&mut terminal).expect("There is enough space");
+///
+/// let snapshot = serde_json::to_string(&terminal).expect("Serializes");
+/// let restored: ConstantSize<12, 1> = serde_json::from_str(&snapshot).expect("Deserializes");
+///
+/// assert_eq!(restored, terminal);
+/// assert!(serde_json::from_str::<ConstantSize<10, 1>>(&snapshot).is_err());
+/// ```
+#[cfg(feature = "serde")]
+impl<const WIDTH: usize, const HEIGHT: usize> serde::Serialize for ConstantSize<WIDTH, HEIGHT> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_terminal(serializer, self.default_style, self.characters.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const WIDTH: usize, const HEIGHT: usize> serde::Deserialize<'de> for ConstantSize<WIDTH, HEIGHT> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let mut terminal = Self::new();
+        let (default_style, (width, height)) = deserialize_terminal(deserializer, RowsInto(&mut terminal.characters))?;
+
+        // A terminal without rows has no way of telling its width.
+        if (width, height) != (WIDTH, HEIGHT) && HEIGHT != 0 {
+            return Err(D::Error::custom(format_args!("expected a {WIDTH}x{HEIGHT} terminal, found a {width}x{height} one")));
+        }
+
+        terminal.default_style = default_style;
+
+        Ok(terminal)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::draw::Renderer;
 use crate::prelude::Metadata;
-use crate::style::Style;
+use crate::style::{Style, Theme};
 use crate::terminal::{Cell, Terminal, TerminalConst, TerminalMut};
 
 /// Print every step of the terminal's draw process out.
@@ -45,6 +45,10 @@ impl<T: Metadata, D> Metadata for Debug<T, D> {
     fn default_style(&self) -> Style {
         self.terminal.default_style()
     }
+
+    fn theme(&self) -> Theme {
+        self.terminal.theme()
+    }
 }
 
 impl<T: Terminal, D: Renderer> TerminalMut for Debug<T, D> {
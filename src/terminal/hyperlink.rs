@@ -0,0 +1,58 @@
+use core::fmt::{Display, Formatter};
+
+/// A hyperlink target that can be attached to a [`Cell`](crate::terminal::Cell).
+///
+/// Terminals that support OSC 8 make linked cells clickable when they're drawn by the
+/// [`AnsiRenderer`](crate::draw::AnsiRenderer) or the `StdoutRenderer`. The target is a borrowed `&'static str`, which keeps cells [`Copy`] and free of allocations.
+/// Neighbouring cells with the same target are rendered as one link.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Hyperlink, Rectangle};
+///
+/// let docs = Hyperlink::new("https://docs.rs/tuit");
+/// let mut terminal: ConstantSize<20, 1> = ConstantSize::new();
+///
+/// // Link a run of cells.
+/// let mut run = terminal.view_mut(Rectangle::of_size((4, 1))).expect("The run fits");
+///
+/// for cell in run.cells_mut() {
+///     cell.hyperlink = Some(docs);
+/// }
+///
+/// assert_eq!(terminal.cell(3, 0).expect("Cell exists").hyperlink, Some(docs));
+/// assert_eq!(terminal.cell(4, 0).expect("Cell exists").hyperlink, None);
+///
+/// // Displaying the hyperlink writes the sequence that opens it.
+/// assert_eq!(docs.to_string(), "\x1b]8;;https://docs.rs/tuit\x1b\\");
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Hyperlink {
+    /// The URI that the link points to, such as `https://example.com` or `file:///etc/hosts`.
+    pub uri: &'static str,
+}
+
+impl Hyperlink {
+    /// The OSC 8 sequence that ends a hyperlink.
+    pub const END: &'static str = "\x1b]8;;\x1b\\";
+
+    /// Create a new [`Hyperlink`] that points to the specified URI.
+    #[must_use]
+    pub const fn new(uri: &'static str) -> Self {
+        Self { uri }
+    }
+}
+
+/// Writes the OSC 8 sequence that starts the hyperlink. Control characters in the URI are left
+/// out, since they would end the sequence early.
+impl Display for Hyperlink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\x1b]8;;")?;
+
+        for character in self.uri.chars().filter(|character| !character.is_control()) {
+            write!(f, "{character}")?;
+        }
+
+        write!(f, "\x1b\\")
+    }
+}
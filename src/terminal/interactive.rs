@@ -5,7 +5,46 @@
 use core::time::Duration;
 use crate::terminal::Rectangle;
 
+pub mod keys {
+    //! Named USB HID usage IDs for the non-printable keys that are commonly sent through
+    //! [`UpdateInfo::KeyboardInput`](super::UpdateInfo::KeyboardInput).
+    //!
+    //! Go to [the UEFI specification](https://uefi.org/specs/UEFI/2.10/Apx_B_Console.html) for the full table.
+
+    /// The enter (or return) key.
+    pub const ENTER: u8 = 0x28;
+    /// The escape key.
+    pub const ESCAPE: u8 = 0x29;
+    /// The backspace key.
+    pub const BACKSPACE: u8 = 0x2A;
+    /// The tab key.
+    pub const TAB: u8 = 0x2B;
+    /// The spacebar.
+    pub const SPACE: u8 = 0x2C;
+    /// The insert key.
+    pub const INSERT: u8 = 0x49;
+    /// The home key.
+    pub const HOME: u8 = 0x4A;
+    /// The page up key.
+    pub const PAGE_UP: u8 = 0x4B;
+    /// The delete key.
+    pub const DELETE: u8 = 0x4C;
+    /// The end key.
+    pub const END: u8 = 0x4D;
+    /// The page down key.
+    pub const PAGE_DOWN: u8 = 0x4E;
+    /// The right arrow key.
+    pub const RIGHT_ARROW: u8 = 0x4F;
+    /// The left arrow key.
+    pub const LEFT_ARROW: u8 = 0x50;
+    /// The down arrow key.
+    pub const DOWN_ARROW: u8 = 0x51;
+    /// The up arrow key.
+    pub const UP_ARROW: u8 = 0x52;
+}
+
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// This enum represents the various buttons on the mouse.
 pub enum MouseButton {
     /// The primary mouse button, usually the left click button.
@@ -17,6 +56,7 @@ pub enum MouseButton {
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [`KeyState`] encompasses the current states that a keyboard key can be in (just pressed, currently held, and just released)
 pub enum KeyState {
     /// Key has just been released
@@ -28,6 +68,7 @@ pub enum KeyState {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// `UpdateInfo` encapsulates the information sent after an update
 pub enum UpdateInfo {
     /// This event triggers when a cell (character) gets clicked. It includes the X co-ordinate,
@@ -84,13 +125,18 @@ impl UpdateInfo {
 /// and thus are reliant on the implementor to get rid of them once their lifecycle is over.
 /// 
 /// In the case that you are handling multiple widgets under the same [`Widget::update`](crate::widgets::Widget::update) method,
-/// you can use ordering to determine which [`UpdateResult`] to return.
+/// you can use ordering to determine which [`UpdateResult`] to return. From lowest to highest, the order is
+/// [`UpdateResult::NoEvent`], [`UpdateResult::Interacted`], [`UpdateResult::Selected`] (ordered by its index),
+/// and [`UpdateResult::LifecycleEnd`].
 pub enum UpdateResult {
     /// No event has occurred, the object will continue to live.
-    NoEvent = 0,
+    NoEvent,
     /// The widget has been interacted with during the last update.
     /// (i.e. it has been clicked on, or a keystroke affected it).
-    Interacted = 1,
+    Interacted,
+    /// The user has made a choice within the widget, such as pressing a button in a dialog or
+    /// activating an item in a menu. It includes the index (or identifier) of the chosen item.
+    Selected(usize),
     /// The object's lifecycle has ended, and it should now be destructured.
-    LifecycleEnd = 2,
+    LifecycleEnd,
 }
@@ -3,6 +3,8 @@ use core::array;
 use crate::prelude::*;
 use crate::style::Style;
 use crate::terminal::{Cell, Metadata, Rescalable};
+#[cfg(feature = "serde")]
+use crate::terminal::serde_impls::{deserialize_terminal, serialize_terminal, RowsInto};
 
 /// A zero-allocation re-scalable terminal that allocates the maximum size that it can scale to.
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
@@ -108,3 +110,43 @@ for MaxSize<MAX_WIDTH, MAX_HEIGHT>
         row.get_mut(x)
     }
 }
+
+/// The terminal is stored as its default style and the cells within its current dimensions, as a
+/// list of rows. Deserializing restores the dimensions, and fails if they are larger than the maximum.
+/// A terminal without any rows has no width to measure, so it comes back 0 by 0.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{MaxSize, Rescalable};
+///
+/// let mut terminal: MaxSize<20, 20> = MaxSize::new();
+/// terminal.rescale((4, 2)).expect("The terminal can grow this large");
+///
+/// let snapshot = serde_json::to_string(&terminal).expect("Serializes");
+/// let restored: MaxSize<20, 20> = serde_json::from_str(&snapshot).expect("Deserializes");
+///
+/// assert_eq!(restored.dimensions(), (4, 2));
+/// assert!(serde_json::from_str::<MaxSize<3, 3>>(&snapshot).is_err());
+/// ```
+#[cfg(feature = "serde")]
+impl<const MAX_WIDTH: usize, const MAX_HEIGHT: usize> serde::Serialize for MaxSize<MAX_WIDTH, MAX_HEIGHT> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (width, height) = self.dimensions;
+        let rows = self.characters[..height].iter().map(|row| &row[..width]);
+
+        serialize_terminal(serializer, self.default_style, rows)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const MAX_WIDTH: usize, const MAX_HEIGHT: usize> serde::Deserialize<'de> for MaxSize<MAX_WIDTH, MAX_HEIGHT> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut terminal = Self::new();
+        let (default_style, dimensions) = deserialize_terminal(deserializer, RowsInto(&mut terminal.characters))?;
+
+        terminal.default_style = default_style;
+        terminal.dimensions = dimensions;
+
+        Ok(terminal)
+    }
+}
@@ -110,13 +110,15 @@ use core::ops::RangeInclusive;
 
 pub use const_size::ConstantSize;
 pub use const_size_ref::ConstantSizeRef;
+pub use hyperlink::Hyperlink;
 pub use interactive::*;
 pub use max_size::MaxSize;
 pub use view::View;
+pub use theme_override::ThemeOverride;
 pub use view_split::ViewSplit;
 
 use crate::prelude::*;
-use crate::style::Style;
+use crate::style::{Style, Theme};
 #[allow(unused_imports)] // used in docs.
 use crate::terminal;
 use crate::widgets::Direction;
@@ -137,6 +139,10 @@ pub mod max_size;
 pub mod dummy;
 /// The [`View`] terminal that can provide mutable or immutable views into terminals.
 pub mod view;
+/// The [`ThemeOverride`] terminal, which changes the [`Theme`] of another terminal.
+pub mod theme_override;
+/// The [`Hyperlink`] targets that can be attached to cells.
+pub mod hyperlink;
 /// The iterator used by the [`View`] terminal.
 pub mod view_iterator;
 /// The [`ViewSplit`] struct, which is used to split the terminal along its axes.
@@ -144,19 +150,30 @@ pub mod view_split;
 /// The [`Debug`] terminal, which prints out the terminal's state every time [`TerminalConst::cell`] is called or writes
 /// an [`Ansi4::Red`](crate::style::Ansi4) to the background of modified cells.
 pub mod debug;
+/// A VT100/xterm emulator, which parses escape sequences and applies them to a terminal.
+pub mod vt;
 
 // #[cfg(feature = "owo_colors")]
 // mod owo_colors;
+mod char_width;
 #[cfg(feature = "anstyle")]
 mod anstyle;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impls;
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// This struct represents a character in the terminal (as well as all the styling that it may have)
 pub struct Cell {
     /// The character inside the cell
     pub character: char,
     /// The character's styling.
     pub style: Style,
+    /// The hyperlink that the cell is part of, if any.
+    ///
+    /// Hyperlinks borrow a `&'static str`, so they are left out when cells are serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub hyperlink: Option<Hyperlink>,
 }
 
 impl Cell {
@@ -166,6 +183,32 @@ impl Cell {
         Self {
             character,
             style: Style::new(),
+            hyperlink: None,
+        }
+    }
+
+    /// The number of columns that the cell's character takes up: 2 for wide characters (like most
+    /// CJK characters and emoji), and 1 for everything else.
+    ///
+    /// A wide character covers the cell to its right too; renderers treat that cell as its continuation.
+    ///
+    /// ```
+    /// use tuit::terminal::Cell;
+    ///
+    /// assert_eq!(Cell::new('a').width(), 1);
+    /// assert_eq!(Cell::new('漢').width(), 2);
+    /// assert_eq!(Cell::new('🦀').width(), 2);
+    ///
+    /// // Hexagrams and chess symbols are narrow, even though their neighbours are wide.
+    /// assert_eq!(Cell::new('䷀').width(), 1);
+    /// assert_eq!(Cell::new('\u{1FA00}').width(), 1);
+    /// ```
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        if char_width::is_wide(self.character) {
+            2
+        } else {
+            1
         }
     }
 }
@@ -185,6 +228,14 @@ pub trait Metadata {
     /// Returns the Terminal's default style.
     fn default_style(&self) -> Style;
 
+    /// Returns the [`Theme`] that builtin widgets should draw with.
+    ///
+    /// Terminals use [`Theme::DEFAULT`] unless they override this. Terminals that wrap another
+    /// terminal (like [`View`]) should return the theme of the terminal that they wrap.
+    fn theme(&self) -> Theme {
+        Theme::DEFAULT
+    }
+
     /// Returns the terminal's width
     fn width(&self) -> usize {
         let (width, _) = self.dimensions();
@@ -344,6 +395,7 @@ pub trait Rescalable {
     Debug,
     Default
 )] // Ord and PartialOrd also implemented. Check default_impls.rs
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Provides the edge coordinates for a tuit [`Rectangle`].
 pub struct Rectangle {
     /// The top-left edge of the square.
@@ -0,0 +1,196 @@
+//! Helpers for implementing [`serde`] support for the contents of terminals.
+//!
+//! A terminal is stored as a struct with two fields: its `default_style`, and its `cells` as a
+//! list of rows. The terminals fill their cells in place while they're deserialized, so that no
+//! allocations are needed.
+
+use core::fmt::Formatter;
+
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::style::Style;
+use crate::terminal::Cell;
+
+/// The name of the struct that terminals are stored as.
+const NAME: &str = "Terminal";
+/// The fields of the struct that terminals are stored as.
+const FIELDS: &[&str] = &["default_style", "cells"];
+
+/// Serialize a terminal using its default style and its rows of cells.
+pub fn serialize_terminal<'a, S, I, R>(serializer: S, default_style: Style, rows: I) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    I: Iterator<Item = R> + Clone,
+    R: IntoIterator<Item = &'a Cell> + Clone,
+{
+    let mut terminal = serializer.serialize_struct(NAME, FIELDS.len())?;
+
+    terminal.serialize_field("default_style", &default_style)?;
+    terminal.serialize_field("cells", &Rows(rows))?;
+
+    terminal.end()
+}
+
+/// Deserialize a terminal, returning its default style and whatever `cells` makes of its rows of cells.
+///
+/// The default style may be left out, in which case it is [`Style::new`].
+pub fn deserialize_terminal<'de, D, S>(deserializer: D, cells: S) -> Result<(Style, S::Value), D::Error>
+where
+    D: Deserializer<'de>,
+    S: DeserializeSeed<'de>,
+{
+    deserializer.deserialize_struct(NAME, FIELDS, TerminalVisitor(cells))
+}
+
+/// Serializes rows of cells as a list of lists.
+struct Rows<I>(I);
+
+impl<'a, I, R> Serialize for Rows<I>
+where
+    I: Iterator<Item = R> + Clone,
+    R: IntoIterator<Item = &'a Cell> + Clone,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.clone().map(Row))
+    }
+}
+
+/// Serializes a single row of cells as a list.
+struct Row<R>(R);
+
+impl<'a, R: IntoIterator<Item = &'a Cell> + Clone> Serialize for Row<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.clone())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum Field {
+    DefaultStyle,
+    Cells,
+}
+
+struct TerminalVisitor<S>(S);
+
+impl<'de, S: DeserializeSeed<'de>> Visitor<'de> for TerminalVisitor<S> {
+    type Value = (Style, S::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a terminal")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let default_style = seq.next_element()?.ok_or_else(|| Error::invalid_length(0, &"a terminal"))?;
+        let cells = seq.next_element_seed(self.0)?.ok_or_else(|| Error::invalid_length(1, &"a terminal"))?;
+
+        Ok((default_style, cells))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut default_style = None;
+        let mut seed = Some(self.0);
+        let mut cells = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::DefaultStyle if default_style.is_some() => return Err(Error::duplicate_field("default_style")),
+                Field::DefaultStyle => default_style = Some(map.next_value()?),
+                Field::Cells => {
+                    let seed = seed.take().ok_or_else(|| Error::duplicate_field("cells"))?;
+
+                    cells = Some(map.next_value_seed(seed)?);
+                }
+            }
+        }
+
+        let cells = cells.ok_or_else(|| Error::missing_field("cells"))?;
+
+        Ok((default_style.unwrap_or_default(), cells))
+    }
+}
+
+/// Deserializes rows of cells into the rows of a fixed-size buffer, returning the dimensions
+/// (width and height) that were filled. Every row must have the same length.
+pub struct RowsInto<'a, const WIDTH: usize>(pub &'a mut [[Cell; WIDTH]]);
+
+impl<'de, const WIDTH: usize> DeserializeSeed<'de> for RowsInto<'_, WIDTH> {
+    type Value = (usize, usize);
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, const WIDTH: usize> Visitor<'de> for RowsInto<'_, WIDTH> {
+    type Value = (usize, usize);
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        write!(formatter, "at most {} rows of at most {WIDTH} cells", self.0.len())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let max_height = self.0.len();
+        let mut width = None;
+        let mut height = 0;
+
+        for row in self.0.iter_mut() {
+            let Some(row_width) = seq.next_element_seed(RowInto(row))? else {
+                break;
+            };
+
+            if width.is_some_and(|width| width != row_width) {
+                return Err(Error::custom("every row of a terminal must have the same length"));
+            }
+
+            width = Some(row_width);
+            height += 1;
+        }
+
+        if height == max_height && seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(Error::custom(format_args!("a terminal can't have more than {max_height} rows here")));
+        }
+
+        Ok((width.unwrap_or(0), height))
+    }
+}
+
+/// Deserializes a row of cells into a fixed-size row, returning the number of cells that were filled.
+struct RowInto<'a, const WIDTH: usize>(&'a mut [Cell; WIDTH]);
+
+impl<'de, const WIDTH: usize> DeserializeSeed<'de> for RowInto<'_, WIDTH> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, const WIDTH: usize> Visitor<'de> for RowInto<'_, WIDTH> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        write!(formatter, "at most {WIDTH} cells")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut width = 0;
+
+        for cell in self.0.iter_mut() {
+            let Some(new_cell) = seq.next_element()? else {
+                break;
+            };
+
+            *cell = new_cell;
+            width += 1;
+        }
+
+        if width == WIDTH && seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(Error::custom(format_args!("a terminal can't have more than {WIDTH} columns here")));
+        }
+
+        Ok(width)
+    }
+}
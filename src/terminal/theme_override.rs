@@ -0,0 +1,69 @@
+use crate::style::{Style, Theme};
+use crate::terminal::{Cell, Metadata, TerminalConst, TerminalMut};
+
+/// A terminal that wraps another terminal, but reports a different [`Theme`]. Everything else is
+/// passed through to the wrapped terminal.
+///
+/// You usually don't need to use this directly; wrap your widget in a
+/// [`Themed`](crate::widgets::builtins::Themed) instead.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Theme;
+/// use tuit::terminal::{ConstantSize, ThemeOverride};
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+/// let themed = ThemeOverride::new(&mut terminal, Theme::MONOCHROME);
+///
+/// assert_eq!(themed.theme(), Theme::MONOCHROME);
+/// assert_eq!(terminal.theme(), Theme::DEFAULT);
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ThemeOverride<T> {
+    /// The terminal that is wrapped.
+    pub terminal: T,
+    /// The theme that is reported instead of the wrapped terminal's.
+    pub theme: Theme,
+}
+
+impl<T> ThemeOverride<T> {
+    /// Wrap a terminal, changing its theme to the specified [`Theme`].
+    #[must_use]
+    pub const fn new(terminal: T, theme: Theme) -> Self {
+        Self { terminal, theme }
+    }
+}
+
+impl<T: Metadata> Metadata for ThemeOverride<T> {
+    fn dimensions(&self) -> (usize, usize) {
+        self.terminal.dimensions()
+    }
+
+    fn default_style(&self) -> Style {
+        self.terminal.default_style()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme
+    }
+}
+
+impl<T: TerminalConst> TerminalConst for ThemeOverride<T> {
+    fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.terminal.cells()
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        self.terminal.cell(x, y)
+    }
+}
+
+impl<T: TerminalMut> TerminalMut for ThemeOverride<T> {
+    fn cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        self.terminal.cells_mut()
+    }
+
+    fn cell_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        self.terminal.cell_mut(x, y)
+    }
+}
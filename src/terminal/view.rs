@@ -1,4 +1,4 @@
-use crate::style::Style;
+use crate::style::{Style, Theme};
 use crate::terminal::view_iterator::ViewIterator;
 use crate::terminal::Rectangle;
 use crate::terminal::TerminalMut;
@@ -25,6 +25,10 @@ where
     fn default_style(&self) -> Style {
         self.default_style
     }
+
+    fn theme(&self) -> Theme {
+        self.parent.theme()
+    }
 }
 
 impl<T> TerminalConst for View<T>
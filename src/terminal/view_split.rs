@@ -1,4 +1,4 @@
-use crate::style::Style;
+use crate::style::{Style, Theme};
 use crate::terminal::{Cell, Metadata, Rectangle, Rescalable, TerminalConst, TerminalMut};
 use crate::terminal::view::View;
 use crate::widgets::Direction;
@@ -172,6 +172,10 @@ impl<T: Metadata> Metadata for ViewSplit<T> {
     fn default_style(&self) -> Style {
         self.child.default_style()
     }
+
+    fn theme(&self) -> Theme {
+        self.child.theme()
+    }
 }
 
 impl<T: TerminalConst> TerminalConst for ViewSplit<T> {
@@ -0,0 +1,1103 @@
+use crate::style::{Colour, Style, Underline};
+use crate::terminal::{Cell, Hyperlink, TerminalMut};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The most parameters that a control sequence can have. Any more are ignored.
+const MAX_PARAMS: usize = 32;
+/// The longest operating system command (like a hyperlink) that can be understood.
+const MAX_OSC: usize = 512;
+/// The distance between tab stops.
+const TAB_WIDTH: usize = 8;
+
+/// The part of an escape sequence that the parser is in the middle of.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum State {
+    /// Printing characters.
+    Ground,
+    /// After an `ESC`.
+    Escape,
+    /// After an `ESC` and an intermediate byte, like in `ESC ( B`. These sequences are ignored.
+    EscapeIntermediate,
+    /// Inside a control sequence (`ESC [`).
+    Csi,
+    /// Inside a malformed control sequence, which is ignored up to its final byte.
+    CsiIgnore,
+    /// Inside an operating system command (`ESC ]`).
+    Osc,
+    /// Inside a device control string, or another string that is ignored up to its terminator.
+    IgnoredString,
+}
+
+/// The cursor, as saved by `ESC 7` and restored by `ESC 8`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct SavedCursor {
+    position: (usize, usize),
+    pending_wrap: bool,
+    pen: Style,
+    origin_mode: bool,
+}
+
+/// A VT100/xterm emulator, which parses a stream of text and escape sequences (like the output of
+/// an [`AnsiRenderer`](crate::draw::AnsiRenderer), or of another program) and applies it to a terminal.
+///
+/// Printed characters become [`Cell`]s with the [`Style`] that was selected when they were
+/// printed, so every cell gets the whole style that it would be displayed with. The parser
+/// understands:
+///
+/// - Cursor movement, including saving and restoring the cursor, tabs, and the origin mode.
+/// - Erasing and inserting characters and lines, and scrolling.
+/// - Every SGR attribute that a [`Style`] has, including the 256-colour and true colour
+///   extensions, underline variants (`4:3`) and underline colours (`58`).
+/// - Scrolling regions (`DECSTBM`), automatic wrapping, and hiding the cursor.
+/// - The alternate screen (`?47`, `?1047` and `?1049`). The main screen is saved into the buffer
+///   given to [`VtParser::alternate_screen`]. Without one, it is saved into an allocated buffer
+///   when the `alloc` feature is enabled, and cleared otherwise.
+/// - OSC 8 hyperlinks, whose targets are looked up in [`VtParser::hyperlinks`], since cells can
+///   only borrow a `&'static str`.
+///
+/// Everything else, like window titles and device status requests, is ignored. Broken UTF-8 is
+/// printed as `U+FFFD`.
+///
+/// A line feed only moves the cursor down, like it does on a VT100, unless the newline mode is
+/// turned on with [`VtParser::newline_mode`] (or `ESC [ 20 h`). Programs usually have their line
+/// feeds turned into `\r\n` by the TTY, but the output of an [`AnsiRenderer`](crate::draw::AnsiRenderer)
+/// isn't, so it needs the newline mode. With it, parsing that output reproduces the terminal that
+/// was rendered, explicitly turned off attributes included. The only difference is that the cell
+/// to the right of a wide character comes back as a space with the wide character's style:
+///
+/// ```
+/// use tuit::draw::AnsiRenderer;
+/// use tuit::prelude::*;
+/// use tuit::style::{Ansi4, Style};
+/// use tuit::terminal::vt::VtParser;
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut source: ConstantSize<3, 2> = ConstantSize::new();
+/// let style = Style::new().bold().fg_ansi4(Ansi4::Red).bg_rgb24(0, 40, 80);
+///
+/// for (cell, character) in source.cells_mut().zip("Hi!Bye".chars()) {
+///     cell.character = character;
+///     cell.style = style;
+/// }
+///
+/// let mut output = String::new();
+/// AnsiRenderer::new(&mut output).render(&source).expect("Infallible");
+///
+/// let mut copy: ConstantSize<3, 2> = ConstantSize::new();
+/// VtParser::new().newline_mode(true).parse(&mut copy, output.as_bytes());
+///
+/// assert_eq!(copy, source);
+/// ```
+///
+/// A [`Style`]'s unset fields are displayed like the cell before it, so the cells of the copy get
+/// those fields filled in when they aren't written out by the renderer. Colours that escape
+/// sequences can't tell apart from others, like [`Colour::Luma8`], come back as their equivalents.
+#[allow(clippy::struct_excessive_bools)] // They are independent modes, not states.
+#[allow(clippy::module_name_repetitions)]
+pub struct VtParser<'a> {
+    /// Whether line feeds also move the cursor to the start of the line.
+    pub newline_mode: bool,
+    /// The hyperlinks that OSC 8 sequences can link cells to. Links to other targets are ignored.
+    pub hyperlinks: &'a [Hyperlink],
+    alternate_buffer: Option<&'a mut [Cell]>,
+    /// Where the main screen is saved when there is no alternate screen buffer to save it into.
+    #[cfg(feature = "alloc")]
+    allocated_buffer: Vec<Cell>,
+    state: State,
+    params: [u16; MAX_PARAMS],
+    /// Whether each parameter is a sub-parameter of the one before it (separated by `:`).
+    subparams: [bool; MAX_PARAMS],
+    param_count: usize,
+    private_marker: Option<u8>,
+    intermediate: Option<u8>,
+    utf8: [u8; 4],
+    utf8_len: usize,
+    osc: [u8; MAX_OSC],
+    osc_len: usize,
+    /// Whether the last byte of a string was an `ESC`, which might start its terminator.
+    string_escape: bool,
+    position: (usize, usize),
+    /// Whether the last character was printed in the last column, so the next one wraps.
+    pending_wrap: bool,
+    pen: Style,
+    hyperlink: Option<Hyperlink>,
+    saved: Option<SavedCursor>,
+    scroll_region: Option<(usize, usize)>,
+    origin_mode: bool,
+    autowrap: bool,
+    cursor_visible: bool,
+    alternate: bool,
+    alternate_saved: bool,
+}
+
+impl Default for VtParser<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> VtParser<'a> {
+    /// Create a new [`VtParser`], with the cursor in the top-left corner and the default style.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            newline_mode: false,
+            hyperlinks: &[],
+            alternate_buffer: None,
+            #[cfg(feature = "alloc")]
+            allocated_buffer: Vec::new(),
+            state: State::Ground,
+            params: [0; MAX_PARAMS],
+            subparams: [false; MAX_PARAMS],
+            param_count: 0,
+            private_marker: None,
+            intermediate: None,
+            utf8: [0; 4],
+            utf8_len: 0,
+            osc: [0; MAX_OSC],
+            osc_len: 0,
+            string_escape: false,
+            position: (0, 0),
+            pending_wrap: false,
+            pen: Style::new(),
+            hyperlink: None,
+            saved: None,
+            scroll_region: None,
+            origin_mode: false,
+            autowrap: true,
+            cursor_visible: true,
+            alternate: false,
+            alternate_saved: false,
+        }
+    }
+
+    /// Set whether line feeds also move the cursor to the start of the line.
+    #[must_use]
+    pub const fn newline_mode(mut self, newline_mode: bool) -> Self {
+        self.newline_mode = newline_mode;
+
+        self
+    }
+
+    /// Set the hyperlinks that OSC 8 sequences can link cells to.
+    #[must_use]
+    pub const fn hyperlinks(mut self, hyperlinks: &'a [Hyperlink]) -> Self {
+        self.hyperlinks = hyperlinks;
+
+        self
+    }
+
+    /// Set the buffer that the main screen is saved into while the alternate screen is shown. It
+    /// should hold as many cells as the terminal; if it's too small, the main screen is saved like
+    /// it would be without a buffer.
+    #[must_use]
+    pub const fn alternate_screen(mut self, buffer: &'a mut [Cell]) -> Self {
+        self.alternate_buffer = Some(buffer);
+
+        self
+    }
+
+    /// The position of the cursor, as `(x, y)`.
+    #[must_use]
+    pub const fn cursor(&self) -> (usize, usize) {
+        self.position
+    }
+
+    /// Whether the cursor is shown.
+    #[must_use]
+    pub const fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Whether the alternate screen is shown.
+    #[must_use]
+    pub const fn in_alternate_screen(&self) -> bool {
+        self.alternate
+    }
+
+    /// Parse some bytes, and apply them to the terminal.
+    ///
+    /// The parser remembers where it is in the stream, so the bytes can come in chunks of any size,
+    /// even ones that split escape sequences or characters. If the terminal has been resized, the
+    /// cursor is moved back into it.
+    pub fn parse(&mut self, terminal: &mut impl TerminalMut, bytes: &[u8]) {
+        let (width, height) = terminal.dimensions();
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        if self.position.0 >= width || self.position.1 >= height {
+            self.move_to(self.position.0.min(width - 1), self.position.1.min(height - 1));
+        }
+
+        for &byte in bytes {
+            self.advance(terminal, byte);
+        }
+    }
+
+    /// Parse a single byte.
+    fn advance(&mut self, terminal: &mut impl TerminalMut, byte: u8) {
+        if self.utf8_len > 0 {
+            if byte & 0xC0 == 0x80 {
+                self.utf8[self.utf8_len] = byte;
+                self.utf8_len += 1;
+
+                let expected = match self.utf8[0] {
+                    0xF0.. => 4,
+                    0xE0.. => 3,
+                    _ => 2,
+                };
+
+                if self.utf8_len == expected {
+                    let character = core::str::from_utf8(&self.utf8[..expected])
+                        .ok()
+                        .and_then(|text| text.chars().next())
+                        .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+                    self.utf8_len = 0;
+                    self.print(terminal, character);
+                }
+
+                return;
+            }
+
+            // The character was cut short.
+            self.utf8_len = 0;
+            self.print(terminal, char::REPLACEMENT_CHARACTER);
+        }
+
+        match (self.state, byte) {
+            (State::Osc | State::IgnoredString, _) => self.string(terminal, byte),
+            // Cancelled or finished sequences.
+            (_, 0x18 | 0x1A) | (State::EscapeIntermediate, 0x30..=0x7E) | (State::CsiIgnore, 0x40..=0x7E) => self.state = State::Ground,
+            (_, 0x1B) => self.enter(State::Escape),
+            (_, 0x00..=0x1F) => self.execute(terminal, byte),
+            // Deletes, and the rest of ignored sequences.
+            (_, 0x7F) | (State::EscapeIntermediate | State::CsiIgnore, _) => {}
+            (State::Ground, 0x20..=0x7E) => self.print(terminal, char::from(byte)),
+            (State::Ground, 0xC2..=0xF4) => {
+                self.utf8[0] = byte;
+                self.utf8_len = 1;
+            }
+            (State::Ground, 0x80..) => self.print(terminal, char::REPLACEMENT_CHARACTER),
+            (State::Escape, 0x20..=0x2F) => self.state = State::EscapeIntermediate,
+            (State::Escape, _) => self.escape(terminal, byte),
+            (State::Csi, _) => self.control_sequence(terminal, byte),
+        }
+    }
+
+    /// Start a new escape sequence, forgetting any parameters from the last one.
+    const fn enter(&mut self, state: State) {
+        self.state = state;
+        self.params = [0; MAX_PARAMS];
+        self.subparams = [false; MAX_PARAMS];
+        self.param_count = 0;
+        self.private_marker = None;
+        self.intermediate = None;
+        self.osc_len = 0;
+        self.string_escape = false;
+    }
+
+    /// Handle a C0 control character.
+    fn execute(&mut self, terminal: &mut impl TerminalMut, byte: u8) {
+        let (width, _) = terminal.dimensions();
+
+        match byte {
+            // Backspace
+            0x08 => {
+                self.position.0 = self.position.0.saturating_sub(1);
+                self.pending_wrap = false;
+            }
+            // Horizontal tab
+            0x09 => {
+                let next_stop = (self.position.0 / TAB_WIDTH + 1) * TAB_WIDTH;
+
+                self.position.0 = next_stop.min(width.saturating_sub(1));
+                self.pending_wrap = false;
+            }
+            // Line feed, vertical tab, and form feed
+            0x0A..=0x0C => {
+                self.index(terminal);
+
+                if self.newline_mode {
+                    self.position.0 = 0;
+                }
+            }
+            // Carriage return
+            0x0D => {
+                self.position.0 = 0;
+                self.pending_wrap = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle the byte after an `ESC`.
+    fn escape(&mut self, terminal: &mut impl TerminalMut, byte: u8) {
+        self.state = State::Ground;
+
+        match byte {
+            b'[' => self.enter(State::Csi),
+            b']' => self.enter(State::Osc),
+            b'P' | b'X' | b'^' | b'_' => self.enter(State::IgnoredString),
+            b'7' => self.save_cursor(),
+            b'8' => self.restore_cursor(terminal),
+            b'D' => self.index(terminal),
+            b'E' => {
+                self.position.0 = 0;
+                self.index(terminal);
+            }
+            b'M' => self.reverse_index(terminal),
+            b'c' => self.reset(terminal),
+            _ => {}
+        }
+    }
+
+    /// Handle a byte of an operating system command, or of a string that is ignored.
+    fn string(&mut self, terminal: &mut impl TerminalMut, byte: u8) {
+        if self.string_escape {
+            self.finish_string();
+
+            if byte != b'\\' {
+                self.state = State::Escape;
+                self.advance(terminal, byte);
+            }
+
+            return;
+        }
+
+        match byte {
+            0x07 => self.finish_string(),
+            0x1B => self.string_escape = true,
+            _ if self.state == State::Osc => {
+                if let Some(slot) = self.osc.get_mut(self.osc_len) {
+                    *slot = byte;
+                }
+
+                self.osc_len = self.osc_len.saturating_add(1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle the end of a string. Only OSC 8 hyperlinks are understood.
+    fn finish_string(&mut self) {
+        let state = self.state;
+
+        self.state = State::Ground;
+        self.string_escape = false;
+
+        if state != State::Osc || self.osc_len > MAX_OSC {
+            return;
+        }
+
+        let Some(link) = self.osc[..self.osc_len].strip_prefix(b"8;") else {
+            return;
+        };
+
+        // The target comes after the link's parameters.
+        let target = link.splitn(2, |&byte| byte == b';').nth(1).unwrap_or_default();
+
+        self.hyperlink = self.hyperlinks.iter().find(|hyperlink| hyperlink.uri.as_bytes() == target).copied();
+    }
+
+    /// Handle a byte of a control sequence.
+    fn control_sequence(&mut self, terminal: &mut impl TerminalMut, byte: u8) {
+        match byte {
+            b'0'..=b'9' if self.intermediate.is_none() => {
+                if self.param_count == 0 {
+                    self.param_count = 1;
+                }
+
+                if let Some(param) = self.params.get_mut(self.param_count - 1) {
+                    *param = param.saturating_mul(10).saturating_add(u16::from(byte - b'0'));
+                }
+            }
+            b';' | b':' if self.intermediate.is_none() => {
+                // An empty first parameter still counts.
+                self.param_count = self.param_count.max(1) + 1;
+
+                if let Some(subparam) = self.subparams.get_mut(self.param_count - 1) {
+                    *subparam = byte == b':';
+                }
+            }
+            b'<'..=b'?' if self.param_count == 0 && self.private_marker.is_none() => self.private_marker = Some(byte),
+            0x20..=0x2F => self.intermediate = Some(byte),
+            0x40..=0x7E => {
+                self.state = State::Ground;
+                self.dispatch(terminal, byte);
+            }
+            _ => self.state = State::CsiIgnore,
+        }
+    }
+
+    /// The parameter at the index, or the default when it's missing or zero.
+    fn param(&self, idx: usize, default: usize) -> usize {
+        match self.params.get(idx) {
+            Some(&param) if idx < self.param_count && param != 0 => usize::from(param),
+            _ => default,
+        }
+    }
+
+    /// Run a complete control sequence.
+    fn dispatch(&mut self, terminal: &mut impl TerminalMut, final_byte: u8) {
+        let (width, height) = terminal.dimensions();
+        let (top, bottom) = self.region(height);
+        let (x, y) = self.position;
+        let count = self.param(0, 1);
+
+        match (self.private_marker, self.intermediate, final_byte) {
+            (None, None, b'@') => self.shift_right(terminal, count),
+            (None, None, b'A') => self.move_to(x, y.saturating_sub(count).max(if y >= top { top } else { 0 })),
+            (None, None, b'B') => self.move_to(x, y.saturating_add(count).min(if y <= bottom { bottom } else { height.saturating_sub(1) })),
+            (None, None, b'C' | b'a') => self.move_to(x.saturating_add(count).min(width.saturating_sub(1)), y),
+            (None, None, b'D') => self.move_to(x.saturating_sub(count), y),
+            (None, None, b'E') => self.move_to(0, y.saturating_add(count).min(if y <= bottom { bottom } else { height.saturating_sub(1) })),
+            (None, None, b'F') => self.move_to(0, y.saturating_sub(count).max(if y >= top { top } else { 0 })),
+            (None, None, b'G' | b'`') => self.move_to(count.min(width) - 1, y),
+            (None, None, b'H' | b'f') => {
+                let row = self.param(0, 1);
+                let column = self.param(1, 1);
+
+                self.move_to(column.min(width) - 1, self.row(row, height));
+            }
+            (None, None, b'I') => self.move_to((x / TAB_WIDTH + count).saturating_mul(TAB_WIDTH).min(width.saturating_sub(1)), y),
+            (None, None, b'Z') => self.move_to(x.div_ceil(TAB_WIDTH).saturating_sub(count) * TAB_WIDTH, y),
+            (None, None, b'd') => self.move_to(x, self.row(count, height)),
+            (None, None, b'e') => self.move_to(x, y.saturating_add(count).min(height.saturating_sub(1))),
+            (None, None, b'J') => self.erase_display(terminal, self.param(0, 0)),
+            (None, None, b'K') => match self.param(0, 0) {
+                0 => self.erase(terminal, y, x..width),
+                1 => self.erase(terminal, y, 0..x + 1),
+                _ => self.erase(terminal, y, 0..width),
+            },
+            (None, None, b'L') if (top..=bottom).contains(&y) => {
+                self.scroll_down(terminal, y, bottom, count);
+                self.move_to(0, y);
+            }
+            (None, None, b'M') if (top..=bottom).contains(&y) => {
+                self.scroll_up(terminal, y, bottom, count);
+                self.move_to(0, y);
+            }
+            (None, None, b'P') => self.shift_left(terminal, count),
+            (None, None, b'S') => self.scroll_up(terminal, top, bottom, count),
+            (None, None, b'T') => self.scroll_down(terminal, top, bottom, count),
+            (None, None, b'X') => {
+                self.erase(terminal, y, x..x.saturating_add(count).min(width));
+                self.pending_wrap = false;
+            }
+            (None, None, b'm') => self.select_graphic_rendition(),
+            (None, None, b'r') => {
+                let top = self.param(0, 1) - 1;
+                let bottom = self.param(1, height).min(height) - 1;
+
+                if top < bottom {
+                    self.scroll_region = (top > 0 || bottom + 1 < height).then_some((top, bottom));
+                    self.move_to(0, if self.origin_mode { top } else { 0 });
+                }
+            }
+            (None, None, b's') => self.save_cursor(),
+            (None, None, b'u') => self.restore_cursor(terminal),
+            (None, None, b'h' | b'l') => {
+                for idx in 0..self.param_count.min(MAX_PARAMS) {
+                    if self.params[idx] == 20 {
+                        self.newline_mode = final_byte == b'h';
+                    }
+                }
+            }
+            (Some(b'?'), None, b'h' | b'l') => {
+                for idx in 0..self.param_count.min(MAX_PARAMS) {
+                    self.set_private_mode(terminal, self.params[idx], final_byte == b'h');
+                }
+            }
+            (None, Some(b'!'), b'p') => self.soft_reset(),
+            _ => {}
+        }
+    }
+
+    /// Turn on or off one of the DEC private modes.
+    fn set_private_mode(&mut self, terminal: &mut impl TerminalMut, mode: u16, enabled: bool) {
+        match mode {
+            6 => {
+                self.origin_mode = enabled;
+
+                let (top, _) = self.region(terminal.height());
+                self.move_to(0, if enabled { top } else { 0 });
+            }
+            7 => self.autowrap = enabled,
+            25 => self.cursor_visible = enabled,
+            47 | 1047 | 1049 if enabled && !self.alternate => {
+                if mode == 1049 {
+                    self.save_cursor();
+                }
+
+                self.alternate = true;
+                self.alternate_saved = false;
+
+                let (width, height) = terminal.dimensions();
+
+                if let Some(buffer) = self.alternate_buffer.as_deref_mut() {
+                    if buffer.len() >= width * height {
+                        for (saved, cell) in buffer.iter_mut().zip(terminal.cells_mut()) {
+                            *saved = *cell;
+                        }
+
+                        self.alternate_saved = true;
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                if !self.alternate_saved {
+                    self.allocated_buffer.clear();
+                    self.allocated_buffer.extend(terminal.cells_mut().take(width * height).map(|cell| *cell));
+                }
+
+                self.erase_display(terminal, 2);
+            }
+            47 | 1047 | 1049 if !enabled && self.alternate => {
+                self.alternate = false;
+
+                match self.alternate_buffer.as_deref() {
+                    Some(buffer) if self.alternate_saved => {
+                        for (cell, saved) in terminal.cells_mut().zip(buffer) {
+                            *cell = *saved;
+                        }
+                    }
+                    #[cfg(feature = "alloc")]
+                    _ if self.allocated_buffer.len() == terminal.width() * terminal.height() => {
+                        for (cell, saved) in terminal.cells_mut().zip(self.allocated_buffer.drain(..)) {
+                            *cell = saved;
+                        }
+                    }
+                    _ => self.erase_display(terminal, 2),
+                }
+
+                if mode == 1049 {
+                    self.restore_cursor(terminal);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply the attributes of an SGR sequence (`ESC [ ... m`) to the pen.
+    fn select_graphic_rendition(&mut self) {
+        let (params, subparams) = (self.params, self.subparams);
+        let count = self.param_count.clamp(1, MAX_PARAMS);
+        let mut idx = 0;
+
+        while idx < count {
+            let mut end = idx + 1;
+
+            while end < count && subparams[end] {
+                end += 1;
+            }
+
+            let extra = &params[idx + 1..end];
+            let pen = &mut self.pen;
+
+            match params[idx] {
+                0 => *pen = Style::new(),
+                1 => pen.font_weight = Some(700),
+                2 => pen.dim = Some(true),
+                3 => pen.italic = Some(true),
+                4 => match extra.first() {
+                    None => {
+                        pen.underline = Some(true);
+                        pen.underline_variant = None;
+                    }
+                    Some(0) => pen.underline = Some(false),
+                    Some(&variant @ 1..=5) => {
+                        pen.underline = Some(true);
+                        pen.underline_variant = Some(match variant {
+                            1 => Underline::Single,
+                            2 => Underline::Double,
+                            3 => Underline::Curly,
+                            4 => Underline::Dotted,
+                            _ => Underline::Dashed,
+                        });
+                    }
+                    Some(_) => {}
+                },
+                5 | 6 => pen.blink = Some(true),
+                7 => pen.invert = Some(true),
+                8 => pen.hidden = Some(true),
+                9 => pen.strikethrough = Some(true),
+                21 => {
+                    pen.underline = Some(true);
+                    pen.underline_variant = Some(Underline::Double);
+                }
+                22 => {
+                    pen.font_weight = Some(400);
+                    pen.dim = Some(false);
+                }
+                23 => pen.italic = Some(false),
+                24 => pen.underline = Some(false),
+                25 => pen.blink = Some(false),
+                27 => pen.invert = Some(false),
+                28 => pen.hidden = Some(false),
+                29 => pen.strikethrough = Some(false),
+                code @ (30..=37 | 90..=97) => pen.fg_colour = Some(ansi16(code % 10, code >= 90)),
+                code @ (40..=47 | 100..=107) => pen.bg_colour = Some(ansi16(code % 10, code >= 100)),
+                39 => pen.fg_colour = Some(Colour::TerminalDefault),
+                49 => pen.bg_colour = Some(Colour::TerminalDefault),
+                59 => pen.underline_colour = Some(Colour::TerminalDefault),
+                53 => pen.overline = Some(true),
+                55 => pen.overline = Some(false),
+                code @ (38 | 48 | 58) => {
+                    // Either `38:2::r:g:b` (or `38:2:r:g:b`), or `38;2;r;g;b`, which takes up the
+                    // parameters after it.
+                    let (colour, used) = if extra.is_empty() {
+                        extended_colour(&params[end..count], false)
+                    } else {
+                        (extended_colour(extra, extra.len() >= 5).0, 0)
+                    };
+
+                    end = (end + used).min(count);
+
+                    let field = match code {
+                        38 => &mut pen.fg_colour,
+                        48 => &mut pen.bg_colour,
+                        _ => &mut pen.underline_colour,
+                    };
+
+                    if colour.is_some() {
+                        *field = colour;
+                    }
+                }
+                _ => {}
+            }
+
+            idx = end;
+        }
+    }
+
+    /// Print a character at the cursor, and move the cursor past it.
+    fn print(&mut self, terminal: &mut impl TerminalMut, character: char) {
+        let (width, _) = terminal.dimensions();
+        let cell = Cell {
+            character,
+            style: self.pen,
+            hyperlink: self.hyperlink,
+        };
+        let cell_width = cell.width().min(width);
+
+        let overflows = self.position.0 + cell_width > width;
+
+        if self.autowrap && (self.pending_wrap || overflows && self.position.0 > 0) {
+            self.position.0 = 0;
+            self.index(terminal);
+        } else if overflows {
+            self.position.0 = width - cell_width;
+        }
+
+        let (x, y) = self.position;
+
+        for column in x..x + cell_width {
+            if let Some(target) = terminal.cell_mut(column, y) {
+                // The cell after a wide character is its continuation.
+                *target = if column == x { cell } else { Cell { character: ' ', ..cell } };
+            }
+        }
+
+        self.pending_wrap = false;
+
+        if x + cell_width < width {
+            self.position.0 = x + cell_width;
+        } else {
+            self.position.0 = width.saturating_sub(1);
+            self.pending_wrap = self.autowrap;
+        }
+    }
+
+    /// Move the cursor down, scrolling if it's at the bottom of the scrolling region.
+    fn index(&mut self, terminal: &mut impl TerminalMut) {
+        let height = terminal.height();
+        let (top, bottom) = self.region(height);
+
+        self.pending_wrap = false;
+
+        if self.position.1 == bottom {
+            self.scroll_up(terminal, top, bottom, 1);
+        } else if self.position.1 + 1 < height {
+            self.position.1 += 1;
+        }
+    }
+
+    /// Move the cursor up, scrolling if it's at the top of the scrolling region.
+    fn reverse_index(&mut self, terminal: &mut impl TerminalMut) {
+        let (top, bottom) = self.region(terminal.height());
+
+        self.pending_wrap = false;
+
+        if self.position.1 == top {
+            self.scroll_down(terminal, top, bottom, 1);
+        } else {
+            self.position.1 = self.position.1.saturating_sub(1);
+        }
+    }
+
+    /// Move the rows from `top` to `bottom` up, and fill the rows left at the bottom with blanks.
+    fn scroll_up(&self, terminal: &mut impl TerminalMut, top: usize, bottom: usize, count: usize) {
+        let width = terminal.width();
+
+        for y in top..=bottom {
+            match y.checked_add(count).filter(|&source| source <= bottom) {
+                Some(source) => copy_row(terminal, source, y),
+                None => self.erase(terminal, y, 0..width),
+            }
+        }
+    }
+
+    /// Move the rows from `top` to `bottom` down, and fill the rows left at the top with blanks.
+    fn scroll_down(&self, terminal: &mut impl TerminalMut, top: usize, bottom: usize, count: usize) {
+        let width = terminal.width();
+
+        for y in (top..=bottom).rev() {
+            match y.checked_sub(count).filter(|&source| source >= top) {
+                Some(source) => copy_row(terminal, source, y),
+                None => self.erase(terminal, y, 0..width),
+            }
+        }
+    }
+
+    /// Move the characters from the cursor to the end of its row right, leaving blanks behind.
+    fn shift_right(&mut self, terminal: &mut impl TerminalMut, count: usize) {
+        let (x, y) = self.position;
+        let width = terminal.width();
+
+        for column in (x..width).rev() {
+            let source = column.checked_sub(count).filter(|&source| source >= x);
+            let cell = source.and_then(|source| terminal.cell_mut(source, y).copied()).unwrap_or_else(|| self.blank());
+
+            if let Some(target) = terminal.cell_mut(column, y) {
+                *target = cell;
+            }
+        }
+
+        self.pending_wrap = false;
+    }
+
+    /// Move the characters after the cursor left over it, filling the end of its row with blanks.
+    fn shift_left(&mut self, terminal: &mut impl TerminalMut, count: usize) {
+        let (x, y) = self.position;
+        let width = terminal.width();
+
+        for column in x..width {
+            let source = column.checked_add(count).filter(|&source| source < width);
+            let cell = source.and_then(|source| terminal.cell_mut(source, y).copied()).unwrap_or_else(|| self.blank());
+
+            if let Some(target) = terminal.cell_mut(column, y) {
+                *target = cell;
+            }
+        }
+
+        self.pending_wrap = false;
+    }
+
+    /// Erase part of the screen: below the cursor (0), above it (1), or all of it (2 and 3).
+    fn erase_display(&self, terminal: &mut impl TerminalMut, mode: usize) {
+        let (width, height) = terminal.dimensions();
+        let (x, y) = self.position;
+
+        match mode {
+            0 => {
+                self.erase(terminal, y, x..width);
+
+                for row in y + 1..height {
+                    self.erase(terminal, row, 0..width);
+                }
+            }
+            1 => {
+                for row in 0..y {
+                    self.erase(terminal, row, 0..width);
+                }
+
+                self.erase(terminal, y, 0..x + 1);
+            }
+            _ => {
+                for row in 0..height {
+                    self.erase(terminal, row, 0..width);
+                }
+            }
+        }
+    }
+
+    /// Replace some of the cells in a row with blanks.
+    fn erase(&self, terminal: &mut impl TerminalMut, y: usize, columns: core::ops::Range<usize>) {
+        for x in columns {
+            if let Some(cell) = terminal.cell_mut(x, y) {
+                *cell = self.blank();
+            }
+        }
+    }
+
+    /// An erased cell, which keeps the background colour of the pen.
+    const fn blank(&self) -> Cell {
+        Cell {
+            character: ' ',
+            style: Style {
+                bg_colour: self.pen.bg_colour,
+                ..Style::new()
+            },
+            hyperlink: None,
+        }
+    }
+
+    /// Move the cursor, which cancels a pending wrap.
+    const fn move_to(&mut self, x: usize, y: usize) {
+        self.position = (x, y);
+        self.pending_wrap = false;
+    }
+
+    /// The row that a 1-based row number refers to, which is inside the scrolling region in the
+    /// origin mode.
+    fn row(&self, row: usize, height: usize) -> usize {
+        let (top, bottom) = self.region(height);
+
+        if self.origin_mode {
+            (top + row - 1).min(bottom)
+        } else {
+            (row - 1).min(height.saturating_sub(1))
+        }
+    }
+
+    /// The top and bottom rows of the scrolling region.
+    const fn region(&self, height: usize) -> (usize, usize) {
+        match self.scroll_region {
+            Some((top, bottom)) if bottom < height => (top, bottom),
+            _ => (0, height.saturating_sub(1)),
+        }
+    }
+
+    /// Save the cursor's position and the pen.
+    const fn save_cursor(&mut self) {
+        self.saved = Some(SavedCursor {
+            position: self.position,
+            pending_wrap: self.pending_wrap,
+            pen: self.pen,
+            origin_mode: self.origin_mode,
+        });
+    }
+
+    /// Restore the cursor's position and the pen, or move the cursor home if it wasn't saved.
+    fn restore_cursor(&mut self, terminal: &impl TerminalMut) {
+        let (width, height) = terminal.dimensions();
+        let saved = self.saved.unwrap_or(SavedCursor {
+            position: (0, 0),
+            pending_wrap: false,
+            pen: Style::new(),
+            origin_mode: false,
+        });
+
+        self.position = (saved.position.0.min(width.saturating_sub(1)), saved.position.1.min(height.saturating_sub(1)));
+        self.pending_wrap = saved.pending_wrap;
+        self.pen = saved.pen;
+        self.origin_mode = saved.origin_mode;
+    }
+
+    /// Reset the modes and the pen, but not the screen (`ESC [ ! p`).
+    const fn soft_reset(&mut self) {
+        self.pen = Style::new();
+        self.hyperlink = None;
+        self.saved = None;
+        self.scroll_region = None;
+        self.origin_mode = false;
+        self.autowrap = true;
+        self.cursor_visible = true;
+    }
+
+    /// Reset everything, and clear the screen (`ESC c`).
+    fn reset(&mut self, terminal: &mut impl TerminalMut) {
+        self.soft_reset();
+        self.alternate = false;
+        self.alternate_saved = false;
+        self.move_to(0, 0);
+        self.erase_display(terminal, 2);
+    }
+}
+
+/// One of the 16 ANSI colours, from its SGR code's last digit.
+fn ansi16(index: u16, bright: bool) -> Colour {
+    let index = usize::from(index) + if bright { 8 } else { 0 };
+
+    Colour::Ansi16(Colour::ANSI16[index % 16].0)
+}
+
+/// Parse the parameters of an extended colour (after the `38`, `48` or `58`), returning the colour
+/// and how many parameters it took up. Sub-parameters (`38:2::r:g:b`) may have a colour space
+/// before the red, green and blue.
+fn extended_colour(params: &[u16], colour_space: bool) -> (Option<Colour>, usize) {
+    let byte = |idx: usize| params.get(idx).and_then(|&param| u8::try_from(param).ok());
+
+    match params.first() {
+        Some(5) => (byte(1).map(Colour::Ansi256), 2),
+        Some(2) => {
+            let first = if colour_space { 2 } else { 1 };
+            let colour = match (byte(first), byte(first + 1), byte(first + 2)) {
+                (Some(red), Some(green), Some(blue)) => Some(Colour::Rgb24(red, green, blue)),
+                _ => None,
+            };
+
+            (colour, 4)
+        }
+        _ => (None, 1),
+    }
+}
+
+/// Copy a row of cells onto another.
+fn copy_row(terminal: &mut impl TerminalMut, source: usize, target: usize) {
+    for x in 0..terminal.width() {
+        if let Some(cell) = terminal.cell_mut(x, source).copied() {
+            if let Some(target) = terminal.cell_mut(x, target) {
+                *target = cell;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::VtParser;
+    use crate::draw::{AnsiRenderer, Renderer};
+    use crate::prelude::*;
+    use crate::style::{Ansi4, Colour, Style, Underline};
+    use crate::terminal::{Cell, ConstantSize, Hyperlink};
+
+    /// The characters in a row of the terminal.
+    fn row<const WIDTH: usize, const HEIGHT: usize>(terminal: &ConstantSize<WIDTH, HEIGHT>, y: usize) -> String {
+        (0..WIDTH).map(|x| terminal.cell(x, y).expect("Cell exists").character).collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        const DOCS: Hyperlink = Hyperlink::new("https://docs.rs/tuit");
+
+        let styles = [
+            Style::new().fg_ansi4(Ansi4::BrightCyan).bg_ansi8(236).italic().underlined(),
+            Style::new().fg_rgb24(255, 128, 0).underline_variant(Underline::Curly).underlined().underline_colour_of(Colour::Ansi256(9)),
+            Style::new().bold().dimmed().blinking().strikethrough().overlined().inverted(),
+            Style::new().hidden().underline_variant(Underline::Double).underlined().not_overlined(),
+            // Everything that the row above turned on is turned off again.
+            Style::new().visible().underline_variant(Underline::Double).not_underlined().not_overlined().regular().not_dimmed().fg(Colour::TerminalDefault),
+        ];
+
+        let mut source: ConstantSize<5, 5> = ConstantSize::new();
+
+        for (idx, cell) in source.cells_mut().enumerate() {
+            cell.character = char::from(b'a' + u8::try_from(idx).expect("There are 25 cells"));
+            cell.style = styles[idx / 5];
+            cell.hyperlink = (idx % 5 < 2).then_some(DOCS);
+        }
+
+        // A wide character comes back with a blank continuation.
+        source.cell_mut(0, 4).expect("Cell exists").character = '漢';
+        source.cell_mut(1, 4).expect("Cell exists").character = ' ';
+
+        let mut output = String::new();
+        AnsiRenderer::new(&mut output).render(&source).expect("Infallible");
+
+        let mut copy: ConstantSize<5, 5> = ConstantSize::new();
+        VtParser::new().newline_mode(true).hyperlinks(&[DOCS]).parse(&mut copy, output.as_bytes());
+
+        assert_eq!(copy, source);
+    }
+
+    #[test]
+    fn cursor_and_erasing() {
+        let mut terminal: ConstantSize<6, 3> = ConstantSize::new();
+        let mut parser = VtParser::new();
+
+        parser.parse(&mut terminal, b"abcdef\x1b[2;3Hxyz\x1b[3;1H123456\x1b[2D\x1b[K");
+        assert_eq!([row(&terminal, 0), row(&terminal, 1), row(&terminal, 2)], ["abcdef", "  xyz ", "123   "]);
+        assert_eq!(parser.cursor(), (3, 2));
+
+        parser.parse(&mut terminal, b"\x1b[1;2H\x1b[2@\x1b[2;1H\x1b[P\x1b[1J");
+        assert_eq!([row(&terminal, 0), row(&terminal, 1), row(&terminal, 2)], ["      ", " xyz  ", "123   "]);
+
+        parser.parse(&mut terminal, b"\x1b[H\tX\x1b[3GY\x1b[3;2H\x1b[2X");
+        assert_eq!([row(&terminal, 0), row(&terminal, 2)], ["  Y  X", "1     "]);
+    }
+
+    #[test]
+    fn wrapping_and_scrolling() {
+        let mut terminal: ConstantSize<3, 3> = ConstantSize::new();
+        let mut parser = VtParser::new();
+
+        // The last column is filled before wrapping, and the bottom row scrolls up.
+        parser.parse(&mut terminal, b"abcdefghijk");
+        assert_eq!([row(&terminal, 0), row(&terminal, 1), row(&terminal, 2)], ["def", "ghi", "jk "]);
+
+        // Only the scrolling region scrolls.
+        parser.parse(&mut terminal, b"\x1b[2;3r\x1b[3;1H\r\n\x1bM\x1bM1");
+        assert_eq!([row(&terminal, 0), row(&terminal, 1), row(&terminal, 2)], ["def", "1  ", "jk "]);
+
+        parser.parse(&mut terminal, b"\x1b[r\x1b[1S\x1b[?7l\x1b[3;1Hwxyz");
+        assert_eq!([row(&terminal, 0), row(&terminal, 1), row(&terminal, 2)], ["1  ", "jk ", "wxz"]);
+    }
+
+    #[test]
+    fn alternate_screen() {
+        let mut terminal: ConstantSize<4, 2> = ConstantSize::new();
+        let mut buffer = [Cell::default(); 4 * 2];
+        let mut parser = VtParser::new().alternate_screen(&mut buffer);
+
+        parser.parse(&mut terminal, b"main\x1b[2;2H\x1b[?1049h");
+        assert!(parser.in_alternate_screen());
+        assert_eq!(row(&terminal, 0), "    ");
+
+        parser.parse(&mut terminal, b"\x1b[Halt\x1b[?25l\x1b[?1049l!");
+        assert!(!parser.in_alternate_screen());
+        assert!(!parser.cursor_visible());
+        assert_eq!([row(&terminal, 0), row(&terminal, 1)], ["main", " !  "]);
+
+        let mut parser = VtParser::new();
+
+        parser.parse(&mut terminal, b"\x1b[?1049h\x1b[Halt\x1b[?1049l");
+        assert_eq!([row(&terminal, 0), row(&terminal, 1)], ["main", " !  "]);
+    }
+
+    #[test]
+    fn graphic_renditions() {
+        let mut terminal: ConstantSize<6, 1> = ConstantSize::new();
+        let mut parser = VtParser::new();
+
+        parser.parse(&mut terminal, b"\x1b[1;38;5;200;48:2::1:2:3ma\x1b[22;4:3;58:2:4:5:6;39mb\x1b[0;21;97;100mc\x1b[2;24;49;1;2md\x1b[m\x1b[38;2;300;0;0;9me");
+
+        let style = |x| terminal.cell(x, 0).expect("Cell exists").style;
+
+        assert_eq!(style(0), Style::new().bold().fg_ansi8(200).bg_rgb24(1, 2, 3));
+        assert_eq!(
+            style(1),
+            Style::new().regular().not_dimmed().fg(Colour::TerminalDefault).bg_rgb24(1, 2, 3).underlined().underline_variant(Underline::Curly).underline_colour_of(Colour::Rgb24(4, 5, 6))
+        );
+        assert_eq!(style(2), Style::new().underlined().underline_variant(Underline::Double).fg_ansi4(Ansi4::BrightWhite).bg_ansi4(Ansi4::BrightBlack));
+        assert_eq!(
+            style(3),
+            Style::new().bold().dimmed().underline_variant(Underline::Double).not_underlined().fg_ansi4(Ansi4::BrightWhite).bg(Colour::TerminalDefault)
+        );
+        // An out of range colour is ignored, but the parameters after it aren't.
+        assert_eq!(style(4), Style::new().strikethrough());
+    }
+
+    #[test]
+    fn split_and_broken_input() {
+        let mut terminal: ConstantSize<7, 1> = ConstantSize::new();
+        let mut parser = VtParser::new();
+
+        for chunk in ["é漢".as_bytes(), b"\xff\xe6\xbc", b"x\x1b", b"[3", b"1m", b"\x1b]0;title\x07", b"y"] {
+            parser.parse(&mut terminal, chunk);
+        }
+
+        assert_eq!(row(&terminal, 0), "é漢 \u{fffd}\u{fffd}xy");
+        assert_eq!(terminal.cell(6, 0).expect("Cell exists").style, Style::new().fg_ansi4(Ansi4::Red));
+        assert_eq!(parser.cursor(), (6, 0));
+    }
+}
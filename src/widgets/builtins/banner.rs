@@ -0,0 +1,204 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::Style;
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+pub use crate::font::{Font, Glyph};
+
+/// Where something is placed along one axis of a [`Rectangle`].
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+pub enum Alignment {
+    /// The left or top edge.
+    Start,
+    /// The middle.
+    #[default]
+    Center,
+    /// The right or bottom edge.
+    End,
+}
+
+impl Alignment {
+    /// The offset of something of the specified length inside of the available space.
+    #[must_use]
+    pub const fn offset(self, length: usize, space: usize) -> usize {
+        let free = space.saturating_sub(length);
+
+        match self {
+            Self::Start => 0,
+            Self::Center => free / 2,
+            Self::End => free,
+        }
+    }
+}
+
+/// Large text drawn with a bitmap [`Font`], for splash screens and big counters.
+///
+/// Each line of the text (separated by `\n`) is aligned on its own. Set pixels are drawn as
+/// [`Banner::pixel`], and the rest of the [`Banner`]'s bounding box is filled with spaces, both
+/// using [`Banner::style`].
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::banner::{Alignment, Font};
+/// use tuit::widgets::builtins::Banner;
+/// use tuit::widgets::BoundingBox;
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+/// let banner = Banner::new("12", Font::SMALL).horizontal(Alignment::Start).pixel('#');
+///
+/// banner.drawn(&mut terminal).expect("There is enough space");
+///
+/// let row: String = terminal.cells().take(7).map(|cell| cell.character).collect();
+///
+/// assert_eq!(row, " #  ###");
+/// assert_eq!(banner.bounding_box(terminal.bounding_box()).expect("It fits").dimensions(), (7, 5));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Banner<'a> {
+    /// The text to draw.
+    pub text: &'a str,
+    /// The font to draw the text with.
+    pub font: Font<'a>,
+    /// The style of the banner. Unset properties come from [`Theme::accent`](crate::style::Theme::accent).
+    pub style: Style,
+    /// The character drawn for each set pixel.
+    pub pixel: char,
+    /// The number of blank columns between each glyph.
+    pub letter_spacing: usize,
+    /// The number of blank rows between each line.
+    pub line_spacing: usize,
+    /// Where the text is placed horizontally inside its [`Rectangle`].
+    pub horizontal: Alignment,
+    /// Where the text is placed vertically inside its [`Rectangle`].
+    pub vertical: Alignment,
+}
+
+impl<'a> Banner<'a> {
+    /// Create a new, centered [`Banner`] that draws pixels as full blocks.
+    #[must_use]
+    pub const fn new(text: &'a str, font: Font<'a>) -> Self {
+        Self {
+            text,
+            font,
+            style: Style::new(),
+            pixel: '█',
+            letter_spacing: 1,
+            line_spacing: 1,
+            horizontal: Alignment::Center,
+            vertical: Alignment::Center,
+        }
+    }
+
+    /// Apply a [`Style`] to the [`Banner`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the character drawn for each set pixel.
+    #[must_use]
+    pub const fn pixel(mut self, pixel: char) -> Self {
+        self.pixel = pixel;
+
+        self
+    }
+
+    /// Set the horizontal [`Alignment`] of each line.
+    #[must_use]
+    pub const fn horizontal(mut self, alignment: Alignment) -> Self {
+        self.horizontal = alignment;
+
+        self
+    }
+
+    /// Set the vertical [`Alignment`] of the text.
+    #[must_use]
+    pub const fn vertical(mut self, alignment: Alignment) -> Self {
+        self.vertical = alignment;
+
+        self
+    }
+
+    /// The width of a line of text, in cells.
+    fn line_width(&self, line: &str) -> usize {
+        let glyphs = line.chars().count();
+
+        (glyphs * (self.font.width + self.letter_spacing)).saturating_sub(self.letter_spacing)
+    }
+
+    /// The size of the whole text, in cells.
+    fn size(&self) -> (usize, usize) {
+        let width = self.text.lines().map(|line| self.line_width(line)).max().unwrap_or(0);
+        let lines = self.text.lines().count();
+        let height = (lines * (self.font.height + self.line_spacing)).saturating_sub(self.line_spacing);
+
+        (width, height)
+    }
+}
+
+impl Widget for Banner<'_> {
+    fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let bounds = self.bounding_box(terminal.bounding_box())?;
+        let style = self.style.inherits(terminal.theme().accent);
+
+        for y in bounds.top()..bounds.bottom() {
+            for x in bounds.left()..bounds.right() {
+                let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+
+                cell.character = ' ';
+                cell.style = style.inherits(cell.style);
+            }
+        }
+
+        for (line_idx, line) in self.text.lines().enumerate() {
+            let top = bounds.top() + line_idx * (self.font.height + self.line_spacing);
+            let left = bounds.left() + self.horizontal.offset(self.line_width(line), bounds.width());
+
+            for (glyph_idx, character) in line.chars().enumerate() {
+                let Some(glyph) = self.font.glyph(character) else { continue };
+                let glyph_left = left + glyph_idx * (self.font.width + self.letter_spacing);
+
+                for y in 0..self.font.height {
+                    for x in 0..self.font.width {
+                        if glyph.pixel(x, y, self.font.width) {
+                            let (x, y) = (glyph_left + x, top + y);
+
+                            terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?.character = self.pixel;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for Banner<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let (width, height) = self.size();
+
+        if width > rect.width() || height > rect.height() {
+            return Err(Error::rescale((width, height)));
+        }
+
+        let left = rect.left() + self.horizontal.offset(width, rect.width());
+        let top = rect.top() + self.vertical.offset(height, rect.height());
+
+        Ok(Rectangle::of_size((width, height)).at((left, top)))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        let (width, height) = self.size();
+
+        rectangle.width() <= width && rectangle.height() <= height
+    }
+}
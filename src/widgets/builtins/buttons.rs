@@ -4,18 +4,28 @@ use crate::widgets::{BoundingBox, Widget};
 use crate::Error;
 
 /// A widget that displays a list of buttons, left-to-right.
-#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+#[derive(Eq, PartialEq, Hash, Debug, Default)]
 pub struct Buttons<'a, T> {
     /// The buttons to display.
     pub buttons: &'a [T],
-    /// The style to use for the selected button.
+    /// The style to use for the selected button. Unset properties come from [`Theme::selected`](crate::style::Theme::selected).
     pub selected_button_style: Style,
-    /// The style to use for the unselected buttons.
+    /// The style to use for the unselected buttons. Unset properties come from [`Theme::text`](crate::style::Theme::text).
     pub unselected_button_style: Style,
     /// The index of the currently hovered button.
     pub hovered_button: Option<usize>,
 }
 
+// Implemented by hand, because deriving them would require `T: Copy`, even though we only hold a
+// reference to the buttons.
+impl<T> Clone for Buttons<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Buttons<'_, T> {}
+
 impl<'a, T: AsRef<str>> Buttons<'a, T> {
     /// Create a new [`Buttons`] widget.
     #[must_use]
@@ -129,14 +139,15 @@ impl<T: AsRef<str>> Widget for Buttons<'_, T> {
         mut terminal: impl Terminal,
     ) -> crate::Result<UpdateResult> {
         let term_bounding_box = terminal.bounding_box();
+        let theme = terminal.theme();
         let mut terminal_cells = terminal.cells_mut().enumerate().peekable();
 
         for (button_idx, button) in self.buttons.iter().enumerate() {
             let selected = Some(button_idx) == self.hovered_button;
             let base_style = if selected {
-                self.selected_button_style
+                self.selected_button_style.inherits(theme.selected)
             } else {
-                self.unselected_button_style
+                self.unselected_button_style.inherits(theme.text)
             };
 
             let max_len = button.as_ref().len().min(term_bounding_box.width());
@@ -1,7 +1,9 @@
+use core::time::Duration;
+
 use crate::Error::RequestRescale;
 use crate::prelude::{ Terminal, TerminalConst, Widget};
 use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
-use crate::widgets::BoundingBox;
+use crate::widgets::{Animated, BoundingBox};
 
 /// A widget that centers its child widget within its bounding box.
 ///
@@ -82,4 +84,10 @@ impl<T: BoundingBox> BoundingBox for Centered<T> {
     fn completely_covers(&self, rectangle: Rectangle) -> bool {
         self.child.completely_covers(rectangle)
     }
-}
\ No newline at end of file
+}
+
+impl<T: Animated + BoundingBox> Animated for Centered<T> {
+    fn next_tick(&self) -> Option<Duration> {
+        self.child.next_tick()
+    }
+}
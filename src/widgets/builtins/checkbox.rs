@@ -1,6 +1,6 @@
 use crate::prelude::{Terminal, TerminalConst, WithLayout};
 use crate::style::Style;
-use crate::terminal::{KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
+use crate::terminal::{keys, KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
 use crate::widgets::builtins::Text;
 use crate::widgets::{BoundingBox, Widget};
 
@@ -11,9 +11,9 @@ pub struct Checkbox<'a> {
     pub entry: &'a str,
     /// Whether the checkbox is checked.
     pub checked: bool,
-    /// The style of the tickbox.
+    /// The style of the tickbox. Unset properties come from [`Theme::accent`](crate::style::Theme::accent).
     pub box_style: Style,
-    /// The style of the entry text.
+    /// The style of the entry text. Unset properties come from [`Theme::text`](crate::style::Theme::text).
     pub entry_style: Style,
 }
 
@@ -79,7 +79,7 @@ impl Widget for Checkbox<'_> {
                     return Ok(UpdateResult::Interacted)
                 }
             }
-            UpdateInfo::KeyboardInput(0x28, KeyState::KeyDown) => {
+            UpdateInfo::KeyboardInput(keys::ENTER, KeyState::KeyDown) => {
                 self.checked = !self.checked;
 
                 return Ok(UpdateResult::Interacted)
@@ -93,7 +93,7 @@ impl Widget for Checkbox<'_> {
     fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
         let box_text = if self.checked { Self::CHECKED } else { Self::UNCHECKED };
         let mut box_widget = Text::new(box_text);
-        box_widget.style = self.box_style;
+        box_widget.style = self.box_style.inherits(terminal.theme().accent);
 
         let mut entry = Text::new(self.entry);
         entry.style = self.entry_style;
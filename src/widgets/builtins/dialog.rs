@@ -0,0 +1,294 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::{Style, Theme};
+use crate::terminal::{keys, KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::{draw_box, Buttons, Text};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// A modal dialog box that draws over whatever is already inside the terminal.
+///
+/// When it is drawn, the [`Dialog`] recolours every existing cell with its
+/// [`Dialog::overlay_style`] (to dim the background), and then draws a framed box in the center
+/// of the terminal containing its title, body text, and [`Buttons`].
+///
+/// While it is open, the [`Dialog`] traps input: every input event is consumed and reported as
+/// (at least) [`UpdateResult::Interacted`], so you should stop forwarding input to the widgets
+/// behind it until it closes. Once a button is chosen, the dialog closes itself and
+/// [`Widget::update`] returns [`UpdateResult::Selected`] with the index of the chosen button.
+/// Pressing escape closes the dialog without a choice and returns [`UpdateResult::LifecycleEnd`].
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{keys, ConstantSize, KeyState, UpdateInfo, UpdateResult};
+/// use tuit::widgets::builtins::Dialog;
+///
+/// let mut terminal: ConstantSize<40, 10> = ConstantSize::new();
+/// let mut dialog = Dialog::new("Quit", "Do you really want to quit?", &[" Yes ", " No "]);
+///
+/// dialog.drawn(&mut terminal).expect("There is enough space");
+///
+/// let right = UpdateInfo::KeyboardInput(keys::RIGHT_ARROW, KeyState::KeyDown);
+/// let enter = UpdateInfo::KeyboardInput(keys::ENTER, KeyState::KeyDown);
+///
+/// assert_eq!(dialog.update(right, &terminal).expect("Infallible"), UpdateResult::Interacted);
+/// assert_eq!(dialog.update(enter, &terminal).expect("Infallible"), UpdateResult::Selected(1));
+/// assert!(!dialog.is_open());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Dialog<'a, T> {
+    /// The title shown in the top edge of the frame.
+    pub title: &'a str,
+    /// The body text of the dialog. It is wrapped to fit inside the frame.
+    pub body: &'a str,
+    /// The buttons along the bottom of the dialog.
+    pub buttons: Buttons<'a, T>,
+    /// The style of the frame, and the background of the dialog box. Unset properties come from [`Theme::border`].
+    pub frame_style: Style,
+    /// The style of the title. Unset properties come from [`Theme::title`].
+    pub title_style: Style,
+    /// The style of the body text. Unset properties come from [`Theme::text`].
+    pub body_style: Style,
+    /// The style applied over every cell in the terminal behind the dialog. Unset properties come
+    /// from [`Theme::overlay`].
+    ///
+    /// Set it to `None` to leave the background untouched.
+    pub overlay_style: Option<Style>,
+    open: bool,
+}
+
+/// The areas of the terminal that each part of a [`Dialog`] is drawn into.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+struct DialogLayout {
+    frame: Rectangle,
+    body: Rectangle,
+    buttons: Option<Rectangle>,
+}
+
+impl<'a, T: AsRef<str>> Dialog<'a, T> {
+    /// The overlay of the default theme ([`Theme::DEFAULT`]); greys out the text behind the dialog.
+    pub const DEFAULT_OVERLAY: Style = Theme::DEFAULT.overlay;
+
+    /// Create a new, open [`Dialog`], with its first button selected.
+    #[must_use]
+    pub const fn new(title: &'a str, body: &'a str, buttons: &'a [T]) -> Self {
+        Self {
+            title,
+            body,
+            buttons: Buttons::new(buttons).select_first(),
+            frame_style: Style::new(),
+            title_style: Style::new(),
+            body_style: Style::new(),
+            overlay_style: Some(Style::new()),
+            open: true,
+        }
+    }
+
+    /// Set the [`Style`] that gets applied over the background cells, or `None` to leave them untouched.
+    #[must_use]
+    pub const fn overlay(mut self, overlay_style: Option<Style>) -> Self {
+        self.overlay_style = overlay_style;
+
+        self
+    }
+
+    /// Set the [`Style`] of the dialog's frame and background.
+    #[must_use]
+    pub const fn framed(mut self, frame_style: Style) -> Self {
+        self.frame_style = frame_style;
+
+        self
+    }
+
+    /// Returns whether the dialog is still open.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Re-opens the dialog after it has been closed.
+    pub const fn open(&mut self) {
+        self.open = true;
+    }
+
+    /// Closes the dialog. A closed dialog draws nothing and ignores input.
+    pub const fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// The width of the buttons when laid out next to each other.
+    fn buttons_width(&self) -> usize {
+        self.buttons
+            .buttons
+            .iter()
+            .map(|button| button.as_ref().chars().count())
+            .sum()
+    }
+
+    /// Works out where the frame, body and buttons go inside the given [`Rectangle`].
+    fn layout(&self, rect: Rectangle) -> crate::Result<DialogLayout> {
+        let title_width = self.title.chars().count();
+        let body_width = self.body.chars().count();
+        let buttons_width = self.buttons_width();
+
+        let desired_inner = (title_width + 2).max(body_width).max(buttons_width).max(1);
+        let inner_width = desired_inner.min(rect.width().saturating_sub(HORIZONTAL_CHROME));
+
+        if inner_width == 0 || inner_width < buttons_width {
+            return Err(Error::rescale((desired_inner + HORIZONTAL_CHROME, rect.height())));
+        }
+
+        let body_lines = body_width.div_ceil(inner_width).max(1);
+        let has_buttons = !self.buttons.buttons.is_empty();
+
+        // top border + body + (gap + buttons) + bottom border.
+        let height = body_lines + if has_buttons { 4 } else { 2 };
+        let width = inner_width + HORIZONTAL_CHROME;
+
+        if height > rect.height() {
+            return Err(Error::rescale((width, height)));
+        }
+
+        let left = rect.left() + (rect.width() - width) / 2;
+        let top = rect.top() + (rect.height() - height) / 2;
+
+        let frame = Rectangle::of_size((width, height)).at((left, top));
+        let body = Rectangle::of_size((inner_width, body_lines)).at((left + 2, top + 1));
+
+        let buttons = has_buttons.then(|| {
+            let buttons_left = left + (width - buttons_width) / 2;
+
+            Rectangle::of_size((buttons_width, 1)).at((buttons_left, body.bottom() + 1))
+        });
+
+        Ok(DialogLayout { frame, body, buttons })
+    }
+
+    /// Finds the button that covers the specified x-coordinate, relative to the start of the buttons.
+    fn button_at(&self, x: usize) -> Option<usize> {
+        let mut button_right = 0;
+
+        self.buttons.buttons.iter().position(|button| {
+            button_right += button.as_ref().chars().count();
+
+            x < button_right
+        })
+    }
+
+    fn draw_frame(&self, mut terminal: impl Terminal, frame: Rectangle) -> crate::Result<()> {
+        let (left, top) = frame.left_top();
+        let theme = terminal.theme();
+
+        draw_box(&mut terminal, frame, self.frame_style.inherits(theme.border))?;
+
+        // The title sits inside the top border, like "┌─Title───┐".
+        let title_space = frame.width().saturating_sub(HORIZONTAL_CHROME);
+        let title_width = self.title.chars().count().min(title_space);
+
+        if title_width > 0 {
+            let title = Rectangle::of_size((title_width, 1)).at((left + 2, top));
+            let title_view = terminal.view_mut(title).ok_or_else(|| Error::rescale_to(title))?;
+            let truncated = self.title
+                .char_indices()
+                .nth(title_width)
+                .map_or(self.title, |(idx, _)| &self.title[..idx]);
+
+            Text::new(truncated).styled(self.title_style.inherits(theme.title)).drawn(title_view)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Each side of a [`Dialog`] has a border and a one-cell gap between the border and the contents.
+const HORIZONTAL_CHROME: usize = 4;
+
+impl<T: AsRef<str>> Widget for Dialog<'_, T> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        if !self.open {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        match update_info {
+            UpdateInfo::KeyboardInput(keys::LEFT_ARROW, KeyState::KeyDown) => {
+                self.buttons = self.buttons.move_left();
+            }
+            UpdateInfo::KeyboardInput(keys::RIGHT_ARROW | keys::TAB, KeyState::KeyDown) => {
+                self.buttons = self.buttons.move_right();
+            }
+            UpdateInfo::KeyboardInput(keys::ENTER | keys::SPACE, KeyState::KeyDown) => {
+                if let Some(selected) = self.buttons.selected() {
+                    self.open = false;
+
+                    return Ok(UpdateResult::Selected(selected));
+                }
+            }
+            UpdateInfo::KeyboardInput(keys::ESCAPE, KeyState::KeyDown) => {
+                self.open = false;
+
+                return Ok(UpdateResult::LifecycleEnd);
+            }
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) => {
+                let layout = self.layout(terminal.bounding_box())?;
+
+                if let Some(buttons) = layout.buttons {
+                    if buttons.contains((x, y)) {
+                        if let Some(clicked) = self.button_at(x - buttons.left()) {
+                            self.buttons = self.buttons.select(clicked);
+                            self.open = false;
+
+                            return Ok(UpdateResult::Selected(clicked));
+                        }
+                    }
+                }
+            }
+            // Time passing and resizes aren't input, so they aren't trapped.
+            UpdateInfo::TimeDelta(_) | UpdateInfo::TerminalResized | UpdateInfo::NoInfo => {
+                return Ok(UpdateResult::NoEvent);
+            }
+            _ => {}
+        }
+
+        Ok(UpdateResult::Interacted)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        if !self.open {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        let layout = self.layout(terminal.bounding_box())?;
+        let theme = terminal.theme();
+
+        if let Some(overlay_style) = self.overlay_style {
+            let overlay_style = overlay_style.inherits(theme.overlay);
+
+            for cell in terminal.cells_mut() {
+                cell.style = overlay_style.inherits(cell.style);
+            }
+        }
+
+        self.draw_frame(&mut terminal, layout.frame)?;
+
+        let body_view = terminal.view_mut(layout.body).ok_or_else(|| Error::rescale_to(layout.body))?;
+        Text::new(self.body).styled(self.body_style.inherits(theme.text)).drawn(body_view)?;
+
+        if let Some(buttons) = layout.buttons {
+            let buttons_view = terminal.view_mut(buttons).ok_or_else(|| Error::rescale_to(buttons))?;
+            self.buttons.drawn(buttons_view)?;
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl<T: AsRef<str>> BoundingBox for Dialog<'_, T> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(self.layout(rect)?.frame)
+    }
+
+    // The overlay paints every cell, and the frame paints every cell inside of it, but nothing at
+    // all is painted when the dialog is closed.
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        self.open && (self.overlay_style.is_some() || self.layout(rectangle).is_ok_and(|layout| layout.frame.contains_rect(rectangle)))
+    }
+}
@@ -0,0 +1,391 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::{Style, Theme};
+use crate::terminal::{keys, KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// A range of bytes that a [`HexView`] draws with a different [`Style`], like a header or a checksum.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Highlight {
+    /// The index of the first highlighted byte.
+    pub start: usize,
+    /// The index of the byte after the last highlighted byte.
+    pub end: usize,
+    /// The style applied to the highlighted bytes, in both the hex and the ASCII column.
+    pub style: Style,
+}
+
+impl Highlight {
+    /// Create a new [`Highlight`] over the bytes from `start` up to (but not including) `end`.
+    #[must_use]
+    pub const fn new(start: usize, end: usize, style: Style) -> Self {
+        Self { start, end, style }
+    }
+
+    /// Whether the [`Highlight`] covers the byte at the specified index.
+    #[must_use]
+    pub const fn contains(&self, index: usize) -> bool {
+        self.start <= index && index < self.end
+    }
+}
+
+/// A hex dump of a slice of bytes, with an offset column, a hex column and an ASCII column.
+///
+/// ```text
+/// 00000000  48 65 6c 6c 6f 2c 20 77  Hello, w
+/// 00000008  6f 72 6c 64 21           orld!
+/// ```
+///
+/// The [`HexView`] has a cursor that can be moved with the arrow keys, page up/down, home/end or by
+/// clicking on a byte. The byte under the cursor is highlighted in both columns, and the view scrolls
+/// to keep it visible. Pressing enter returns [`UpdateResult::Selected`] with the cursor's index.
+///
+/// Bytes that aren't printable ASCII are shown as [`HexView::placeholder`] in the ASCII column.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Style;
+/// use tuit::terminal::{keys, ConstantSize, KeyState, UpdateInfo};
+/// use tuit::widgets::builtins::hex_view::Highlight;
+/// use tuit::widgets::builtins::HexView;
+///
+/// let mut terminal: ConstantSize<45, 4> = ConstantSize::new();
+/// let highlights = [Highlight::new(0, 5, Style::new().bold())];
+/// let mut hex_view = HexView::new(b"Hello, world!").bytes_per_row(Some(8)).highlights(&highlights);
+///
+/// hex_view.update(UpdateInfo::KeyboardInput(keys::DOWN_ARROW, KeyState::KeyDown), &terminal).expect("Infallible");
+/// hex_view.drawn(&mut terminal).expect("There is enough space");
+///
+/// let row: String = terminal.cells().skip(45).take(45).map(|cell| cell.character).collect();
+///
+/// assert_eq!(hex_view.cursor(), 8);
+/// assert_eq!(row.trim_end(), "00000008  6f 72 6c 64 21           orld!");
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct HexView<'a> {
+    /// The bytes to display.
+    pub data: &'a [u8],
+    /// The address of the first byte, which is added to every offset in the offset column.
+    pub address: usize,
+    /// How many bytes are shown per row, or `None` to fit as many as possible into the terminal.
+    pub bytes_per_row: Option<usize>,
+    /// Ranges of bytes to draw with their own style. When highlights overlap, the last one wins.
+    pub highlights: &'a [Highlight],
+    /// The character shown in the ASCII column for bytes that aren't printable.
+    pub placeholder: char,
+    /// The style of the offset column. Unset properties come from [`Theme::muted`].
+    pub offset_style: Style,
+    /// The style of the hex column. Unset properties come from [`Theme::text`].
+    pub hex_style: Style,
+    /// The style of the ASCII column. Unset properties come from [`Theme::text`].
+    pub ascii_style: Style,
+    /// The style of the byte under the cursor, in both columns. It is applied over any highlights.
+    /// Unset properties come from [`Theme::selected`].
+    pub cursor_style: Style,
+    cursor: usize,
+    scroll: usize,
+}
+
+/// Where each column of a [`HexView`] starts, for a given terminal width.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+struct HexLayout {
+    offset_digits: usize,
+    bytes_per_row: usize,
+    hex_left: usize,
+    ascii_left: usize,
+}
+
+impl HexLayout {
+    /// The total width of a row.
+    const fn width(&self) -> usize {
+        self.ascii_left + self.bytes_per_row
+    }
+
+    /// Finds the byte (relative to the start of its row) that is drawn at the specified x-coordinate.
+    const fn column_at(&self, x: usize) -> Option<usize> {
+        let hex_right = self.hex_left + self.bytes_per_row * 3;
+
+        if x >= self.hex_left && x < hex_right {
+            let relative = x - self.hex_left;
+
+            // The space after each pair of digits doesn't belong to any byte.
+            return if relative % 3 == 2 { None } else { Some(relative / 3) };
+        }
+
+        if x >= self.ascii_left && x < self.ascii_left + self.bytes_per_row {
+            return Some(x - self.ascii_left);
+        }
+
+        None
+    }
+}
+
+impl<'a> HexView<'a> {
+    const HEX_DIGITS: &'static [u8; 16] = b"0123456789abcdef";
+
+    /// The smallest number of digits that the offset column is padded to.
+    const MIN_OFFSET_DIGITS: usize = 8;
+
+    /// Create a new [`HexView`] over the specified bytes, with the cursor on the first byte.
+    #[must_use]
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            address: 0,
+            bytes_per_row: None,
+            highlights: &[],
+            placeholder: '.',
+            offset_style: Style::new(),
+            hex_style: Style::new(),
+            ascii_style: Style::new(),
+            cursor_style: Style::new(),
+            cursor: 0,
+            scroll: 0,
+        }
+    }
+
+    /// Set the address of the first byte.
+    #[must_use]
+    pub const fn address(mut self, address: usize) -> Self {
+        self.address = address;
+
+        self
+    }
+
+    /// Set how many bytes are shown per row, or `None` to fit as many as possible.
+    #[must_use]
+    pub const fn bytes_per_row(mut self, bytes_per_row: Option<usize>) -> Self {
+        self.bytes_per_row = bytes_per_row;
+
+        self
+    }
+
+    /// Set the ranges of bytes that are highlighted.
+    #[must_use]
+    pub const fn highlights(mut self, highlights: &'a [Highlight]) -> Self {
+        self.highlights = highlights;
+
+        self
+    }
+
+    /// Returns the index of the byte under the cursor.
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Move the cursor to the specified byte, clamped to the end of the data.
+    ///
+    /// The view scrolls to the cursor the next time that the [`HexView`] is updated.
+    pub const fn set_cursor(&mut self, index: usize) {
+        let last = self.data.len().saturating_sub(1);
+
+        self.cursor = if index > last { last } else { index };
+    }
+
+    /// Returns the index of the first visible row.
+    #[must_use]
+    pub const fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Scroll so that the specified row is the first visible row.
+    pub const fn scroll_to(&mut self, row: usize) {
+        self.scroll = row;
+    }
+
+    /// Works out the width of each column, fitting the row into the given width if
+    /// [`HexView::bytes_per_row`] is `None`.
+    fn layout(&self, width: usize) -> crate::Result<HexLayout> {
+        let last_address = self.address.saturating_add(self.data.len().saturating_sub(1));
+        let significant_digits = (usize::BITS - last_address.leading_zeros()).div_ceil(4) as usize;
+        let offset_digits = significant_digits.max(Self::MIN_OFFSET_DIGITS);
+
+        // "offset  " + "xx " per byte + " " + one character per byte.
+        let chrome = offset_digits + 3;
+
+        let bytes_per_row = if let Some(bytes_per_row) = self.bytes_per_row {
+            bytes_per_row.max(1)
+        } else {
+            let fits = width.saturating_sub(chrome) / 4;
+
+            if fits == 0 {
+                return Err(Error::rescale((chrome + 4, 1)));
+            }
+
+            // Round down to a power of two, so that the offsets stay nice and round.
+            1 << fits.ilog2()
+        };
+
+        let hex_left = offset_digits + 2;
+        let layout = HexLayout {
+            offset_digits,
+            bytes_per_row,
+            hex_left,
+            ascii_left: hex_left + bytes_per_row * 3 + 1,
+        };
+
+        if layout.width() > width {
+            return Err(Error::rescale((layout.width(), 1)));
+        }
+
+        Ok(layout)
+    }
+
+    /// The number of rows needed to show all the data.
+    const fn rows(&self, layout: &HexLayout) -> usize {
+        self.data.len().div_ceil(layout.bytes_per_row)
+    }
+
+    /// Scrolls the minimum distance to make the row containing the cursor visible.
+    const fn scroll_to_cursor(&mut self, layout: &HexLayout, height: usize) {
+        let cursor_row = self.cursor / layout.bytes_per_row;
+
+        if cursor_row < self.scroll {
+            self.scroll = cursor_row;
+        } else if height > 0 && cursor_row >= self.scroll + height {
+            self.scroll = cursor_row + 1 - height;
+        }
+    }
+
+    /// Fill in the unset properties of each style from its role in the [`Theme`].
+    const fn with_theme(mut self, theme: &Theme) -> Self {
+        self.offset_style = self.offset_style.inherits(theme.muted);
+        self.hex_style = self.hex_style.inherits(theme.text);
+        self.ascii_style = self.ascii_style.inherits(theme.text);
+        self.cursor_style = self.cursor_style.inherits(theme.selected);
+
+        self
+    }
+
+    /// The style of the byte at the specified index, applied over the column's base style.
+    fn byte_style(&self, index: usize, base: Style) -> Style {
+        let highlighted = self
+            .highlights
+            .iter()
+            .rev()
+            .find(|highlight| highlight.contains(index))
+            .map_or(base, |highlight| highlight.style.inherits(base));
+
+        if index == self.cursor {
+            self.cursor_style.inherits(highlighted)
+        } else {
+            highlighted
+        }
+    }
+
+    fn draw_row(&self, mut terminal: impl Terminal, layout: &HexLayout, row: usize, y: usize) -> crate::Result<()> {
+        let mut put = |x: usize, character: char, style: Style| -> crate::Result<()> {
+            let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+
+            cell.character = character;
+            cell.style = style.inherits(cell.style);
+
+            Ok(())
+        };
+
+        let first_byte = row * layout.bytes_per_row;
+        let offset = self.address.saturating_add(first_byte);
+
+        for digit in 0..layout.offset_digits {
+            let shift = (layout.offset_digits - 1 - digit) * 4;
+            let nibble = offset.checked_shr(shift as u32).unwrap_or(0) & 0xF;
+
+            put(digit, char::from(Self::HEX_DIGITS[nibble]), self.offset_style)?;
+        }
+
+        let bytes = self.data.iter().enumerate().skip(first_byte).take(layout.bytes_per_row);
+
+        for (column, (index, &byte)) in bytes.enumerate() {
+            let hex_x = layout.hex_left + column * 3;
+
+            let hex_style = self.byte_style(index, self.hex_style);
+            put(hex_x, char::from(Self::HEX_DIGITS[usize::from(byte >> 4)]), hex_style)?;
+            put(hex_x + 1, char::from(Self::HEX_DIGITS[usize::from(byte & 0xF)]), hex_style)?;
+
+            let character = if byte.is_ascii_graphic() || byte == b' ' { char::from(byte) } else { self.placeholder };
+            put(layout.ascii_left + column, character, self.byte_style(index, self.ascii_style))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Widget for HexView<'_> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let layout = self.layout(terminal.width())?;
+        let height = terminal.height();
+
+        let row = layout.bytes_per_row;
+        let page = row.saturating_mul(height.max(1));
+
+        let cursor = match update_info {
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => match key {
+                keys::LEFT_ARROW => self.cursor.saturating_sub(1),
+                keys::RIGHT_ARROW => self.cursor.saturating_add(1),
+                keys::UP_ARROW => self.cursor.checked_sub(row).unwrap_or(self.cursor),
+                keys::DOWN_ARROW if self.cursor + row < self.data.len() => self.cursor + row,
+                keys::DOWN_ARROW => self.cursor,
+                keys::PAGE_UP => self.cursor.saturating_sub(page),
+                keys::PAGE_DOWN => self.cursor.saturating_add(page),
+                keys::HOME => 0,
+                keys::END => self.data.len(),
+                keys::ENTER if !self.data.is_empty() => return Ok(UpdateResult::Selected(self.cursor)),
+                _ => return Ok(UpdateResult::NoEvent),
+            },
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) => {
+                let clicked = layout
+                    .column_at(x)
+                    .map(|column| (self.scroll + y) * row + column)
+                    .filter(|&index| index < self.data.len());
+
+                match clicked {
+                    Some(index) => index,
+                    None => return Ok(UpdateResult::NoEvent),
+                }
+            }
+            UpdateInfo::TerminalResized => {
+                self.scroll = self.scroll.min(self.rows(&layout).saturating_sub(1));
+                self.scroll_to_cursor(&layout, height);
+
+                return Ok(UpdateResult::NoEvent);
+            }
+            _ => return Ok(UpdateResult::NoEvent),
+        };
+
+        self.set_cursor(cursor);
+        self.scroll_to_cursor(&layout, height);
+
+        Ok(UpdateResult::Interacted)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let layout = self.layout(terminal.width())?;
+        let height = terminal.height();
+
+        for cell in terminal.cells_mut() {
+            cell.character = ' ';
+        }
+
+        let visible_rows = self.rows(&layout).saturating_sub(self.scroll).min(height);
+        let themed = self.with_theme(&terminal.theme());
+
+        for y in 0..visible_rows {
+            themed.draw_row(&mut terminal, &layout, self.scroll + y, y)?;
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for HexView<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        self.layout(rect.width())?;
+
+        Ok(rect)
+    }
+
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        true
+    }
+}
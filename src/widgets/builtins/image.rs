@@ -0,0 +1,300 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::{Colour, ColourDepth, Style};
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// How an [`Image`] is resized to fit inside of its [`Rectangle`].
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+pub enum Scaling {
+    /// Each pixel takes the colour of the closest source pixel. Fast and crisp, which suits pixel art.
+    Nearest,
+    /// Each pixel takes the average colour of the source pixels that it covers. Smoother when
+    /// shrinking photos and heatmaps.
+    #[default]
+    Box,
+}
+
+/// The colours that an [`Image`] is drawn with.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+pub enum Palette {
+    /// Draw the exact colours, using [`Colour::Rgb24`].
+    #[default]
+    Rgb24,
+    /// Quantize to the closest of the 256 ANSI colours, using [`Colour::Ansi256`].
+    Ansi256,
+    /// Quantize to the closest of the 16 ANSI colours, using [`Colour::Ansi16`].
+    Ansi16,
+}
+
+/// An image made of RGB pixels, drawn using upper half-blocks (`▀`) so that each cell shows two
+/// pixels: the top one as its foreground, and the bottom one as its background.
+///
+/// The image is scaled to fit inside the terminal (see [`Image::scaling`]) and centered. By default
+/// it keeps its aspect ratio, since the pixels of a half-block are roughly square.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Colour;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::image::Palette;
+/// use tuit::widgets::builtins::Image;
+/// use tuit::widgets::BoundingBox;
+///
+/// // A 2x2 image: red, green / blue, white.
+/// let pixels = [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+/// let image = Image::new(&pixels, 2, 2);
+///
+/// let mut terminal: ConstantSize<4, 1> = ConstantSize::new();
+/// image.drawn(&mut terminal).expect("The pixel buffer is big enough");
+///
+/// // The two rows of pixels share a single row of cells, and the image is centered.
+/// let cell = terminal.cell(1, 0).expect("Cell exists");
+///
+/// assert_eq!(image.bounding_box(terminal.bounding_box()).expect("Fits").left(), 1);
+/// assert_eq!(cell.character, '▀');
+/// assert_eq!(cell.style.fg_colour, Some(Colour::Rgb24(255, 0, 0)));
+/// assert_eq!(cell.style.bg_colour, Some(Colour::Rgb24(0, 0, 255)));
+///
+/// // Quantized to the 16 ANSI colours instead.
+/// image.palette(Palette::Ansi16).drawn(&mut terminal).expect("The pixel buffer is big enough");
+///
+/// assert!(matches!(terminal.cell(1, 0).expect("Cell exists").style.fg_colour, Some(Colour::Ansi16(_))));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Image<'a> {
+    /// The pixels, row by row, three bytes (red, green, and blue) each.
+    pub pixels: &'a [u8],
+    /// The width of the image, in pixels.
+    pub width: usize,
+    /// The height of the image, in pixels.
+    pub height: usize,
+    /// How the image is resized.
+    pub scaling: Scaling,
+    /// Whether to keep the aspect ratio of the image, or stretch it over the whole terminal.
+    pub preserve_aspect_ratio: bool,
+    /// The colours that the image is drawn with.
+    pub palette: Palette,
+    /// Whether to apply ordered dithering when quantizing to [`Palette::Ansi256`] or [`Palette::Ansi16`].
+    ///
+    /// Dithering trades some noise for smoother gradients.
+    pub dither: bool,
+}
+
+impl<'a> Image<'a> {
+    /// A 4x4 Bayer matrix, used for ordered dithering.
+    const BAYER: [[i32; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+    /// Create a new [`Image`] out of a buffer of RGB pixels.
+    ///
+    /// The buffer must hold at least `width * height * 3` bytes, or drawing the [`Image`] fails.
+    #[must_use]
+    pub const fn new(pixels: &'a [u8], width: usize, height: usize) -> Self {
+        Self {
+            pixels,
+            width,
+            height,
+            scaling: Scaling::Box,
+            preserve_aspect_ratio: true,
+            palette: Palette::Rgb24,
+            dither: false,
+        }
+    }
+
+    /// Set how the [`Image`] is resized.
+    #[must_use]
+    pub const fn scaling(mut self, scaling: Scaling) -> Self {
+        self.scaling = scaling;
+
+        self
+    }
+
+    /// Stretch the [`Image`] over the whole terminal, instead of keeping its aspect ratio.
+    #[must_use]
+    pub const fn stretched(mut self) -> Self {
+        self.preserve_aspect_ratio = false;
+
+        self
+    }
+
+    /// Set the colours that the [`Image`] is drawn with.
+    #[must_use]
+    pub const fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+
+        self
+    }
+
+    /// Enable or disable dithering when quantizing.
+    #[must_use]
+    pub const fn dithered(mut self, dither: bool) -> Self {
+        self.dither = dither;
+
+        self
+    }
+
+    /// The size of the scaled image in pixels, when fit into the specified number of cells.
+    const fn scaled_size(&self, (columns, rows): (usize, usize)) -> (usize, usize) {
+        let (max_width, max_height) = (columns, rows * 2);
+
+        if self.width == 0 || self.height == 0 || max_width == 0 || max_height == 0 {
+            return (0, 0);
+        }
+
+        if !self.preserve_aspect_ratio {
+            return (max_width, max_height);
+        }
+
+        if self.width * max_height <= self.height * max_width {
+            // The height is the limiting side.
+            let width = (self.width * max_height + self.height / 2) / self.height;
+
+            (clamp(width, max_width), max_height)
+        } else {
+            let height = (self.height * max_width + self.width / 2) / self.width;
+
+            (max_width, clamp(height, max_height))
+        }
+    }
+
+    /// Get the colour of the source pixel at the specified coordinates.
+    fn source_pixel(&self, x: usize, y: usize) -> crate::Result<(u8, u8, u8)> {
+        let idx = (y * self.width + x) * 3;
+
+        match self.pixels.get(idx..idx + 3) {
+            Some(&[red, green, blue]) => Ok((red, green, blue)),
+            _ => Err(Error::oobi(idx + 2)),
+        }
+    }
+
+    /// Get the colour of a pixel in the scaled image.
+    fn sample(&self, (x, y): (usize, usize), (scaled_width, scaled_height): (usize, usize)) -> crate::Result<(u8, u8, u8)> {
+        match self.scaling {
+            Scaling::Nearest => {
+                let source_x = ((2 * x + 1) * self.width) / (2 * scaled_width);
+                let source_y = ((2 * y + 1) * self.height) / (2 * scaled_height);
+
+                self.source_pixel(source_x, source_y)
+            }
+            Scaling::Box => {
+                let span = |position: usize, scaled: usize, source: usize| {
+                    let start = position * source / scaled;
+                    let end = ((position + 1) * source).div_ceil(scaled).clamp(start + 1, source);
+
+                    start..end
+                };
+
+                let (mut red, mut green, mut blue, mut count) = (0_usize, 0_usize, 0_usize, 0_usize);
+
+                for source_y in span(y, scaled_height, self.height) {
+                    for source_x in span(x, scaled_width, self.width) {
+                        let (pixel_r, pixel_g, pixel_b) = self.source_pixel(source_x, source_y)?;
+
+                        red += usize::from(pixel_r);
+                        green += usize::from(pixel_g);
+                        blue += usize::from(pixel_b);
+                        count += 1;
+                    }
+                }
+
+                let average = |channel: usize| ((channel + count / 2) / count) as u8;
+
+                Ok((average(red), average(green), average(blue)))
+            }
+        }
+    }
+
+    /// Turn a pixel of the scaled image into a [`Colour`] of the image's [`Palette`].
+    fn quantize(&self, (r, g, b): (u8, u8, u8), position: (usize, usize)) -> Colour {
+        let spread = match self.palette {
+            Palette::Rgb24 => return Colour::Rgb24(r, g, b),
+            Palette::Ansi256 => 40,
+            Palette::Ansi16 => 96,
+        };
+
+        let (r, g, b) = if self.dither {
+            let threshold = Self::BAYER[position.1 % 4][position.0 % 4];
+            let offset = ((threshold * 2 + 1) * spread / 32 - spread / 2) as i8;
+            let shift = |channel: u8| channel.saturating_add_signed(offset);
+
+            (shift(r), shift(g), shift(b))
+        } else {
+            (r, g, b)
+        };
+
+        let depth = match self.palette {
+            Palette::Ansi16 => ColourDepth::Ansi16,
+            _ => ColourDepth::Ansi256,
+        };
+
+        Colour::Rgb24(r, g, b).downsample(depth).unwrap_or(Colour::TerminalDefault)
+    }
+}
+
+/// Like `value.clamp(1, max)`, but `const`.
+const fn clamp(value: usize, max: usize) -> usize {
+    if value < 1 {
+        1
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+impl Widget for Image<'_> {
+    fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let bounds = self.bounding_box(terminal.bounding_box())?;
+        let scaled = self.scaled_size(terminal.bounding_box().dimensions());
+
+        for row in 0..bounds.height() {
+            for column in 0..bounds.width() {
+                let (top_y, bottom_y) = (row * 2, row * 2 + 1);
+
+                let top = self.quantize(self.sample((column, top_y), scaled)?, (column, top_y));
+
+                // An odd number of pixel rows leaves the bottom half of the last row of cells empty.
+                let bottom = if bottom_y < scaled.1 {
+                    Some(self.quantize(self.sample((column, bottom_y), scaled)?, (column, bottom_y)))
+                } else {
+                    None
+                };
+
+                let (x, y) = (bounds.left() + column, bounds.top() + row);
+                let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+
+                cell.character = '▀';
+                cell.style = Style {
+                    fg_colour: Some(top),
+                    bg_colour: bottom,
+                    invert: Some(false),
+                    ..Style::new()
+                }
+                .inherits(cell.style);
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for Image<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let (width, height) = self.scaled_size(rect.dimensions());
+        let rows = height.div_ceil(2);
+
+        let left = rect.left() + (rect.width() - width) / 2;
+        let top = rect.top() + (rect.height() - rows) / 2;
+
+        Ok(Rectangle::of_size((width, rows)).at((left, top)))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        // Unless the image is stretched, there are gaps next to it that depend on the terminal's size.
+        !self.preserve_aspect_ratio && self.width > 0 && self.height > 0 && rectangle.area() > 0
+    }
+}
@@ -1,7 +1,9 @@
+use core::time::Duration;
+
 use crate::Error;
 use crate::prelude::{Metadata, Terminal, TerminalConst};
 use crate::terminal::{Rectangle, UpdateInfo, UpdateResult, View};
-use crate::widgets::{BoundingBox, Widget};
+use crate::widgets::{Animated, BoundingBox, Widget};
 
 /// Add a [`Margin`] to your widgets! Works by expanding the bounding box, meaning that it may fail
 /// to draw in certain cases. Try using [`ShrinkWrap`](crate::widgets::builtins::ShrinkWrap).
@@ -199,4 +201,10 @@ where T: BoundingBox {
 
         self.child.draw(view)
     }
-}
\ No newline at end of file
+}
+
+impl<T: Animated + BoundingBox> Animated for Margin<T> {
+    fn next_tick(&self) -> Option<Duration> {
+        self.child.next_tick()
+    }
+}
@@ -0,0 +1,628 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::Style;
+use crate::terminal::{keys, KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::draw_box;
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// How many levels of dropdowns (the top-level menu plus its nested submenus) a [`MenuBar`] can
+/// have open at once. Submenus that are nested any deeper can't be opened.
+pub const MENU_DEPTH: usize = 4;
+
+/// A single item inside a [`Menu`] that can be activated.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MenuItem<'a> {
+    /// The text displayed for the item.
+    pub label: &'a str,
+    /// The identifier reported through [`UpdateResult::Selected`] when the item is activated.
+    pub id: usize,
+    /// A key that activates the item while its menu is open. It is shown on the right-hand side
+    /// of the item.
+    pub accelerator: Option<char>,
+    /// Whether the item can be highlighted and activated.
+    pub enabled: bool,
+}
+
+impl<'a> MenuItem<'a> {
+    /// Create a new, enabled [`MenuItem`] with the specified label and identifier.
+    #[must_use]
+    pub const fn new(label: &'a str, id: usize) -> Self {
+        Self { label, id, accelerator: None, enabled: true }
+    }
+
+    /// Set the key that activates the item while its menu is open.
+    #[must_use]
+    pub const fn accelerator(mut self, accelerator: char) -> Self {
+        self.accelerator = Some(accelerator);
+
+        self
+    }
+
+    /// Disable the item, so that it is greyed out and can't be activated.
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.enabled = false;
+
+        self
+    }
+}
+
+/// A titled list of [`MenuEntry`]s. Used both for the top-level menus of a [`MenuBar`], and for
+/// nested submenus.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Menu<'a> {
+    /// The title of the menu.
+    pub title: &'a str,
+    /// The entries inside the menu's dropdown.
+    pub entries: &'a [MenuEntry<'a>],
+    /// Whether the menu can be opened.
+    pub enabled: bool,
+}
+
+impl<'a> Menu<'a> {
+    /// Create a new, enabled [`Menu`].
+    #[must_use]
+    pub const fn new(title: &'a str, entries: &'a [MenuEntry<'a>]) -> Self {
+        Self { title, entries, enabled: true }
+    }
+
+    /// Disable the menu, so that it is greyed out and can't be opened.
+    #[must_use]
+    pub const fn disabled(mut self) -> Self {
+        self.enabled = false;
+
+        self
+    }
+}
+
+/// An entry inside of a [`Menu`]'s dropdown.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub enum MenuEntry<'a> {
+    /// An item that can be activated.
+    Item(MenuItem<'a>),
+    /// A nested menu that opens to the side.
+    Submenu(Menu<'a>),
+    /// A horizontal line that separates groups of entries.
+    Separator,
+}
+
+impl MenuEntry<'_> {
+    /// Whether the entry can be highlighted.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        match self {
+            Self::Item(item) => item.enabled,
+            Self::Submenu(menu) => menu.enabled,
+            Self::Separator => false,
+        }
+    }
+
+    /// The number of cells the entry needs, not including the menu's borders.
+    fn width(&self) -> usize {
+        match self {
+            // " label  X "
+            Self::Item(item) => item.label.chars().count() + if item.accelerator.is_some() { 5 } else { 3 },
+            // " label  ▸ "
+            Self::Submenu(menu) => menu.title.chars().count() + 5,
+            Self::Separator => 0,
+        }
+    }
+}
+
+/// A menu bar along the top row of the terminal, with dropdown menus that open over whatever is
+/// already drawn beneath it.
+///
+/// Since the dropdowns are drawn as overlays, the [`MenuBar`] should be drawn last, into the whole
+/// terminal. It can be navigated using the arrow keys (once a menu has been focused using
+/// [`MenuBar::open_menu`] or clicked on), and activating an item makes [`Widget::update`] return
+/// [`UpdateResult::Selected`] with the item's [`MenuItem::id`].
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{keys, ConstantSize, KeyState, UpdateInfo, UpdateResult};
+/// use tuit::widgets::builtins::menu::{Menu, MenuBar, MenuEntry, MenuItem};
+///
+/// const RECENT: &[MenuEntry] = &[MenuEntry::Item(MenuItem::new("notes.txt", 10))];
+/// const FILE: &[MenuEntry] = &[
+///     MenuEntry::Item(MenuItem::new("Open", 1).accelerator('o')),
+///     MenuEntry::Submenu(Menu::new("Recent", RECENT)),
+///     MenuEntry::Separator,
+///     MenuEntry::Item(MenuItem::new("Quit", 2).accelerator('q')),
+/// ];
+/// const MENUS: &[Menu] = &[Menu::new("File", FILE), Menu::new("Edit", &[])];
+///
+/// let mut terminal: ConstantSize<40, 10> = ConstantSize::new();
+/// let mut menu_bar = MenuBar::new(MENUS);
+///
+/// menu_bar.open_menu(0);
+/// menu_bar.drawn(&mut terminal).expect("There is enough space");
+///
+/// let down = UpdateInfo::KeyboardInput(keys::DOWN_ARROW, KeyState::KeyDown);
+/// let right = UpdateInfo::KeyboardInput(keys::RIGHT_ARROW, KeyState::KeyDown);
+/// let enter = UpdateInfo::KeyboardInput(keys::ENTER, KeyState::KeyDown);
+///
+/// menu_bar.update(down, &terminal).expect("Infallible");
+/// menu_bar.update(right, &terminal).expect("Infallible");
+///
+/// assert_eq!(menu_bar.update(enter, &terminal).expect("Infallible"), UpdateResult::Selected(10));
+/// assert!(!menu_bar.is_open());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MenuBar<'a> {
+    /// The top-level menus, from left to right.
+    pub menus: &'a [Menu<'a>],
+    /// The style of the bar along the top of the terminal. Unset properties come from [`Theme::primary`](crate::style::Theme::primary).
+    pub bar_style: Style,
+    /// The style of the dropdowns. Unset properties come from [`Theme::border`](crate::style::Theme::border) for their frames,
+    /// and from [`Theme::text`](crate::style::Theme::text) for their entries.
+    pub menu_style: Style,
+    /// The style applied over the focused menu title and the highlighted entries. Unset properties
+    /// come from [`Theme::selected`](crate::style::Theme::selected).
+    pub selected_style: Style,
+    /// The style applied over disabled menus and entries. Unset properties come from [`Theme::disabled`](crate::style::Theme::disabled).
+    pub disabled_style: Style,
+    focused: Option<usize>,
+    path: [usize; MENU_DEPTH],
+    depth: usize,
+}
+
+impl<'a> MenuBar<'a> {
+    /// Create a new [`MenuBar`] with no menus open.
+    #[must_use]
+    pub const fn new(menus: &'a [Menu<'a>]) -> Self {
+        Self {
+            menus,
+            bar_style: Style::new(),
+            menu_style: Style::new(),
+            selected_style: Style::new(),
+            disabled_style: Style::new(),
+            focused: None,
+            path: [0; MENU_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Returns the index of the focused top-level menu, if there is one.
+    #[must_use]
+    pub const fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Returns whether any dropdown is open.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.depth > 0
+    }
+
+    /// Focus the top-level menu at the specified index and open its dropdown.
+    ///
+    /// Does nothing if there is no such menu, or if it is disabled.
+    pub fn open_menu(&mut self, menu: usize) {
+        if !self.menus.get(menu).is_some_and(|menu| menu.enabled) {
+            return;
+        }
+
+        self.focused = Some(menu);
+        self.depth = 0;
+        self.open_level(0);
+    }
+
+    /// Close every dropdown and remove focus from the bar.
+    pub const fn close(&mut self) {
+        self.focused = None;
+        self.depth = 0;
+    }
+
+    /// The entries of the dropdown at the specified level, if it is open.
+    fn level_entries(&self, level: usize) -> Option<&'a [MenuEntry<'a>]> {
+        let mut entries = self.menus.get(self.focused?)?.entries;
+
+        for &parent in &self.path[..level] {
+            let MenuEntry::Submenu(submenu) = entries.get(parent)? else {
+                return None;
+            };
+
+            entries = submenu.entries;
+        }
+
+        Some(entries)
+    }
+
+    /// The highlighted entry of the innermost open dropdown.
+    fn highlighted(&self) -> Option<&'a MenuEntry<'a>> {
+        let level = self.depth.checked_sub(1)?;
+
+        self.level_entries(level)?.get(self.path[level])
+    }
+
+    /// Open the dropdown at the specified level (which must be directly below the innermost open
+    /// dropdown) and highlight its first enabled entry.
+    fn open_level(&mut self, level: usize) {
+        if level >= MENU_DEPTH {
+            return;
+        }
+
+        self.depth = level + 1;
+
+        let Some(entries) = self.level_entries(level) else {
+            self.depth = level;
+            return;
+        };
+
+        self.path[level] = entries.iter().position(MenuEntry::is_enabled).unwrap_or(0);
+    }
+
+    /// Move the highlight within the innermost open dropdown, skipping over separators and disabled
+    /// entries.
+    fn move_highlight(&mut self, forwards: bool) {
+        let Some(level) = self.depth.checked_sub(1) else {
+            return;
+        };
+
+        let Some(entries) = self.level_entries(level) else {
+            return;
+        };
+
+        let len = entries.len();
+        let current = self.path[level];
+
+        for step in 1..=len {
+            let candidate = if forwards {
+                (current + step) % len
+            } else {
+                (current + len - step % len) % len
+            };
+
+            if entries[candidate].is_enabled() {
+                self.path[level] = candidate;
+                return;
+            }
+        }
+    }
+
+    /// Focus the next (or previous) enabled top-level menu. Keeps the dropdown open if it was open.
+    fn move_focus(&mut self, forwards: bool) {
+        let len = self.menus.len();
+
+        let Some(current) = self.focused else {
+            return;
+        };
+
+        let was_open = self.is_open();
+
+        for step in 1..=len {
+            let candidate = if forwards {
+                (current + step) % len
+            } else {
+                (current + len - step % len) % len
+            };
+
+            if self.menus[candidate].enabled {
+                self.focused = Some(candidate);
+                self.depth = 0;
+
+                if was_open {
+                    self.open_level(0);
+                }
+
+                return;
+            }
+        }
+    }
+
+    /// Activate the highlighted entry of the innermost open dropdown.
+    fn activate(&mut self) -> UpdateResult {
+        match self.highlighted() {
+            Some(MenuEntry::Item(item)) if item.enabled => {
+                self.close();
+
+                UpdateResult::Selected(item.id)
+            }
+            Some(MenuEntry::Submenu(menu)) if menu.enabled => {
+                self.open_level(self.depth);
+
+                UpdateResult::Interacted
+            }
+            _ => UpdateResult::Interacted,
+        }
+    }
+
+    /// The areas of the bar covered by each top-level menu's title.
+    fn title_rects(&self, bounds: Rectangle) -> impl Iterator<Item = Rectangle> + 'a {
+        let mut left = bounds.left();
+        let top = bounds.top();
+
+        self.menus.iter().map(move |menu| {
+            let rect = Rectangle::of_size((menu.title.chars().count() + 2, 1)).at((left, top));
+
+            left = rect.right();
+
+            rect
+        })
+    }
+
+    /// The areas covered by each of the open dropdowns, from the outermost to the innermost.
+    fn dropdown_rects(&self, bounds: Rectangle) -> crate::Result<[Option<Rectangle>; MENU_DEPTH]> {
+        let mut rects = [None; MENU_DEPTH];
+
+        let Some(focused) = self.focused else {
+            return Ok(rects);
+        };
+
+        let Some(title) = self.title_rects(bounds).nth(focused) else {
+            return Ok(rects);
+        };
+
+        // The top-level dropdown opens underneath its title, and each submenu opens to the right of
+        // the entry that it belongs to.
+        let mut anchor = title.left_bottom();
+
+        for (level, rect) in rects.iter_mut().enumerate().take(self.depth) {
+            let entries = self.level_entries(level).ok_or(Error::oob())?;
+            let width = entries.iter().map(MenuEntry::width).max().unwrap_or(0) + 2;
+            let height = entries.len() + 2;
+
+            if width > bounds.width() || height > bounds.height() {
+                return Err(Error::rescale((width, height)));
+            }
+
+            // Push the dropdown back inside of the terminal if it would spill out of it.
+            let left = anchor.0.min(bounds.right() - width);
+            let top = anchor.1.min(bounds.bottom() - height);
+
+            let dropdown = Rectangle::of_size((width, height)).at((left, top));
+
+            anchor = (dropdown.right(), dropdown.top() + self.path[level]);
+            *rect = Some(dropdown);
+        }
+
+        Ok(rects)
+    }
+
+    fn on_click(&mut self, x: usize, y: usize, bounds: Rectangle) -> crate::Result<UpdateResult> {
+        if let Some(clicked) = self.title_rects(bounds).position(|title| title.contains((x, y))) {
+            if self.focused == Some(clicked) && self.is_open() {
+                self.close();
+            } else {
+                self.open_menu(clicked);
+            }
+
+            return Ok(UpdateResult::Interacted);
+        }
+
+        let dropdowns = self.dropdown_rects(bounds)?;
+
+        for level in (0..self.depth).rev() {
+            let Some(dropdown) = dropdowns[level] else {
+                continue;
+            };
+
+            if !dropdown.contains((x, y)) {
+                continue;
+            }
+
+            let entries = self.level_entries(level).ok_or(Error::oob())?;
+            let row = (y - dropdown.top()).checked_sub(1);
+
+            if let Some(row) = row.filter(|row| entries.get(*row).is_some_and(MenuEntry::is_enabled)) {
+                self.path[level] = row;
+                self.depth = level + 1;
+
+                return Ok(self.activate());
+            }
+
+            return Ok(UpdateResult::Interacted);
+        }
+
+        // Clicking anywhere else closes the menus.
+        if self.is_open() {
+            self.close();
+
+            return Ok(UpdateResult::Interacted);
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn on_key(&mut self, key: u8) -> UpdateResult {
+        if self.focused.is_none() {
+            return UpdateResult::NoEvent;
+        }
+
+        match (key, self.is_open()) {
+            (keys::LEFT_ARROW, false) => self.move_focus(false),
+            (keys::RIGHT_ARROW, false) => self.move_focus(true),
+            (keys::ENTER | keys::SPACE | keys::DOWN_ARROW, false) => self.open_level(0),
+            (keys::ESCAPE, false) => self.close(),
+            (keys::UP_ARROW, true) => self.move_highlight(false),
+            (keys::DOWN_ARROW, true) => self.move_highlight(true),
+            (keys::RIGHT_ARROW, true) => {
+                if matches!(self.highlighted(), Some(MenuEntry::Submenu(menu)) if menu.enabled) && self.depth < MENU_DEPTH {
+                    self.open_level(self.depth);
+                } else {
+                    self.move_focus(true);
+                }
+            }
+            (keys::LEFT_ARROW, true) => {
+                if self.depth > 1 {
+                    self.depth -= 1;
+                } else {
+                    self.move_focus(false);
+                }
+            }
+            (keys::ENTER | keys::SPACE, true) => return self.activate(),
+            (keys::ESCAPE, true) => self.depth -= 1,
+            _ => return UpdateResult::NoEvent,
+        }
+
+        UpdateResult::Interacted
+    }
+
+    fn on_character(&mut self, character: char) -> UpdateResult {
+        let Some(level) = self.depth.checked_sub(1) else {
+            return UpdateResult::NoEvent;
+        };
+
+        let Some(entries) = self.level_entries(level) else {
+            return UpdateResult::NoEvent;
+        };
+
+        let accelerated = entries.iter().position(|entry| match entry {
+            MenuEntry::Item(item) => item.enabled
+                && item.accelerator.is_some_and(|accelerator| accelerator.eq_ignore_ascii_case(&character)),
+            _ => false,
+        });
+
+        let Some(accelerated) = accelerated else {
+            return UpdateResult::NoEvent;
+        };
+
+        self.path[level] = accelerated;
+
+        self.activate()
+    }
+
+    fn draw_bar(&self, mut terminal: impl Terminal, bounds: Rectangle) -> crate::Result<()> {
+        let theme = terminal.theme();
+        let bar_style = self.bar_style.inherits(theme.primary);
+        let selected_style = self.selected_style.inherits(theme.selected);
+        let disabled_style = self.disabled_style.inherits(theme.disabled);
+
+        for x in bounds.left()..bounds.right() {
+            let cell = terminal.cell_mut(x, bounds.top()).ok_or_else(|| Error::oob_with((x, bounds.top())))?;
+
+            cell.character = ' ';
+            cell.style = bar_style;
+        }
+
+        for (idx, (menu, title)) in self.menus.iter().zip(self.title_rects(bounds)).enumerate() {
+            let style = if self.focused == Some(idx) {
+                selected_style.inherits(bar_style)
+            } else if menu.enabled {
+                bar_style
+            } else {
+                disabled_style.inherits(bar_style)
+            };
+
+            put_str(&mut terminal, (title.left() + 1, title.top()), menu.title, style, bounds.right());
+        }
+
+        Ok(())
+    }
+
+    fn draw_dropdown(&self, mut terminal: impl Terminal, level: usize, dropdown: Rectangle) -> crate::Result<()> {
+        let entries = self.level_entries(level).ok_or(Error::oob())?;
+        let (left, right) = (dropdown.left(), dropdown.right() - 1);
+
+        let theme = terminal.theme();
+        let menu_style = self.menu_style.inherits(theme.text);
+        let selected_style = self.selected_style.inherits(theme.selected);
+        let disabled_style = self.disabled_style.inherits(theme.disabled);
+
+        draw_box(&mut terminal, dropdown, self.menu_style.inherits(theme.border))?;
+
+        for (row, entry) in entries.iter().enumerate() {
+            let y = dropdown.top() + 1 + row;
+
+            let style = if self.path[level] == row && entry.is_enabled() {
+                selected_style.inherits(menu_style)
+            } else if entry.is_enabled() {
+                menu_style
+            } else {
+                disabled_style.inherits(menu_style)
+            };
+
+            let (label, suffix) = match entry {
+                MenuEntry::Item(item) => (item.label, item.accelerator),
+                MenuEntry::Submenu(menu) => (menu.title, Some('▸')),
+                MenuEntry::Separator => {
+                    for x in left..=right {
+                        let character = match x {
+                            x if x == left => '├',
+                            x if x == right => '┤',
+                            _ => '─',
+                        };
+
+                        terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?.character = character;
+                    }
+
+                    continue;
+                }
+            };
+
+            for x in left + 1..right {
+                terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?.style = style;
+            }
+
+            put_str(&mut terminal, (left + 2, y), label, style, right);
+
+            if let Some(suffix) = suffix {
+                let cell = terminal.cell_mut(right - 2, y).ok_or_else(|| Error::oob_with((right - 2, y)))?;
+
+                cell.character = suffix;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the string into a single row, starting at the specified coordinates and stopping before
+/// the `end` x-coordinate.
+fn put_str(mut terminal: impl Terminal, (x, y): (usize, usize), text: &str, style: Style, end: usize) {
+    for (x, character) in (x..end).zip(text.chars()) {
+        if let Some(cell) = terminal.cell_mut(x, y) {
+            cell.character = character;
+            cell.style = style;
+        }
+    }
+}
+
+impl Widget for MenuBar<'_> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        match update_info {
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) => self.on_click(x, y, terminal.bounding_box()),
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown) => Ok(self.on_key(key)),
+            UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown) => Ok(self.on_character(character)),
+            _ => Ok(UpdateResult::NoEvent),
+        }
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let bounds = terminal.bounding_box();
+
+        if bounds.height() == 0 {
+            return Err(Error::rescale((bounds.width(), 1)));
+        }
+
+        self.draw_bar(&mut terminal, bounds)?;
+
+        let dropdowns = self.dropdown_rects(bounds)?;
+
+        for (level, dropdown) in dropdowns.iter().enumerate() {
+            if let Some(dropdown) = dropdown {
+                self.draw_dropdown(&mut terminal, level, *dropdown)?;
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for MenuBar<'_> {
+    // Only the bar itself is reported; the dropdowns are overlays that get drawn over other widgets.
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        if rect.height() == 0 {
+            return Err(Error::rescale((rect.width(), 1)));
+        }
+
+        Ok(Rectangle::of_size((rect.width(), 1)).at(rect.left_top()))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        rectangle.height() <= 1
+    }
+}
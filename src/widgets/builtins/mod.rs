@@ -9,8 +9,19 @@ pub use shelved::Shelved;
 pub use buttons::Buttons;
 pub use shrink_wrap::ShrinkWrap;
 pub use backdrop::Backdrop;
-use crate::style::{Colour, Style};
+pub use spinner::Spinner;
+pub use dialog::Dialog;
+pub use menu::MenuBar;
+pub use toasts::Toasts;
+pub use hex_view::HexView;
+pub use banner::Banner;
+pub use image::Image;
+pub use themed::Themed;
+use crate::prelude::{Terminal, Widget};
+use crate::style::{Colour, Style, Theme};
+use crate::terminal::Rectangle;
 use crate::widgets::BoundingBox;
+use crate::Error;
 
 /// The code for the [`Sweeper`] widget.
 pub mod sweeper;
@@ -38,6 +49,22 @@ pub mod backdrop;
 pub mod shelved;
 /// The code for the [`Checkbox`] widget.
 pub mod checkbox;
+/// The code for the [`Spinner`] widget.
+pub mod spinner;
+/// The code for the [`Dialog`] widget.
+pub mod dialog;
+/// The code for the [`MenuBar`] widget, and the [`menu::Menu`]s inside it.
+pub mod menu;
+/// The code for the [`Toasts`] widget.
+pub mod toasts;
+/// The code for the [`HexView`] widget.
+pub mod hex_view;
+/// The code for the [`Banner`] widget, and the [`banner::Font`]s it draws with.
+pub mod banner;
+/// The code for the [`Image`] widget.
+pub mod image;
+/// The code for the [`Themed`] widget.
+pub mod themed;
 
 impl<T: BoundingBox> From<T> for Centered<T> {
     fn from(value: T) -> Self {
@@ -89,8 +116,8 @@ pub trait WithLayout: Sized {
     /// overflow_text.with_margin(-2).drawn(&mut terminal).expect_err("Failed to render");
     /// ```
     ///
-    /// The reason for this is that when you set the margin to a negative value, the [`Widget`](crate::widgets::Widget)
-    /// will try and expand out into space that doesn't exist (below zero). When the [`Widget`](crate::widgets::Widget)
+    /// The reason for this is that when you set the margin to a negative value, the [`Widget`]
+    /// will try and expand out into space that doesn't exist (below zero). When the [`Widget`]
     /// is [`Centered`], there is space around it that allows it to expand.
     ///
     /// TODO: Include diagram!
@@ -120,6 +147,42 @@ pub trait WithLayout: Sized {
     fn next_to<T>(self, other: T) -> Shelved<Self, T> {
         Shelved::new(self, other)
     }
+
+    /// Draws the widget (and everything inside of it) with the specified [`Theme`].
+    fn themed(self, theme: Theme) -> Themed<Self> {
+        Themed::new(self, theme)
+    }
 }
 
 impl<T: BoundingBox> WithLayout for T {}
+
+/// Fills the [`Rectangle`] with blank cells of the specified [`Style`], and draws a single-line box
+/// around its edges. Used by the widgets that draw framed overlays, like [`Dialog`] and [`MenuBar`].
+pub(crate) fn draw_box(mut terminal: impl Terminal, rect: Rectangle, style: Style) -> crate::Result<()> {
+    let (left, top) = rect.left_top();
+    let (right, bottom) = (rect.right() - 1, rect.bottom() - 1);
+
+    let view = terminal.view_mut(rect).ok_or_else(|| Error::rescale_to(rect))?;
+    Sweeper::new(style).drawn(view)?;
+
+    let mut set = |x: usize, y: usize, character: char| -> crate::Result<()> {
+        terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?.character = character;
+
+        Ok(())
+    };
+
+    for x in left + 1..right {
+        set(x, top, '─')?;
+        set(x, bottom, '─')?;
+    }
+
+    for y in top + 1..bottom {
+        set(left, y, '│')?;
+        set(right, y, '│')?;
+    }
+
+    set(left, top, '┌')?;
+    set(right, top, '┐')?;
+    set(left, bottom, '└')?;
+    set(right, bottom, '┘')
+}
@@ -1,7 +1,9 @@
+use core::time::Duration;
+
 use crate::Error;
 use crate::prelude::{Terminal, TerminalConst, Widget};
 use crate::terminal::{Metadata, Rectangle, UpdateInfo, UpdateResult, View};
-use crate::widgets::BoundingBox;
+use crate::widgets::{Animated, BoundingBox};
 
 /// Adds padding to a [`BoundingBox`] widget by pretending that the terminal is smaller than it actually is
 pub struct ShrinkWrap<T> {
@@ -85,4 +87,10 @@ impl<T: Widget> BoundingBox for ShrinkWrap<T> {
     fn completely_covers(&self, _rectangle: Rectangle) -> bool {
         false
     }
-}
\ No newline at end of file
+}
+
+impl<T: Animated> Animated for ShrinkWrap<T> {
+    fn next_tick(&self) -> Option<Duration> {
+        self.child.next_tick()
+    }
+}
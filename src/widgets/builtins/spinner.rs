@@ -0,0 +1,212 @@
+use core::time::Duration;
+
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::Style;
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::{Animated, BoundingBox};
+use crate::Error;
+
+/// An animated spinner (or throbber) that advances a frame every time its interval elapses.
+///
+/// Time is measured by accumulating the [`UpdateInfo::TimeDelta`]s that the [`Spinner`] receives,
+/// so it will stand still if you never send it any.
+///
+/// ```
+/// use core::time::Duration;
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, UpdateInfo};
+/// use tuit::widgets::builtins::Spinner;
+///
+/// let mut terminal: ConstantSize<5, 1> = ConstantSize::new();
+/// let mut spinner = Spinner::line().interval(Duration::from_millis(100));
+///
+/// spinner.update(UpdateInfo::TimeDelta(Duration::from_millis(250)), &terminal).expect("Infallible");
+/// spinner.drawn(&mut terminal).expect("There is enough space");
+///
+/// assert_eq!(spinner.frame_index(), 2);
+/// assert_eq!(terminal.cell(0, 0).expect("Cell exists").character, '|');
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Spinner<'a> {
+    /// The frames that the spinner cycles through.
+    pub frames: &'a [&'a str],
+    /// How long each frame is shown for.
+    pub interval: Duration,
+    /// The style with which to display the frames. Unset properties come from [`Theme::accent`](crate::style::Theme::accent).
+    pub style: Style,
+    frame: usize,
+    elapsed: Duration,
+}
+
+impl<'a> Spinner<'a> {
+    /// Braille dots circling clockwise: `⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`
+    pub const BRAILLE: &'static [&'static str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+    /// The classic ASCII line spinner: `-\|/`
+    pub const LINE: &'static [&'static str] = &["-", "\\", "|", "/"];
+    /// Trailing dots, like a loading ellipsis.
+    pub const DOTS: &'static [&'static str] = &["   ", ".  ", ".. ", "..."];
+
+    /// The interval used by the builtin frame sets, unless you choose another one using [`Spinner::interval`].
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(80);
+
+    /// Create a new [`Spinner`] that cycles through a custom set of frames.
+    ///
+    /// An empty slice of frames is allowed, but the [`Spinner`] will not draw anything.
+    #[must_use]
+    pub const fn new(frames: &'a [&'a str]) -> Self {
+        Self {
+            frames,
+            interval: Self::DEFAULT_INTERVAL,
+            style: Style::new(),
+            frame: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Create a new [`Spinner`] using the [`Spinner::BRAILLE`] frames.
+    #[must_use]
+    pub const fn braille() -> Self {
+        Self::new(Self::BRAILLE)
+    }
+
+    /// Create a new [`Spinner`] using the [`Spinner::LINE`] frames.
+    #[must_use]
+    pub const fn line() -> Self {
+        Self::new(Self::LINE)
+    }
+
+    /// Create a new [`Spinner`] using the [`Spinner::DOTS`] frames.
+    #[must_use]
+    pub const fn dots() -> Self {
+        Self::new(Self::DOTS)
+    }
+
+    /// Set how long each frame is shown for.
+    #[must_use]
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Apply a [`Style`] to the [`Spinner`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Returns the index of the frame that is currently displayed.
+    #[must_use]
+    pub const fn frame_index(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns the frame that is currently displayed, or `None` if the [`Spinner`] has no frames.
+    #[must_use]
+    pub fn current_frame(&self) -> Option<&'a str> {
+        self.frames.get(self.frame).copied()
+    }
+
+    /// Rewinds the [`Spinner`] back to its first frame.
+    pub const fn reset(&mut self) {
+        self.frame = 0;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Advance the [`Spinner`] by the specified amount of time, moving it forward by as many frames
+    /// as have elapsed.
+    ///
+    /// Returns `true` if the displayed frame has changed.
+    pub fn advance(&mut self, delta: Duration) -> bool {
+        if self.frames.is_empty() || self.interval.is_zero() {
+            return false;
+        }
+
+        self.elapsed += delta;
+
+        let interval = self.interval.as_nanos();
+        let elapsed = self.elapsed.as_nanos();
+
+        let frames_passed = elapsed / interval;
+
+        if frames_passed == 0 {
+            return false;
+        }
+
+        // The remainder is always smaller than `interval`, which fits in a `Duration`.
+        self.elapsed = Duration::from_nanos((elapsed % interval) as u64);
+
+        let previous = self.frame;
+        self.frame = ((self.frame as u128 + frames_passed) % self.frames.len() as u128) as usize;
+
+        previous != self.frame
+    }
+
+    /// The width of the widest frame, in characters.
+    fn widest_frame(&self) -> usize {
+        self.frames
+            .iter()
+            .map(|frame| frame.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Widget for Spinner<'_> {
+    fn update(&mut self, update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        if let UpdateInfo::TimeDelta(delta) = update_info {
+            self.advance(delta);
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let Some(frame) = self.current_frame() else {
+            return Ok(UpdateResult::NoEvent);
+        };
+
+        let theme = terminal.theme();
+        let mut cells = terminal.cells_mut();
+        let mut characters = frame.chars();
+
+        // Pad shorter frames out to the widest frame, so that a previous (longer) frame does not
+        // leave any characters behind.
+        for idx in 0..self.widest_frame() {
+            let current_cell = cells.next().ok_or(Error::OutOfBoundsIndex(idx))?;
+
+            current_cell.character = characters.next().unwrap_or(' ');
+            current_cell.style = self.style.inherits(theme.accent).inherits(current_cell.style);
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for Spinner<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let width = self.widest_frame();
+
+        if width > rect.width() || rect.height() == 0 {
+            return Err(Error::rescale((width, 1)));
+        }
+
+        Ok(Rectangle::of_size((width, 1)))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        rectangle.height() <= 1 && self.widest_frame() >= rectangle.width()
+    }
+}
+
+impl Animated for Spinner<'_> {
+    fn next_tick(&self) -> Option<Duration> {
+        if self.frames.len() < 2 || self.interval.is_zero() {
+            return None;
+        }
+
+        Some(self.interval.saturating_sub(self.elapsed))
+    }
+}
@@ -6,7 +6,7 @@ use crate::widgets::BoundingBox;
 /// A widget that will clear the entire terminal and replace it with a blank cell containing
 /// the specified style.
 pub struct Sweeper {
-    /// The style to use for the blank cells that clear the terminal.
+    /// The style to use for the blank cells that clear the terminal. Unset properties come from [`Theme::text`](crate::style::Theme::text).
     pub style: Style,
 }
 
@@ -39,8 +39,10 @@ impl Widget for Sweeper {
         &self,
         mut terminal: impl Terminal,
     ) -> crate::Result<UpdateResult> {
+        let style = self.style.inherits(terminal.theme().text);
+
         for character in terminal.cells_mut() {
-            character.style = self.style;
+            character.style = style;
             character.character = ' ';
         }
 
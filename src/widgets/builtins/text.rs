@@ -2,7 +2,7 @@ use crate::prelude::Terminal;
 use crate::prelude::TerminalConst;
 use crate::prelude::Widget;
 use crate::style::Style;
-use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::terminal::{Hyperlink, Rectangle, UpdateInfo, UpdateResult};
 use crate::widgets::BoundingBox;
 use crate::Error;
 
@@ -10,8 +10,10 @@ use crate::Error;
 pub struct Text<'a> {
     /// The text to display.
     pub text: &'a str,
-    /// The style with which to display it.
+    /// The style with which to display it. Unset properties come from [`Theme::text`](crate::style::Theme::text).
     pub style: Style,
+    /// The hyperlink that the text points to. Drawing the text replaces the hyperlinks of the cells it covers.
+    pub hyperlink: Option<Hyperlink>,
 }
 
 impl<'a> Text<'a> {
@@ -51,6 +53,7 @@ impl<'a> Text<'a> {
         Self {
             text,
             style: Style::new(),
+            hyperlink: None,
         }
     }
 
@@ -72,6 +75,27 @@ impl<'a> Text<'a> {
 
         self
     }
+
+    /// Make the [`Text`] a hyperlink to the specified [`Hyperlink`].
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::{ConstantSize, Hyperlink};
+    /// use tuit::widgets::builtins::Text;
+    ///
+    /// let mut terminal: ConstantSize<20, 1> = ConstantSize::new();
+    /// let link = Hyperlink::new("file:///var/log/syslog");
+    ///
+    /// Text::new("/var/log/syslog").linked(link).drawn(&mut terminal).expect("There is enough space");
+    ///
+    /// assert_eq!(terminal.cell(0, 0).expect("Cell exists").hyperlink, Some(link));
+    /// ```
+    #[must_use]
+    pub const fn linked(mut self, hyperlink: Hyperlink) -> Self {
+        self.hyperlink = Some(hyperlink);
+
+        self
+    }
 }
 
 impl Widget for Text<'_> {
@@ -87,13 +111,15 @@ impl Widget for Text<'_> {
         &self,
         mut terminal: impl Terminal,
     ) -> crate::Result<UpdateResult> {
+        let theme = terminal.theme();
         let mut cells = terminal.cells_mut();
 
         for (idx, character) in self.text.chars().enumerate() {
             let current_cell = cells.next().ok_or(Error::OutOfBoundsIndex(idx))?;
 
             current_cell.character = character;
-            current_cell.style = self.style.inherits(current_cell.style);
+            current_cell.style = self.style.inherits(theme.text).inherits(current_cell.style);
+            current_cell.hyperlink = self.hyperlink;
         }
 
         Ok(UpdateResult::NoEvent)
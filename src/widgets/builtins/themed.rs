@@ -0,0 +1,90 @@
+use core::time::Duration;
+
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::Theme;
+use crate::terminal::{Rectangle, ThemeOverride, UpdateInfo, UpdateResult};
+use crate::widgets::{Animated, BoundingBox};
+
+/// A widget that changes the [`Theme`] that its child (and everything inside of it) is drawn with.
+///
+/// Wrap your whole UI in a [`Themed`] to change its theme, or wrap only a part of it to override
+/// the theme there. Themes can be nested; the innermost one wins.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::{Ansi4, Style, Theme};
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::Buttons;
+///
+/// let theme = Theme {
+///     selected: Style::new().fg_ansi4(Ansi4::Black).bg_ansi4(Ansi4::Cyan),
+///     ..Theme::DEFAULT
+/// };
+///
+/// let mut terminal: ConstantSize<20, 1> = ConstantSize::new();
+/// let buttons = Buttons::new(&["Ok", "Cancel"]).select(0);
+///
+/// buttons.themed(theme).drawn(&mut terminal).expect("There is enough space");
+///
+/// let cell = terminal.cell(0, 0).expect("Cell exists");
+///
+/// assert_eq!(cell.style.bg_colour, Some(tuit::style::Colour::Ansi16(Ansi4::Cyan)));
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct Themed<T> {
+    child: T,
+    /// The theme that the child is drawn with.
+    pub theme: Theme,
+}
+
+impl<T> Themed<T> {
+    /// Make a new [`Themed`] widget.
+    #[must_use]
+    pub const fn new(child: T, theme: Theme) -> Self {
+        Self { child, theme }
+    }
+
+    /// Consume the [`Themed`] widget and return the inner widget.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.child
+    }
+
+    /// Get a reference to the inner widget.
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.child
+    }
+
+    /// Get a mutable reference to the inner widget.
+    #[must_use]
+    pub const fn inner_mut(&mut self) -> &mut T {
+        &mut self.child
+    }
+}
+
+impl<T: Widget> Widget for Themed<T> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        self.child.update(update_info, ThemeOverride::new(terminal, self.theme))
+    }
+
+    fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        self.child.draw(ThemeOverride::new(terminal, self.theme))
+    }
+}
+
+impl<T: BoundingBox> BoundingBox for Themed<T> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        self.child.bounding_box(rect)
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        self.child.completely_covers(rectangle)
+    }
+}
+
+impl<T: Animated> Animated for Themed<T> {
+    fn next_tick(&self) -> Option<Duration> {
+        self.child.next_tick()
+    }
+}
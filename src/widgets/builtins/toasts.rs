@@ -0,0 +1,424 @@
+use core::time::Duration;
+
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::{Style, Theme};
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::{Animated, BoundingBox};
+use crate::Error;
+
+/// How important a [`Toast`] is. Each severity is drawn with its own [`Style`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Debug, Default)]
+pub enum Severity {
+    /// Something happened that the user might want to know about.
+    #[default]
+    Info,
+    /// Something finished successfully.
+    Success,
+    /// Something might need the user's attention.
+    Warning,
+    /// Something went wrong.
+    Error,
+}
+
+/// The corner of the terminal that [`Toasts`] are stacked in.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+pub enum Corner {
+    /// The top-left corner of the terminal.
+    TopLeft,
+    /// The top-right corner of the terminal.
+    TopRight,
+    /// The bottom-left corner of the terminal.
+    BottomLeft,
+    /// The bottom-right corner of the terminal.
+    #[default]
+    BottomRight,
+}
+
+impl Corner {
+    /// Whether the corner is at the top of the terminal.
+    #[must_use]
+    pub const fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopRight)
+    }
+
+    /// Whether the corner is on the left side of the terminal.
+    #[must_use]
+    pub const fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// A single transient message inside of [`Toasts`].
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Toast<'a> {
+    /// The text of the message. It is wrapped if it doesn't fit into [`Toasts::max_width`].
+    pub message: &'a str,
+    /// How important the message is.
+    pub severity: Severity,
+    /// How much longer the toast is shown for, or `None` if it is only dismissed by clicking it.
+    pub remaining: Option<Duration>,
+}
+
+impl<'a> Toast<'a> {
+    /// How long a [`Toast`] is shown for, unless you choose another duration with [`Toast::lasting`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+
+    /// Create a new [`Toast`] that dismisses itself after [`Toast::DEFAULT_DURATION`].
+    #[must_use]
+    pub const fn new(message: &'a str, severity: Severity) -> Self {
+        Self {
+            message,
+            severity,
+            remaining: Some(Self::DEFAULT_DURATION),
+        }
+    }
+
+    /// Set how long the [`Toast`] is shown for.
+    #[must_use]
+    pub const fn lasting(mut self, duration: Duration) -> Self {
+        self.remaining = Some(duration);
+
+        self
+    }
+
+    /// Make the [`Toast`] stay until it is clicked.
+    #[must_use]
+    pub const fn sticky(mut self) -> Self {
+        self.remaining = None;
+
+        self
+    }
+}
+
+/// A stack of transient notifications, drawn in a corner of the terminal over whatever is already there.
+///
+/// [`Toasts`] never allocates: it holds up to `N` [`Toast`]s at once, and pushing a toast onto a
+/// full stack evicts the oldest one. The newest toast is drawn closest to the [`Corner`], and
+/// toasts that don't fit inside the terminal are not drawn.
+///
+/// Time is measured by accumulating the [`UpdateInfo::TimeDelta`]s that the [`Toasts`] receive, and
+/// clicking on a toast dismisses it.
+///
+/// ```
+/// use core::time::Duration;
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, MouseButton, UpdateInfo, UpdateResult};
+/// use tuit::widgets::builtins::toasts::{Corner, Severity, Toast};
+/// use tuit::widgets::builtins::Toasts;
+///
+/// let mut terminal: ConstantSize<30, 10> = ConstantSize::new();
+/// let mut toasts: Toasts<4> = Toasts::new().corner(Corner::TopLeft);
+///
+/// toasts.push(Toast::new("Saved!", Severity::Success).lasting(Duration::from_secs(1)));
+/// toasts.push(Toast::new("Disk is almost full", Severity::Warning).sticky());
+/// toasts.drawn(&mut terminal).expect("Infallible");
+///
+/// // The newest toast is drawn in the corner.
+/// assert_eq!(terminal.cell(1, 0).expect("Cell exists").character, 'D');
+///
+/// toasts.update(UpdateInfo::TimeDelta(Duration::from_secs(2)), &terminal).expect("Infallible");
+/// assert_eq!(toasts.len(), 1);
+///
+/// let click = UpdateInfo::CellClicked(3, 0, MouseButton::Primary);
+/// assert_eq!(toasts.update(click, &terminal).expect("Infallible"), UpdateResult::Interacted);
+/// assert!(toasts.is_empty());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Toasts<'a, const N: usize> {
+    /// The corner that the toasts are stacked in.
+    pub corner: Corner,
+    /// The maximum width of a toast, including its padding. Longer messages are wrapped.
+    pub max_width: usize,
+    /// The style of [`Severity::Info`] toasts. Unset properties come from [`Theme::info_toast`].
+    pub info_style: Style,
+    /// The style of [`Severity::Success`] toasts. Unset properties come from [`Theme::success_toast`].
+    pub success_style: Style,
+    /// The style of [`Severity::Warning`] toasts. Unset properties come from [`Theme::warning_toast`].
+    pub warning_style: Style,
+    /// The style of [`Severity::Error`] toasts. Unset properties come from [`Theme::error_toast`].
+    pub error_style: Style,
+    /// The toasts, oldest first. Only the first `len` are `Some`.
+    stack: [Option<Toast<'a>>; N],
+    len: usize,
+}
+
+impl<'a, const N: usize> Toasts<'a, N> {
+    /// The [`Toasts::max_width`] used by [`Toasts::new`].
+    pub const DEFAULT_MAX_WIDTH: usize = 32;
+
+    /// Create a new, empty stack of [`Toasts`] in the bottom-right corner.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            corner: Corner::BottomRight,
+            max_width: Self::DEFAULT_MAX_WIDTH,
+            info_style: Style::new(),
+            success_style: Style::new(),
+            warning_style: Style::new(),
+            error_style: Style::new(),
+            stack: [None; N],
+            len: 0,
+        }
+    }
+
+    /// Set the corner that the toasts are stacked in.
+    #[must_use]
+    pub const fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+
+        self
+    }
+
+    /// Set the maximum width of a toast, including its padding.
+    #[must_use]
+    pub const fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+
+        self
+    }
+
+    /// The [`Style`] used to draw toasts of the specified [`Severity`] with the specified [`Theme`].
+    #[must_use]
+    pub const fn style_of(&self, severity: Severity, theme: &Theme) -> Style {
+        match severity {
+            Severity::Info => self.info_style.inherits(theme.info_toast),
+            Severity::Success => self.success_style.inherits(theme.success_toast),
+            Severity::Warning => self.warning_style.inherits(theme.warning_toast),
+            Severity::Error => self.error_style.inherits(theme.error_toast),
+        }
+    }
+
+    /// Push a new [`Toast`] onto the stack.
+    ///
+    /// If the stack is already full, the oldest toast is evicted to make room and then returned.
+    pub fn push(&mut self, toast: Toast<'a>) -> Option<Toast<'a>> {
+        if N == 0 {
+            return Some(toast);
+        }
+
+        let evicted = if self.len == N { self.remove(0) } else { None };
+
+        self.stack[self.len] = Some(toast);
+        self.len += 1;
+
+        evicted
+    }
+
+    /// Push a new [`Toast`] with the default duration onto the stack. See [`Toasts::push`].
+    pub fn notify(&mut self, message: &'a str, severity: Severity) -> Option<Toast<'a>> {
+        self.push(Toast::new(message, severity))
+    }
+
+    /// Remove the toast at the specified index (the oldest toast is at index zero), and return it.
+    pub fn remove(&mut self, index: usize) -> Option<Toast<'a>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let removed = self.stack[index].take();
+
+        self.stack[index..self.len].rotate_left(1);
+        self.len -= 1;
+
+        removed
+    }
+
+    /// Dismiss every toast.
+    pub const fn clear(&mut self) {
+        self.stack = [None; N];
+        self.len = 0;
+    }
+
+    /// Returns the number of toasts in the stack.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no toasts in the stack.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the toasts, from oldest to newest.
+    #[must_use]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Toast<'a>> {
+        self.stack[..self.len].iter().flatten()
+    }
+
+    /// Advance every toast's timer by the specified amount of time, dismissing the ones that expire.
+    ///
+    /// Returns `true` if any toasts were dismissed.
+    pub fn advance(&mut self, delta: Duration) -> bool {
+        let mut idx = 0;
+        let mut dismissed = false;
+
+        while idx < self.len {
+            let expired = match &mut self.stack[idx] {
+                Some(Toast { remaining: Some(remaining), .. }) => {
+                    *remaining = remaining.saturating_sub(delta);
+
+                    remaining.is_zero()
+                }
+                _ => false,
+            };
+
+            if expired {
+                self.remove(idx);
+                dismissed = true;
+            } else {
+                idx += 1;
+            }
+        }
+
+        dismissed
+    }
+
+    /// Works out where each toast goes inside the given [`Rectangle`], indexed the same way as the toasts.
+    ///
+    /// Toasts that don't fit are `None`.
+    fn placements(&self, rect: Rectangle) -> [Option<Rectangle>; N] {
+        let mut placements = [None; N];
+        let max_width = self.max_width.min(rect.width());
+
+        // The space between the corner and the edge of the next toast.
+        let mut used_height = 0;
+
+        // Newest toasts go closest to the corner.
+        for (idx, toast) in self.stack[..self.len].iter().enumerate().rev() {
+            let Some(toast) = toast else { continue };
+
+            let Some((width, height)) = toast_size(toast.message, max_width) else {
+                continue;
+            };
+
+            // Leave a gap of one row between toasts.
+            let gap = usize::from(used_height > 0);
+
+            if used_height + gap + height > rect.height() {
+                break;
+            }
+
+            used_height += gap;
+
+            let left = if self.corner.is_left() { rect.left() } else { rect.right() - width };
+            let top = if self.corner.is_top() { rect.top() + used_height } else { rect.bottom() - used_height - height };
+
+            placements[idx] = Some(Rectangle::of_size((width, height)).at((left, top)));
+            used_height += height;
+        }
+
+        placements
+    }
+
+    fn draw_toast(&self, mut terminal: impl Terminal, toast: &Toast, placement: Rectangle) -> crate::Result<()> {
+        let style = self.style_of(toast.severity, &terminal.theme());
+        let mut characters = toast.message.chars();
+
+        for y in placement.top()..placement.bottom() {
+            for x in placement.left()..placement.right() {
+                let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+                let is_padding = x == placement.left() || x == placement.right() - 1;
+
+                cell.character = if is_padding { ' ' } else { characters.next().unwrap_or(' ') };
+                cell.style = style.inherits(cell.style);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The size of a toast containing the message, with one cell of padding on either side.
+///
+/// Returns `None` if not even a single character of the message fits.
+fn toast_size(message: &str, max_width: usize) -> Option<(usize, usize)> {
+    let message_width = message.chars().count().max(1);
+    let inner_width = message_width.min(max_width.checked_sub(2)?);
+
+    if inner_width == 0 {
+        return None;
+    }
+
+    Some((inner_width + 2, message_width.div_ceil(inner_width)))
+}
+
+impl<const N: usize> Default for Toasts<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Widget for Toasts<'_, N> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        match update_info {
+            UpdateInfo::TimeDelta(delta) => {
+                self.advance(delta);
+            }
+            UpdateInfo::CellClicked(x, y, _) => {
+                let placements = self.placements(terminal.bounding_box());
+
+                let clicked = placements
+                    .iter()
+                    .position(|placement| placement.is_some_and(|placement| placement.contains((x, y))));
+
+                if let Some(clicked) = clicked {
+                    self.remove(clicked);
+
+                    return Ok(UpdateResult::Interacted);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let placements = self.placements(terminal.bounding_box());
+
+        for (toast, placement) in self.stack.iter().zip(placements) {
+            if let (Some(toast), Some(placement)) = (toast, placement) {
+                self.draw_toast(&mut terminal, toast, placement)?;
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl<const N: usize> BoundingBox for Toasts<'_, N> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let placements = self.placements(rect);
+        let mut placed = placements.iter().flatten();
+
+        let Some(first) = placed.next() else {
+            // An empty stack takes up no space in its corner.
+            let x = if self.corner.is_left() { rect.left() } else { rect.right() };
+            let y = if self.corner.is_top() { rect.top() } else { rect.bottom() };
+
+            return Ok(Rectangle::of_size((0, 0)).at((x, y)));
+        };
+
+        let bounds = placed.fold(*first, |bounds, placement| {
+            Rectangle::new(
+                (bounds.left().min(placement.left()), bounds.top().min(placement.top())),
+                (bounds.right().max(placement.right()), bounds.bottom().max(placement.bottom())),
+            )
+        });
+
+        Ok(bounds)
+    }
+
+    // Where the toasts go depends on the size of the terminal, which we don't know here.
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        false
+    }
+}
+
+impl<const N: usize> Animated for Toasts<'_, N> {
+    fn next_tick(&self) -> Option<Duration> {
+        self.iter().filter_map(|toast| toast.remaining).min()
+    }
+}
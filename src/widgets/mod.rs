@@ -2,6 +2,8 @@
 //!
 //! The widgets module includes the necessary traits for widgets
 
+use core::time::Duration;
+
 use crate::prelude::*;
 use crate::terminal::{Rectangle, Terminal, UpdateInfo, UpdateResult};
 
@@ -11,6 +13,7 @@ pub mod builtins;
 
 /// Provides a direction for [`Widget`]s to optionally use where it makes sense.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Left
     #[default]
@@ -309,6 +312,29 @@ pub trait BoundingBox: Widget {
     }
 }
 
+/// The [`Animated`] trait allows widgets that change over time to tell whoever is driving them
+/// how long it will be until they next need an [`UpdateInfo::TimeDelta`].
+///
+/// Tuit doesn't run an event loop for you, so this is only a hint; your runner can use it to decide
+/// how long to sleep (or how long to wait for input) before updating and redrawing again.
+///
+/// ```
+/// use core::time::Duration;
+/// use tuit::widgets::Animated;
+/// use tuit::widgets::builtins::Spinner;
+///
+/// let spinner = Spinner::line().interval(Duration::from_millis(100));
+///
+/// assert_eq!(spinner.next_tick(), Some(Duration::from_millis(100)));
+/// ```
+pub trait Animated: Widget {
+    /// Returns how long it will be until the widget next wants to receive an [`UpdateInfo::TimeDelta`].
+    ///
+    /// Returns `None` if the widget does not currently need to be woken up at all (for example, if
+    /// its animation has finished).
+    fn next_tick(&self) -> Option<Duration>;
+}
+
 #[cfg(test)]
 #[doc(hidden)]
 /// Proud to be a great programmer who tests his code. :)